@@ -359,28 +359,7 @@ impl Default for WebOptions {
 
 // ----------------------------------------------------------------------------
 
-/// Dark or Light theme.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
-pub enum Theme {
-    /// Dark mode: light text on a dark background.
-    Dark,
-
-    /// Light mode: dark text on a light background.
-    Light,
-}
-
-impl Theme {
-    /// Get the egui visuals corresponding to this theme.
-    ///
-    /// Use with [`egui::Context::set_visuals`].
-    pub fn egui_visuals(self) -> egui::Visuals {
-        match self {
-            Self::Dark => egui::Visuals::dark(),
-            Self::Light => egui::Visuals::light(),
-        }
-    }
-}
+pub use egui::Theme;
 
 // ----------------------------------------------------------------------------
 
@@ -488,6 +467,9 @@ pub struct Frame {
     /// Where the app can issue commands back to the integration.
     pub(crate) output: backend::AppOutput,
 
+    /// The files picked in the last file dialog, see [`Self::take_file_dialog_result`].
+    pub(crate) file_dialog_result: Option<Vec<PickedFile>>,
+
     /// A place where you can store custom data in a way that persists when you restart the app.
     pub(crate) storage: Option<Box<dyn Storage>>,
 
@@ -596,12 +578,151 @@ impl Frame {
         self.output.visible = Some(visible);
     }
 
+    /// Set the hash part of the URL (e.g. `"#settings"`), adding an entry to the browser history.
+    ///
+    /// The new hash shows up in [`Location::hash`] right away. When the user navigates back or
+    /// forward, [`Location::hash`] is updated and the app is repainted, so you can use this
+    /// to deep-link to a specific part of your app.
+    #[cfg(target_arch = "wasm32")]
+    pub fn set_location_hash(&mut self, hash: &str) {
+        let hash = if hash.starts_with('#') {
+            hash.to_owned()
+        } else {
+            format!("#{}", hash)
+        };
+        self.info.web_info.location.hash = hash.clone();
+        self.output.location_hash = Some(hash);
+    }
+
+    /// Show a file dialog, e.g. for picking a file to open.
+    ///
+    /// The dialog is shown after this frame, and the result can be read with
+    /// [`Self::take_file_dialog_result`] once the user is done.
+    /// The app keeps running while the dialog is open.
+    ///
+    /// On native, the `rfd` feature shows a native dialog. Without it,
+    /// a simple dialog made with egui is shown, where the user can type a path.
+    ///
+    /// On web, the browser's file picker is shown, and the contents of the picked files
+    /// are read into [`PickedFile::bytes`]. Browsers can't save files,
+    /// so [`FileDialogKind::SaveFile`] shows the egui dialog, where the user can type a file name.
+    pub fn open_file_dialog(&mut self, dialog: FileDialog) {
+        self.output.file_dialog = Some(dialog);
+    }
+
+    /// The files picked in the dialog opened with [`Self::open_file_dialog`].
+    ///
+    /// Returns `Some` once, the frame after the user closes the dialog.
+    /// The list is empty if the user cancelled.
+    ///
+    /// ```
+    /// # fn update(ctx: &egui::Context, frame: &mut eframe::Frame) {
+    /// egui::CentralPanel::default().show(ctx, |ui| {
+    ///     if ui.button("Open…").clicked() {
+    ///         frame.open_file_dialog(eframe::FileDialog::open_file().add_extension("txt"));
+    ///     }
+    /// });
+    ///
+    /// if let Some(files) = frame.take_file_dialog_result() {
+    ///     for file in files {
+    ///         println!("Picked {}", file.name);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn take_file_dialog_result(&mut self) -> Option<Vec<PickedFile>> {
+        self.file_dialog_result.take()
+    }
+
     /// for integrations only: call once per frame
     pub(crate) fn take_app_output(&mut self) -> backend::AppOutput {
         std::mem::take(&mut self.output)
     }
 }
 
+/// A file dialog for [`Frame::open_file_dialog`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileDialog {
+    pub(crate) kind: FileDialogKind,
+    pub(crate) title: String,
+    pub(crate) extensions: Vec<String>,
+}
+
+/// What the user should pick in a [`FileDialog`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FileDialogKind {
+    /// Pick one existing file.
+    OpenFile,
+
+    /// Pick any number of existing files.
+    OpenFiles,
+
+    /// Pick where to save a file.
+    SaveFile,
+}
+
+impl FileDialog {
+    /// A dialog for picking what `kind` says, with no title and showing all files.
+    ///
+    /// See also [`Self::open_file`], [`Self::open_files`] and [`Self::save_file`].
+    pub fn new(kind: FileDialogKind) -> Self {
+        Self {
+            kind,
+            title: Default::default(),
+            extensions: Default::default(),
+        }
+    }
+
+    /// Pick one existing file.
+    pub fn open_file() -> Self {
+        Self::new(FileDialogKind::OpenFile)
+    }
+
+    /// Pick any number of existing files.
+    pub fn open_files() -> Self {
+        Self::new(FileDialogKind::OpenFiles)
+    }
+
+    /// Pick where to save a file.
+    pub fn save_file() -> Self {
+        Self::new(FileDialogKind::SaveFile)
+    }
+
+    /// Title of the dialog window.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Only show files with this extension (without the dot).
+    ///
+    /// Call several times to allow several extensions. By default all files are shown.
+    pub fn add_extension(mut self, extension: impl Into<String>) -> Self {
+        self.extensions.push(extension.into());
+        self
+    }
+
+    /// What the user should pick.
+    pub fn kind(&self) -> FileDialogKind {
+        self.kind
+    }
+}
+
+/// A file picked in a [`FileDialog`], see [`Frame::take_file_dialog_result`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PickedFile {
+    /// Set on native, where the app reads (or writes) the file itself.
+    pub path: Option<std::path::PathBuf>,
+
+    /// Name of the file, e.g. `"notes.txt"`.
+    pub name: String,
+
+    /// The contents of the file.
+    ///
+    /// Set on web, where there is no path to read the file from.
+    pub bytes: Option<std::sync::Arc<[u8]>>,
+}
+
 /// Information about the web environment (if applicable).
 #[derive(Clone, Debug)]
 #[cfg(target_arch = "wasm32")]
@@ -797,5 +918,12 @@ pub(crate) mod backend {
         /// Set to some bool to change window visibility.
         #[cfg(not(target_arch = "wasm32"))]
         pub visible: Option<bool>,
+
+        /// Set to show a file dialog after this frame.
+        pub file_dialog: Option<super::FileDialog>,
+
+        /// Set to change the hash part of the URL.
+        #[cfg(target_arch = "wasm32")]
+        pub location_hash: Option<String>,
     }
 }
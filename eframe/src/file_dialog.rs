@@ -0,0 +1,343 @@
+//! Showing the file dialogs requested with [`crate::Frame::open_file_dialog`].
+
+use std::path::PathBuf;
+
+use crate::epi::{FileDialog, FileDialogKind, Frame, PickedFile};
+
+/// The file dialogs of an integration.
+#[derive(Default)]
+pub(crate) struct FileDialogs {
+    /// The native dialog, resolving to the picked files once the user is done.
+    #[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+    native: Option<std::pin::Pin<Box<dyn std::future::Future<Output = Vec<PickedFile>>>>>,
+
+    /// The `<input type="file">` element we are waiting for.
+    #[cfg(target_arch = "wasm32")]
+    web: Option<web::WebFileInput>,
+
+    /// Shown with egui, when there is no native file dialog.
+    fallback: Option<FallbackFileDialog>,
+}
+
+impl FileDialogs {
+    /// Call from within [`egui::Context::run`], after [`crate::App::update`].
+    pub fn show(&mut self, egui_ctx: &egui::Context, frame: &mut Frame) {
+        #[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+        if let Some(native) = &mut self.native {
+            let waker = std::task::Waker::from(std::sync::Arc::new(RepaintWaker(egui_ctx.clone())));
+            let mut cx = std::task::Context::from_waker(&waker);
+            if let std::task::Poll::Ready(files) =
+                std::future::Future::poll(native.as_mut(), &mut cx)
+            {
+                frame.file_dialog_result = Some(files);
+                self.native = None;
+            }
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        if let Some(web) = &self.web {
+            if let Some(files) = web.take_result() {
+                frame.file_dialog_result = Some(files);
+                self.web = None;
+            }
+        }
+
+        if let Some(fallback) = &mut self.fallback {
+            if let Some(files) = fallback.show(egui_ctx) {
+                frame.file_dialog_result = Some(files);
+                self.fallback = None;
+            }
+        }
+    }
+
+    /// Call with [`crate::epi::backend::AppOutput::file_dialog`] at the end of the frame.
+    pub fn open(&mut self, egui_ctx: &egui::Context, dialog: FileDialog) {
+        #[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+        {
+            self.native = Some(open_native(&dialog));
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            if dialog.kind == FileDialogKind::SaveFile {
+                // There is no save dialog on the web.
+                self.fallback = Some(FallbackFileDialog::new(dialog));
+            } else {
+                match web::WebFileInput::open(egui_ctx, &dialog) {
+                    Ok(web) => self.web = Some(web),
+                    Err(err) => {
+                        tracing::error!("Failed to open file dialog: {:?}", err);
+                        self.fallback = Some(FallbackFileDialog::new(dialog));
+                    }
+                }
+            }
+        }
+
+        #[cfg(not(any(feature = "rfd", target_arch = "wasm32")))]
+        {
+            self.fallback = Some(FallbackFileDialog::new(dialog));
+        }
+
+        egui_ctx.request_repaint();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Repaints when the native dialog is done, so we get to poll it again.
+#[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+struct RepaintWaker(egui::Context);
+
+#[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+impl std::task::Wake for RepaintWaker {
+    fn wake(self: std::sync::Arc<Self>) {
+        self.0.request_repaint();
+    }
+}
+
+/// Opens a native dialog without blocking: the returned future is polled every frame.
+#[cfg(all(feature = "rfd", not(target_arch = "wasm32")))]
+fn open_native(
+    dialog: &FileDialog,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = Vec<PickedFile>>>> {
+    let mut native = rfd::AsyncFileDialog::new();
+    if !dialog.title.is_empty() {
+        native = native.set_title(&dialog.title);
+    }
+    if !dialog.extensions.is_empty() {
+        let extensions: Vec<&str> = dialog.extensions.iter().map(String::as_str).collect();
+        native = native.add_filter(&extensions.join(", "), &extensions);
+    }
+
+    fn picked(handle: rfd::FileHandle) -> PickedFile {
+        PickedFile {
+            path: Some(handle.path().to_owned()),
+            name: handle.file_name(),
+            bytes: None,
+        }
+    }
+
+    match dialog.kind {
+        FileDialogKind::OpenFile => {
+            let future = native.pick_file();
+            Box::pin(async move { future.await.into_iter().map(picked).collect() })
+        }
+        FileDialogKind::OpenFiles => {
+            let future = native.pick_files();
+            Box::pin(async move {
+                future
+                    .await
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(picked)
+                    .collect()
+            })
+        }
+        FileDialogKind::SaveFile => {
+            let future = native.save_file();
+            Box::pin(async move { future.await.into_iter().map(picked).collect() })
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::sync::Arc;
+
+    use egui::mutex::Mutex;
+    use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+    use crate::epi::{FileDialog, FileDialogKind, PickedFile};
+
+    /// An `<input type="file">` that we have clicked for the user.
+    pub(super) struct WebFileInput {
+        result: Arc<Mutex<Option<Vec<PickedFile>>>>,
+
+        // Kept alive until the user is done:
+        _input: web_sys::HtmlInputElement,
+        _on_change: Closure<dyn FnMut()>,
+        _on_cancel: Closure<dyn FnMut()>,
+    }
+
+    impl WebFileInput {
+        pub fn open(egui_ctx: &egui::Context, dialog: &FileDialog) -> Result<Self, JsValue> {
+            let document = web_sys::window()
+                .and_then(|window| window.document())
+                .ok_or_else(|| JsValue::from_str("no document"))?;
+            let input: web_sys::HtmlInputElement = document.create_element("input")?.dyn_into()?;
+            input.set_type("file");
+            input.set_multiple(dialog.kind == FileDialogKind::OpenFiles);
+            if !dialog.extensions.is_empty() {
+                let accept: Vec<String> = dialog
+                    .extensions
+                    .iter()
+                    .map(|extension| format!(".{}", extension))
+                    .collect();
+                input.set_accept(&accept.join(","));
+            }
+
+            let result: Arc<Mutex<Option<Vec<PickedFile>>>> = Default::default();
+
+            let on_change = Closure::wrap(Box::new({
+                let input = input.clone();
+                let result = result.clone();
+                let egui_ctx = egui_ctx.clone();
+                move || {
+                    let files = input.files();
+                    let result = result.clone();
+                    let egui_ctx = egui_ctx.clone();
+                    wasm_bindgen_futures::spawn_local(async move {
+                        let files = match files {
+                            Some(files) => read_files(&files).await,
+                            None => vec![],
+                        };
+                        *result.lock() = Some(files);
+                        egui_ctx.request_repaint();
+                    });
+                }
+            }) as Box<dyn FnMut()>);
+            input.set_onchange(Some(on_change.as_ref().unchecked_ref()));
+
+            let on_cancel = Closure::wrap(Box::new({
+                let result = result.clone();
+                let egui_ctx = egui_ctx.clone();
+                move || {
+                    *result.lock() = Some(vec![]);
+                    egui_ctx.request_repaint();
+                }
+            }) as Box<dyn FnMut()>);
+            input.add_event_listener_with_callback("cancel", on_cancel.as_ref().unchecked_ref())?;
+
+            input.click();
+
+            Ok(Self {
+                result,
+                _input: input,
+                _on_change: on_change,
+                _on_cancel: on_cancel,
+            })
+        }
+
+        /// The picked files, once they have all been read.
+        pub fn take_result(&self) -> Option<Vec<PickedFile>> {
+            self.result.lock().take()
+        }
+    }
+
+    async fn read_files(files: &web_sys::FileList) -> Vec<PickedFile> {
+        let mut picked = vec![];
+        for i in 0..files.length() {
+            if let Some(file) = files.get(i) {
+                let name = file.name();
+                match wasm_bindgen_futures::JsFuture::from(file.array_buffer()).await {
+                    Ok(array_buffer) => {
+                        let bytes = js_sys::Uint8Array::new(&array_buffer).to_vec();
+                        picked.push(PickedFile {
+                            path: None,
+                            name,
+                            bytes: Some(bytes.into()),
+                        });
+                    }
+                    Err(err) => {
+                        tracing::error!("Failed to read {:?}: {:?}", name, err);
+                    }
+                }
+            }
+        }
+        picked
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A simple file dialog made with egui, where the user types the path(s).
+struct FallbackFileDialog {
+    dialog: FileDialog,
+    text: String,
+}
+
+impl FallbackFileDialog {
+    #[cfg_attr(all(feature = "rfd", not(target_arch = "wasm32")), allow(dead_code))]
+    fn new(dialog: FileDialog) -> Self {
+        Self {
+            dialog,
+            text: Default::default(),
+        }
+    }
+
+    /// Returns the picked files once the user is done (empty if cancelled).
+    fn show(&mut self, egui_ctx: &egui::Context) -> Option<Vec<PickedFile>> {
+        let Self { dialog, text } = self;
+
+        let title = if dialog.title.is_empty() {
+            match dialog.kind {
+                FileDialogKind::OpenFile => "Open file",
+                FileDialogKind::OpenFiles => "Open files",
+                FileDialogKind::SaveFile => "Save file",
+            }
+        } else {
+            dialog.title.as_str()
+        };
+
+        let mut result = None;
+
+        egui::Window::new(title)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(egui_ctx, |ui| {
+                if dialog.kind == FileDialogKind::OpenFiles {
+                    ui.label("Paths, one per line:");
+                    ui.text_edit_multiline(text);
+                } else {
+                    ui.label("Path:");
+                    ui.text_edit_singleline(text);
+                }
+
+                if !dialog.extensions.is_empty() {
+                    ui.weak(format!("Supported: {}", dialog.extensions.join(", ")));
+                }
+
+                ui.horizontal(|ui| {
+                    let files: Vec<PickedFile> = text
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty())
+                        .map(picked_from_path)
+                        .collect();
+
+                    if ui
+                        .add_enabled(!files.is_empty(), egui::Button::new("OK"))
+                        .clicked()
+                    {
+                        result = Some(files);
+                    }
+                    if ui.button("Cancel").clicked() {
+                        result = Some(vec![]);
+                    }
+                });
+            });
+
+        result
+    }
+}
+
+fn picked_from_path(path: &str) -> PickedFile {
+    let path = PathBuf::from(path);
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    PickedFile {
+        // On the web there is no file system for the path to refer to.
+        path: if cfg!(target_arch = "wasm32") {
+            None
+        } else {
+            Some(path)
+        },
+        name,
+        bytes: None,
+    }
+}
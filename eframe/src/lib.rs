@@ -69,10 +69,14 @@ pub use {egui_glow, glow};
 pub use {egui_wgpu, wgpu};
 
 mod epi;
+mod file_dialog;
 
 // Re-export everything in `epi` so `eframe` users don't have to care about what `epi` is:
 pub use epi::*;
 
+/// The version of eframe, e.g. for showing in an about panel.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 // ----------------------------------------------------------------------------
 // When compiling for web
 
@@ -118,12 +122,42 @@ pub fn start_web(
     Ok(handle)
 }
 
+/// Like [`start_web`], but paints with WebGPU if the `wgpu` feature is enabled
+/// and the browser supports it, and otherwise falls back to WebGL.
+///
+/// WebGPU in `web-sys` is still unstable, so you must also build with
+/// `RUSTFLAGS=--cfg=web_sys_unstable_apis`.
+///
+/// ``` no_run
+/// #[cfg(target_arch = "wasm32")]
+/// use wasm_bindgen::prelude::*;
+///
+/// /// This is called from the HTML, and returns a promise of a handle to the running app.
+/// #[cfg(target_arch = "wasm32")]
+/// #[wasm_bindgen]
+/// pub async fn start(canvas_id: String) -> Result<AppRunnerRef>, eframe::wasm_bindgen::JsValue> {
+///     let web_options = eframe::WebOptions::default();
+///     eframe::start_web_async(&canvas_id, web_options, Box::new(|cc| Box::new(MyEguiApp::new(cc)))).await
+/// }
+/// ```
+#[cfg(target_arch = "wasm32")]
+pub async fn start_web_async(
+    canvas_id: &str,
+    web_options: WebOptions,
+    app_creator: AppCreator,
+) -> Result<AppRunnerRef, wasm_bindgen::JsValue> {
+    web::start_async(canvas_id, web_options, app_creator).await
+}
+
 // ----------------------------------------------------------------------------
 // When compiling natively
 
 #[cfg(not(target_arch = "wasm32"))]
 mod native;
 
+#[cfg(all(feature = "persistence", not(target_arch = "wasm32")))]
+pub use native::style_file::StyleFile;
+
 /// This is how you start a native (desktop) app.
 ///
 /// The first argument is name of your app, used for the title bar of the native window
@@ -123,6 +123,7 @@ pub fn handle_app_output(
         drag_window,
         window_pos,
         visible,
+        file_dialog: _, // handled in `EpiIntegration::update`
     } = app_output;
 
     if let Some(decorated) = decorated {
@@ -176,6 +177,14 @@ pub fn create_storage(_app_name: &str) -> Option<Box<dyn epi::Storage>> {
 
 // ----------------------------------------------------------------------------
 
+/// The native window of a viewport, see [`egui::Context::show_viewport`].
+#[cfg(feature = "wgpu")]
+struct ViewportWindow {
+    window: winit::window::Window,
+    egui_winit: egui_winit::State,
+    builder: egui::ViewportBuilder,
+}
+
 /// Everything needed to make a winit-based integration for [`epi`].
 pub struct EpiIntegration {
     pub frame: epi::Frame,
@@ -186,6 +195,9 @@ pub struct EpiIntegration {
     /// When set, it is time to quit
     quit: bool,
     can_drag_window: bool,
+    file_dialogs: crate::file_dialog::FileDialogs,
+    #[cfg(feature = "wgpu")]
+    viewport_windows: std::collections::HashMap<egui::ViewportId, ViewportWindow>,
 }
 
 impl EpiIntegration {
@@ -210,6 +222,7 @@ impl EpiIntegration {
                 window_info: read_window_info(window, egui_ctx.pixels_per_point()),
             },
             output: Default::default(),
+            file_dialog_result: None,
             storage,
             #[cfg(feature = "glow")]
             gl,
@@ -221,6 +234,11 @@ impl EpiIntegration {
         egui_winit.set_max_texture_side(max_texture_side);
         let pixels_per_point = window.scale_factor() as f32;
         egui_winit.set_pixels_per_point(pixels_per_point);
+        egui_winit.set_system_theme(system_theme);
+        if system_theme.is_some() {
+            // Only set if `NativeOptions::follow_system_theme` is on:
+            egui_ctx.memory().options.follow_system_theme = true;
+        }
 
         Self {
             frame,
@@ -230,6 +248,9 @@ impl EpiIntegration {
             pending_full_output: Default::default(),
             quit: false,
             can_drag_window: false,
+            file_dialogs: Default::default(),
+            #[cfg(feature = "wgpu")]
+            viewport_windows: Default::default(),
         }
     }
 
@@ -259,12 +280,20 @@ impl EpiIntegration {
                 state: ElementState::Pressed,
                 ..
             } => self.can_drag_window = true,
+            WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.frame.info.native_pixels_per_point = Some(*scale_factor as _);
+            }
             _ => {}
         }
 
         self.egui_winit.on_event(&self.egui_ctx, event);
     }
 
+    /// Returns `true` if egui got the motion, see [`egui_winit::State::on_mouse_motion`].
+    pub fn on_mouse_motion(&mut self, delta: (f64, f64)) -> bool {
+        self.egui_winit.on_mouse_motion(delta)
+    }
+
     pub fn update(
         &mut self,
         app: &mut dyn epi::App,
@@ -273,14 +302,37 @@ impl EpiIntegration {
         let frame_start = std::time::Instant::now();
 
         self.frame.info.window_info = read_window_info(window, self.egui_ctx.pixels_per_point());
-        let raw_input = self.egui_winit.take_egui_input(window);
+        #[allow(unused_mut)]
+        let mut raw_input = self.egui_winit.take_egui_input(window);
+        #[cfg(feature = "wgpu")]
+        for (id, viewport) in &mut self.viewport_windows {
+            // All viewports are painted with the `pixels_per_point` of the root viewport:
+            viewport
+                .egui_winit
+                .set_pixels_per_point(self.egui_ctx.pixels_per_point());
+            let viewport_input = viewport.egui_winit.take_egui_input(&viewport.window);
+            raw_input.viewports.insert(*id, viewport_input);
+        }
         let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
             crate::profile_scope!("App::update");
             app.update(egui_ctx, &mut self.frame);
+            self.file_dialogs.show(egui_ctx, &mut self.frame);
+            egui::gui_zoom::zoom_with_keyboard_shortcuts(
+                egui_ctx,
+                self.frame.info.native_pixels_per_point,
+            );
         });
         self.pending_full_output.append(full_output);
         let full_output = std::mem::take(&mut self.pending_full_output);
 
+        if full_output
+            .platform_output
+            .window_commands
+            .contains(&egui::WindowCommand::Close)
+        {
+            self.quit = app.on_exit_event();
+        }
+
         {
             let mut app_output = self.frame.take_app_output();
             app_output.drag_window &= self.can_drag_window; // Necessary on Windows; see https://github.com/emilk/egui/pull/1108
@@ -288,11 +340,15 @@ impl EpiIntegration {
             if app_output.quit {
                 self.quit = app.on_exit_event();
             }
+            if let Some(file_dialog) = app_output.file_dialog.take() {
+                self.file_dialogs.open(&self.egui_ctx, file_dialog);
+            }
             handle_app_output(window, self.egui_ctx.pixels_per_point(), app_output);
         }
 
         let frame_time = (std::time::Instant::now() - frame_start).as_secs_f64() as f32;
         self.frame.info.cpu_usage = Some(frame_time);
+        self.egui_ctx.report_frame_time(frame_time);
 
         full_output
     }
@@ -313,6 +369,120 @@ impl EpiIntegration {
             .handle_platform_output(window, &self.egui_ctx, platform_output);
     }
 
+    // ------------------------------------------------------------------------
+    // Viewports:
+
+    /// Which viewport, if any, the given window belongs to.
+    #[cfg(feature = "wgpu")]
+    pub fn viewport_of_window(
+        &self,
+        window_id: winit::window::WindowId,
+    ) -> Option<egui::ViewportId> {
+        self.viewport_windows
+            .iter()
+            .find(|(_, viewport)| viewport.window.id() == window_id)
+            .map(|(id, _)| *id)
+    }
+
+    #[cfg(feature = "wgpu")]
+    pub fn viewport_window(&self, id: egui::ViewportId) -> Option<&winit::window::Window> {
+        self.viewport_windows
+            .get(&id)
+            .map(|viewport| &viewport.window)
+    }
+
+    /// The viewports we currently have windows for.
+    #[cfg(feature = "wgpu")]
+    pub fn viewport_ids(&self) -> Vec<egui::ViewportId> {
+        self.viewport_windows.keys().copied().collect()
+    }
+
+    /// An event for the window of a viewport other than the root one.
+    #[cfg(feature = "wgpu")]
+    pub fn on_viewport_event(
+        &mut self,
+        id: egui::ViewportId,
+        event: &winit::event::WindowEvent<'_>,
+    ) {
+        if let Some(viewport) = self.viewport_windows.get_mut(&id) {
+            viewport.egui_winit.on_event(&self.egui_ctx, event);
+        }
+    }
+
+    /// Open the window of a new viewport, or update the window of an existing one.
+    ///
+    /// Returns `true` if a new window was opened.
+    #[cfg(feature = "wgpu")]
+    pub fn open_viewport_window<E>(
+        &mut self,
+        event_loop: &EventLoopWindowTarget<E>,
+        output: &egui::ViewportOutput,
+    ) -> bool {
+        if let Some(viewport) = self.viewport_windows.get_mut(&output.id) {
+            if viewport.builder.title != output.builder.title {
+                viewport.window.set_title(&output.builder.title);
+            }
+            if viewport.builder.resizable != output.builder.resizable {
+                viewport.window.set_resizable(output.builder.resizable);
+            }
+            viewport.builder = output.builder.clone();
+            return false;
+        }
+
+        let pixels_per_point = self.egui_ctx.pixels_per_point();
+        let window = match egui_winit::viewport_window_builder(&output.builder, pixels_per_point)
+            .build(event_loop)
+        {
+            Ok(window) => window,
+            Err(err) => {
+                tracing::error!(
+                    "Failed to open a window for viewport {:?}: {}",
+                    output.builder.title,
+                    err
+                );
+                return false;
+            }
+        };
+
+        let mut egui_winit = egui_winit::State::new(event_loop);
+        egui_winit.set_pixels_per_point(pixels_per_point);
+
+        self.viewport_windows.insert(
+            output.id,
+            ViewportWindow {
+                window,
+                egui_winit,
+                builder: output.builder.clone(),
+            },
+        );
+        true
+    }
+
+    /// Forget the window of a viewport that is no longer shown.
+    ///
+    /// Returns the window, so the caller can drop anything painting to it first.
+    #[cfg(feature = "wgpu")]
+    pub fn close_viewport_window(&mut self, id: egui::ViewportId) -> Option<winit::window::Window> {
+        self.viewport_windows
+            .remove(&id)
+            .map(|viewport| viewport.window)
+    }
+
+    #[cfg(feature = "wgpu")]
+    pub fn handle_viewport_platform_output(
+        &mut self,
+        id: egui::ViewportId,
+        platform_output: egui::PlatformOutput,
+    ) {
+        if let Some(viewport) = self.viewport_windows.get_mut(&id) {
+            viewport.egui_winit.handle_platform_output(
+                &viewport.window,
+                &self.egui_ctx,
+                platform_output,
+            );
+        }
+    }
+
     // ------------------------------------------------------------------------
     // Persistance stuff:
 
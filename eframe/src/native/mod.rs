@@ -4,3 +4,7 @@ pub mod run;
 /// File storage which can be used by native backends.
 #[cfg(feature = "persistence")]
 pub mod file_storage;
+
+/// Hot-reloading of the [`egui::Style`] from a file.
+#[cfg(feature = "persistence")]
+pub mod style_file;
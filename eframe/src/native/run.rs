@@ -123,13 +123,17 @@ pub fn run_glow(
                 repaint_after,
                 textures_delta,
                 shapes,
+                shape_layers,
+                viewports: _, // embedded as `egui::Window`s with glow
             } = integration.update(app.as_mut(), window);
 
             integration.handle_platform_output(window, platform_output);
 
             let clipped_primitives = {
                 crate::profile_scope!("tessellate");
-                integration.egui_ctx.tessellate(shapes)
+                integration
+                    .egui_ctx
+                    .tessellate_layers(shapes, &shape_layers)
             };
 
             painter.paint_and_update_textures(
@@ -211,6 +215,14 @@ pub fn run_glow(
                 }
                 window.request_redraw(); // TODO(emilk): ask egui if the events warrants a repaint instead
             }
+            winit::event::Event::DeviceEvent {
+                event: winit::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                if integration.on_mouse_motion(delta) {
+                    window.request_redraw();
+                }
+            }
             winit::event::Event::LoopDestroyed => {
                 integration.save(&mut *app, window);
                 app.on_exit(Some(&gl));
@@ -278,6 +290,8 @@ pub fn run_wgpu(
     );
     let theme = system_theme.unwrap_or(native_options.default_theme);
     integration.egui_ctx.set_visuals(theme.egui_visuals());
+    // We can paint in more than one window, so give each viewport its own:
+    integration.egui_ctx.set_embed_viewports(false);
 
     {
         let event_loop_proxy = egui::mutex::Mutex::new(event_loop.create_proxy());
@@ -301,7 +315,7 @@ pub fn run_wgpu(
 
     let mut is_focused = true;
 
-    event_loop.run(move |event, _, control_flow| {
+    event_loop.run(move |event, event_loop, control_flow| {
         let window = &window;
 
         let mut redraw = || {
@@ -314,13 +328,30 @@ pub fn run_wgpu(
                 repaint_after,
                 textures_delta,
                 shapes,
+                shape_layers,
+                viewports,
             } = integration.update(app.as_mut(), window);
 
             integration.handle_platform_output(window, platform_output);
 
+            // Tessellate the other viewports first, so that the paint stats are those of the root viewport:
+            let viewports: Vec<_> = {
+                crate::profile_scope!("tessellate viewports");
+                viewports
+                    .into_iter()
+                    .map(|mut viewport| {
+                        let shapes = std::mem::take(&mut viewport.shapes);
+                        let clipped_primitives = integration.egui_ctx.tessellate(shapes);
+                        (viewport, clipped_primitives)
+                    })
+                    .collect()
+            };
+
             let clipped_primitives = {
                 crate::profile_scope!("tessellate");
-                integration.egui_ctx.tessellate(shapes)
+                integration
+                    .egui_ctx
+                    .tessellate_layers(shapes, &shape_layers)
             };
 
             painter.paint_and_update_textures(
@@ -330,6 +361,43 @@ pub fn run_wgpu(
                 &textures_delta,
             );
 
+            {
+                crate::profile_scope!("viewports");
+
+                // Close the windows of the viewports that were not shown this frame:
+                for id in integration.viewport_ids() {
+                    if !viewports.iter().any(|(viewport, _)| viewport.id == id) {
+                        // SAFETY: the surface is dropped before its window.
+                        #[allow(unsafe_code)]
+                        unsafe {
+                            painter.set_viewport_window(id, None);
+                        }
+                        integration.close_viewport_window(id);
+                    }
+                }
+
+                for (viewport, clipped_primitives) in viewports {
+                    let id = viewport.id;
+                    if integration.open_viewport_window(event_loop, &viewport) {
+                        // SAFETY: the surface is removed above, before the window is closed.
+                        #[allow(unsafe_code)]
+                        unsafe {
+                            painter.set_viewport_window(id, integration.viewport_window(id));
+                        }
+                    }
+                    integration.handle_viewport_platform_output(id, viewport.platform_output);
+
+                    // The textures were uploaded when painting the root viewport:
+                    painter.paint_viewport_and_update_textures(
+                        id,
+                        integration.egui_ctx.pixels_per_point(),
+                        app.clear_color(&integration.egui_ctx.style().visuals),
+                        &clipped_primitives,
+                        &Default::default(),
+                    );
+                }
+            }
+
             *control_flow = if integration.should_quit() {
                 winit::event_loop::ControlFlow::Exit
             } else if repaint_after.is_zero() {
@@ -377,6 +445,34 @@ pub fn run_wgpu(
                 painter.set_window(None);
             },
 
+            winit::event::Event::WindowEvent { window_id, event } if window_id != window.id() => {
+                // An event for the window of one of the other viewports.
+                if let Some(id) = integration.viewport_of_window(window_id) {
+                    match &event {
+                        winit::event::WindowEvent::Resized(physical_size) => {
+                            if physical_size.width > 0 && physical_size.height > 0 {
+                                painter.on_viewport_resized(
+                                    id,
+                                    physical_size.width,
+                                    physical_size.height,
+                                );
+                            }
+                        }
+                        winit::event::WindowEvent::ScaleFactorChanged {
+                            new_inner_size, ..
+                        } => {
+                            painter.on_viewport_resized(
+                                id,
+                                new_inner_size.width,
+                                new_inner_size.height,
+                            );
+                        }
+                        _ => {}
+                    }
+                    integration.on_viewport_event(id, &event);
+                    window.request_redraw(); // all viewports are painted together with the root one
+                }
+            }
             winit::event::Event::WindowEvent { event, .. } => {
                 match &event {
                     winit::event::WindowEvent::Focused(new_focused) => {
@@ -405,6 +501,14 @@ pub fn run_wgpu(
                 }
                 window.request_redraw(); // TODO(emilk): ask egui if the events warrants a repaint instead
             }
+            winit::event::Event::DeviceEvent {
+                event: winit::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                if integration.on_mouse_motion(delta) {
+                    window.request_redraw();
+                }
+            }
             winit::event::Event::LoopDestroyed => {
                 integration.save(&mut *app, window);
 
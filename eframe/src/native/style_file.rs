@@ -0,0 +1,90 @@
+//! Load the [`egui::Style`] from a [RON](https://github.com/ron-rs/ron) file, and reload it when the file changes.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// A [`egui::Style`] stored in a [RON](https://github.com/ron-rs/ron) file,
+/// which is applied again each time the file is saved.
+///
+/// This lets you tweak colors, spacing etc without recompiling your app.
+/// Fields missing from the file get their default value,
+/// so the file only needs to contain what you want to change.
+///
+/// ``` no_run
+/// struct MyApp {
+///     style_file: eframe::StyleFile,
+/// }
+///
+/// impl eframe::App for MyApp {
+///     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+///         self.style_file.update(ctx);
+///         // …
+///     }
+/// }
+/// # use eframe::egui;
+/// ```
+///
+/// Use [`StyleFile::save`] to write the current style to a file to start from.
+pub struct StyleFile {
+    path: PathBuf,
+
+    /// Modification time of the file when we last loaded it.
+    last_modified: Option<SystemTime>,
+}
+
+impl StyleFile {
+    /// How often to check if the file has changed.
+    pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// The file is loaded on the first call to [`Self::update`].
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            last_modified: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Call each frame, e.g. at the start of [`crate::App::update`].
+    ///
+    /// If the file has changed since it was last loaded, it is loaded and set with [`egui::Context::set_style`].
+    /// If the file can't be parsed, a warning is logged and the current style is kept.
+    pub fn update(&mut self, ctx: &egui::Context) {
+        // Keep checking, even if nothing else happens:
+        ctx.request_repaint_after(Self::POLL_INTERVAL);
+
+        let modified = match std::fs::metadata(&self.path).and_then(|meta| meta.modified()) {
+            Ok(modified) => modified,
+            Err(_) => return, // Probably doesn't exist (yet). That's fine.
+        };
+        if self.last_modified == Some(modified) {
+            return;
+        }
+        self.last_modified = Some(modified);
+
+        match load(&self.path) {
+            Ok(style) => {
+                tracing::debug!("Loaded style from {:?}", self.path);
+                ctx.set_style(style);
+            }
+            Err(err) => {
+                tracing::warn!("Failed to load style from {:?}: {}", self.path, err);
+            }
+        }
+    }
+
+    /// Write the current style of the context to the given file, as pretty RON.
+    pub fn save(ctx: &egui::Context, path: impl AsRef<Path>) -> Result<(), String> {
+        let ron = ron::ser::to_string_pretty(&*ctx.style(), Default::default())
+            .map_err(|err| err.to_string())?;
+        std::fs::write(path, ron).map_err(|err| err.to_string())
+    }
+}
+
+fn load(path: &Path) -> Result<egui::Style, String> {
+    let ron = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+    ron::from_str(&ron).map_err(|err| err.to_string())
+}
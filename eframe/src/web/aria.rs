@@ -0,0 +1,134 @@
+//! Exposes the [`egui::AccessibilityTree`] to screen readers
+//! as invisible ARIA elements on top of the canvas.
+
+use wasm_bindgen::{prelude::*, JsCast};
+
+use super::canvas_element;
+
+static ROOT_ID: &str = "egui_aria_tree";
+
+/// Replace the ARIA elements with the widgets of the given tree.
+pub fn update_aria_tree(canvas_id: &str, tree: &egui::AccessibilityTree) -> Result<(), JsValue> {
+    let document = web_sys::window().unwrap().document().unwrap();
+    let canvas = canvas_element(canvas_id).ok_or_else(|| JsValue::from_str("no canvas"))?;
+
+    let root = match document.get_element_by_id(ROOT_ID) {
+        Some(root) => root,
+        None => {
+            let root = document.create_element("div")?;
+            root.set_id(ROOT_ID);
+            root.set_attribute("role", "application")?;
+            root.set_attribute(
+                "style",
+                "position: absolute; overflow: hidden; opacity: 0; pointer-events: none;",
+            )?;
+            document
+                .body()
+                .ok_or_else(|| JsValue::from_str("no body"))?
+                .append_child(&root)?;
+            root
+        }
+    };
+
+    // Cover the canvas, so that the widgets are where they are painted:
+    let canvas_rect = canvas.get_bounding_client_rect();
+    let window = web_sys::window().unwrap();
+    let scroll_x = window.scroll_x().unwrap_or_default();
+    let scroll_y = window.scroll_y().unwrap_or_default();
+    let root_style = root.dyn_ref::<web_sys::HtmlElement>().unwrap().style();
+    root_style.set_property("left", &format!("{}px", canvas_rect.left() + scroll_x))?;
+    root_style.set_property("top", &format!("{}px", canvas_rect.top() + scroll_y))?;
+    root_style.set_property("width", &format!("{}px", canvas_rect.width()))?;
+    root_style.set_property("height", &format!("{}px", canvas_rect.height()))?;
+
+    root.set_inner_html("");
+    for node in &tree.nodes {
+        root.append_child(&create_element(&document, node)?.into())?;
+    }
+
+    match tree.focus {
+        Some(focus) => root.set_attribute("aria-activedescendant", &element_id(focus))?,
+        None => root.remove_attribute("aria-activedescendant")?,
+    }
+
+    Ok(())
+}
+
+fn element_id(id: egui::Id) -> String {
+    format!("egui_aria_{:?}", id)
+}
+
+fn create_element(
+    document: &web_sys::Document,
+    node: &egui::AccessNode,
+) -> Result<web_sys::Element, JsValue> {
+    use egui::WidgetType;
+
+    let egui::AccessNode {
+        id,
+        layer_id: _,
+        rect,
+        info,
+        labelled_by,
+    } = node;
+
+    let element = document.create_element("div")?;
+    element.set_id(&element_id(*id));
+    element.set_attribute(
+        "style",
+        &format!(
+            "position: absolute; left: {}px; top: {}px; width: {}px; height: {}px;",
+            rect.left(),
+            rect.top(),
+            rect.width(),
+            rect.height()
+        ),
+    )?;
+
+    let role = match info.typ {
+        WidgetType::Label | WidgetType::Other => None,
+        WidgetType::Link => Some("link"),
+        WidgetType::TextEdit => Some("textbox"),
+        WidgetType::Button
+        | WidgetType::ColorButton
+        | WidgetType::ImageButton
+        | WidgetType::SelectableLabel
+        | WidgetType::CollapsingHeader => Some("button"),
+        WidgetType::Checkbox => Some("checkbox"),
+        WidgetType::RadioButton => Some("radio"),
+        WidgetType::ComboBox => Some("combobox"),
+        WidgetType::Slider | WidgetType::DragValue => Some("slider"),
+    };
+    if let Some(role) = role {
+        element.set_attribute("role", role)?;
+    }
+
+    if let Some(label) = &info.label {
+        if role.is_some() {
+            element.set_attribute("aria-label", label)?;
+        } else {
+            element.set_text_content(Some(label));
+        }
+    }
+    if let Some(labelled_by) = labelled_by {
+        element.set_attribute("aria-labelledby", &element_id(*labelled_by))?;
+    }
+    if let Some(text) = &info.current_text_value {
+        element.set_text_content(Some(text));
+    }
+    if let Some(selected) = info.selected {
+        let attribute = match info.typ {
+            WidgetType::Checkbox | WidgetType::RadioButton => "aria-checked",
+            _ => "aria-pressed",
+        };
+        element.set_attribute(attribute, if selected { "true" } else { "false" })?;
+    }
+    if let Some(value) = info.value {
+        element.set_attribute("aria-valuenow", &value.to_string())?;
+    }
+    if !info.enabled {
+        element.set_attribute("aria-disabled", "true")?;
+    }
+
+    Ok(element)
+}
@@ -1,4 +1,4 @@
-use super::{glow_wrapping::WrappedGlowPainter, *};
+use super::{web_painter::WebPainter, *};
 
 use crate::epi;
 
@@ -162,7 +162,7 @@ fn test_parse_query() {
 pub struct AppRunner {
     pub(crate) frame: epi::Frame,
     egui_ctx: egui::Context,
-    painter: WrappedGlowPainter,
+    painter: WebPainter,
     pub(crate) input: WebInput,
     app: Box<dyn epi::App>,
     pub(crate) needs_repaint: std::sync::Arc<NeedRepaint>,
@@ -171,8 +171,22 @@ pub struct AppRunner {
     screen_reader: super::screen_reader::ScreenReader,
     pub(crate) text_cursor_pos: Option<egui::Pos2>,
     pub(crate) mutable_text_under_cursor: bool,
+    /// The latest pointer we got a `PointerEvent` for, used for pointer capture.
+    pub(crate) pointer_id: Option<i32>,
+    /// Have we requested pointer lock because of [`egui::PlatformOutput::lock_pointer`]?
+    pointer_locked: bool,
+    /// Size of the canvas container and `devicePixelRatio` last we checked, see [`Self::has_screen_changed`].
+    last_screen: (egui::Vec2, f32),
+    /// The last custom cursor we showed, and the CSS showing it.
+    custom_cursor: Option<(egui::CustomCursor, String)>,
+    /// The last tree we exposed as ARIA elements.
+    accessibility_tree: Option<egui::AccessibilityTree>,
+    file_dialogs: crate::file_dialog::FileDialogs,
     textures_delta: TexturesDelta,
     pub events_to_unsubscribe: Vec<EventToUnsubscribe>,
+
+    /// The browser took away our WebGL context (e.g. after a GPU reset), so we can't paint until it is restored.
+    pub(crate) is_context_lost: bool,
 }
 
 impl Drop for AppRunner {
@@ -182,14 +196,36 @@ impl Drop for AppRunner {
 }
 
 impl AppRunner {
+    /// Create a runner that paints with WebGL.
+    ///
+    /// Use [`Self::new_async`] to paint with WebGPU when available.
     pub fn new(
         canvas_id: &str,
         web_options: crate::WebOptions,
         app_creator: epi::AppCreator,
     ) -> Result<Self, JsValue> {
-        let painter = WrappedGlowPainter::new(canvas_id, web_options.webgl_context_option)
+        let painter = WebPainter::new_webgl(canvas_id, &web_options).map_err(JsValue::from)?; // fail early
+        Self::with_painter(painter, web_options, app_creator)
+    }
+
+    /// Create a runner that paints with WebGPU if the `wgpu` feature is enabled
+    /// and the browser supports it, and otherwise with WebGL.
+    pub async fn new_async(
+        canvas_id: &str,
+        web_options: crate::WebOptions,
+        app_creator: epi::AppCreator,
+    ) -> Result<Self, JsValue> {
+        let painter = WebPainter::new(canvas_id, &web_options)
+            .await
             .map_err(JsValue::from)?; // fail early
+        Self::with_painter(painter, web_options, app_creator)
+    }
 
+    fn with_painter(
+        painter: WebPainter,
+        web_options: crate::WebOptions,
+        app_creator: epi::AppCreator,
+    ) -> Result<Self, JsValue> {
         let system_theme = if web_options.follow_system_theme {
             super::system_theme()
         } else {
@@ -207,7 +243,7 @@ impl AppRunner {
         let storage = LocalStorage::default();
 
         let egui_ctx = egui::Context::default();
-        load_memory(&egui_ctx);
+        load_memory(&egui_ctx, &storage);
 
         let theme = system_theme.unwrap_or(web_options.default_theme);
         egui_ctx.set_visuals(theme.egui_visuals());
@@ -217,19 +253,20 @@ impl AppRunner {
             integration_info: info.clone(),
             storage: Some(&storage),
             #[cfg(feature = "glow")]
-            gl: Some(painter.painter.gl().clone()),
+            gl: painter.gl().cloned(),
             #[cfg(feature = "wgpu")]
-            render_state: None,
+            render_state: painter.render_state(),
         });
 
         let frame = epi::Frame {
             info,
             output: Default::default(),
+            file_dialog_result: None,
             storage: Some(Box::new(storage)),
             #[cfg(feature = "glow")]
-            gl: Some(painter.gl().clone()),
+            gl: painter.gl().cloned(),
             #[cfg(feature = "wgpu")]
-            render_state: None,
+            render_state: painter.render_state(),
         };
 
         let needs_repaint: std::sync::Arc<NeedRepaint> = Default::default();
@@ -252,11 +289,20 @@ impl AppRunner {
             screen_reader: Default::default(),
             text_cursor_pos: None,
             mutable_text_under_cursor: false,
+            pointer_id: None,
+            pointer_locked: false,
+            last_screen: (egui::Vec2::ZERO, 0.0),
+            custom_cursor: None,
+            accessibility_tree: None,
+            file_dialogs: Default::default(),
             textures_delta: Default::default(),
             events_to_unsubscribe: Default::default(),
+            is_context_lost: false,
         };
 
         runner.input.raw.max_texture_side = Some(runner.painter.max_texture_side());
+        runner.input.raw.system_theme = super::system_theme();
+        runner.egui_ctx.memory().options.follow_system_theme = web_options.follow_system_theme;
 
         Ok(runner)
     }
@@ -265,25 +311,52 @@ impl AppRunner {
         &self.egui_ctx
     }
 
+    /// Save if it has been more than [`epi::App::auto_save_interval`] since last time.
     pub fn auto_save(&mut self) {
-        let now = now_sec();
-        let time_since_last_save = now - self.last_save_time;
-
+        let time_since_last_save = now_sec() - self.last_save_time;
         if time_since_last_save > self.app.auto_save_interval().as_secs_f64() {
+            self.save();
+        }
+    }
+
+    /// Save the egui memory and app state right away, e.g. because the page is being closed.
+    pub fn save(&mut self) {
+        if let Some(storage) = self.frame.storage.as_deref_mut() {
             if self.app.persist_egui_memory() {
-                save_memory(&self.egui_ctx);
+                save_memory(&self.egui_ctx, storage);
             }
-            if let Some(storage) = self.frame.storage_mut() {
-                self.app.save(storage);
-            }
-            self.last_save_time = now;
+            self.app.save(storage);
+            storage.flush();
         }
+        self.last_save_time = now_sec();
     }
 
     pub fn canvas_id(&self) -> &str {
         self.painter.canvas_id()
     }
 
+    /// Has the size of the canvas container or the `devicePixelRatio` changed since last call?
+    ///
+    /// Not all such changes come with a `resize` event, e.g. when the page layout changes
+    /// the size of the container, or when the browser window is moved to another monitor.
+    pub(crate) fn has_screen_changed(&mut self) -> bool {
+        let screen = (
+            canvas_parent_size(self.canvas_id()).unwrap_or_default(),
+            native_pixels_per_point(),
+        );
+        if self.last_screen == screen {
+            false
+        } else {
+            self.last_screen = screen;
+            true
+        }
+    }
+
+    /// Which graphics API is used for painting (WebGPU, WebGL2 or WebGL1), and details about it.
+    pub fn debug_info(&self) -> String {
+        self.painter.debug_info()
+    }
+
     pub fn warm_up(&mut self) -> Result<(), JsValue> {
         if self.app.warm_up_enabled() {
             let saved_memory: egui::Memory = self.egui_ctx.memory().clone();
@@ -313,6 +386,41 @@ impl AppRunner {
         }
     }
 
+    /// Called when the browser takes away our WebGL context, e.g. after a GPU reset.
+    pub fn on_context_lost(&mut self) {
+        tracing::warn!("WebGL context lost. Waiting for it to be restored…");
+        self.is_context_lost = true;
+    }
+
+    /// Called when the browser gives us back a WebGL context.
+    ///
+    /// All GPU resources must be created again, including the textures.
+    /// Apps that use the `glow` context directly must recreate their own resources too,
+    /// using the new context from [`epi::Frame::gl`].
+    pub fn on_context_restored(&mut self) {
+        let painter = match &mut self.painter {
+            WebPainter::Glow(painter) => painter,
+            #[cfg(feature = "wgpu")]
+            WebPainter::Wgpu(_) => return, // not using WebGL
+        };
+        match painter.recreate() {
+            Ok(()) => {
+                tracing::info!("WebGL context restored");
+                self.is_context_lost = false;
+                #[cfg(feature = "glow")]
+                {
+                    self.frame.gl = self.painter.gl().cloned();
+                }
+                self.input.raw.max_texture_side = Some(self.painter.max_texture_side());
+                self.egui_ctx.invalidate_font_texture();
+                self.needs_repaint.repaint_asap();
+            }
+            Err(err) => {
+                tracing::error!("Failed to restore the WebGL context: {}", err);
+            }
+        }
+    }
+
     /// Returns how long to wait until the next repaint.
     ///
     /// Call [`Self::paint`] later to paint
@@ -325,37 +433,47 @@ impl AppRunner {
 
         let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
             self.app.update(egui_ctx, &mut self.frame);
+            self.file_dialogs.show(egui_ctx, &mut self.frame);
         });
         let egui::FullOutput {
             platform_output,
             repaint_after,
             textures_delta,
             shapes,
+            shape_layers,
+            viewports: _, // embedded as `egui::Window`s on the web
         } = full_output;
 
         self.handle_platform_output(platform_output);
         self.textures_delta.append(textures_delta);
-        let clipped_primitives = self.egui_ctx.tessellate(shapes);
+        let clipped_primitives = self.egui_ctx.tessellate_layers(shapes, &shape_layers);
 
         {
             let app_output = self.frame.take_app_output();
-            let epi::backend::AppOutput {} = app_output;
+            let epi::backend::AppOutput {
+                file_dialog,
+                location_hash,
+            } = app_output;
+            if let Some(file_dialog) = file_dialog {
+                self.file_dialogs.open(&self.egui_ctx, file_dialog);
+            }
+            if let Some(location_hash) = location_hash {
+                set_location_hash(&location_hash);
+            }
         }
 
-        self.frame.info.cpu_usage = Some((now_sec() - frame_start) as f32);
+        let frame_time = (now_sec() - frame_start) as f32;
+        self.frame.info.cpu_usage = Some(frame_time);
+        self.egui_ctx.report_frame_time(frame_time);
         Ok((repaint_after, clipped_primitives))
     }
 
-    pub fn clear_color_buffer(&self) {
-        self.painter
-            .clear(self.app.clear_color(&self.egui_ctx.style().visuals));
-    }
-
-    /// Paint the results of the last call to [`Self::logic`].
+    /// Clear the canvas with [`epi::App::clear_color`] and paint the results of the last call to [`Self::logic`].
     pub fn paint(&mut self, clipped_primitives: &[egui::ClippedPrimitive]) -> Result<(), JsValue> {
         let textures_delta = std::mem::take(&mut self.textures_delta);
 
         self.painter.paint_and_update_textures(
+            self.app.clear_color(&self.egui_ctx.style().visuals),
             clipped_primitives,
             self.egui_ctx.pixels_per_point(),
             &textures_delta,
@@ -364,6 +482,37 @@ impl AppRunner {
         Ok(())
     }
 
+    fn set_cursor(
+        &mut self,
+        cursor_icon: egui::CursorIcon,
+        custom_cursor: Option<egui::CustomCursor>,
+    ) {
+        let custom_cursor = match custom_cursor {
+            Some(custom_cursor) => custom_cursor,
+            None => {
+                self.custom_cursor = None;
+                set_cursor_icon(cursor_icon);
+                return;
+            }
+        };
+
+        // Encoding the image is slow, so only do it when the cursor changes:
+        if self.custom_cursor.as_ref().map(|(cursor, _)| cursor) != Some(&custom_cursor) {
+            match super::custom_cursor_css(&custom_cursor, cursor_icon) {
+                Ok(css) => self.custom_cursor = Some((custom_cursor, css)),
+                Err(err) => {
+                    tracing::error!("Failed to create custom cursor: {:?}", err);
+                    self.custom_cursor = None;
+                    set_cursor_icon(cursor_icon);
+                    return;
+                }
+            }
+        }
+        if let Some((_, css)) = &self.custom_cursor {
+            super::set_cursor_css(css);
+        }
+    }
+
     fn handle_platform_output(&mut self, platform_output: egui::PlatformOutput) {
         if self.egui_ctx.options().screen_reader {
             self.screen_reader
@@ -372,32 +521,72 @@ impl AppRunner {
 
         let egui::PlatformOutput {
             cursor_icon,
+            custom_cursor,
             open_url,
             copied_text,
             events: _, // already handled
             mutable_text_under_cursor,
             text_cursor_pos,
+            capture_pointer,
+            lock_pointer,
+            window_commands,
+            accessibility_tree,
         } = platform_output;
 
-        set_cursor_icon(cursor_icon);
+        self.set_cursor(cursor_icon, custom_cursor);
         if let Some(open) = open_url {
             super::open_url(&open.url, open.new_tab);
         }
 
-        #[cfg(web_sys_unstable_apis)]
         if !copied_text.is_empty() {
             set_clipboard_text(&copied_text);
         }
 
-        #[cfg(not(web_sys_unstable_apis))]
-        let _ = copied_text;
-
         self.mutable_text_under_cursor = mutable_text_under_cursor;
 
         if self.text_cursor_pos != text_cursor_pos {
             text_agent::move_text_cursor(text_cursor_pos, self.canvas_id());
             self.text_cursor_pos = text_cursor_pos;
         }
+
+        if capture_pointer {
+            if let (Some(pointer_id), Some(canvas)) =
+                (self.pointer_id, canvas_element(self.canvas_id()))
+            {
+                // Fails if the pointer is no longer down, which is fine.
+                // The capture is released automatically when the pointer is released.
+                canvas.set_pointer_capture(pointer_id).ok();
+            }
+        }
+
+        if self.pointer_locked != lock_pointer {
+            self.pointer_locked = lock_pointer;
+            set_pointer_lock(self.canvas_id(), lock_pointer);
+        }
+
+        if let Some(accessibility_tree) = accessibility_tree {
+            if self.accessibility_tree.as_ref() != Some(&accessibility_tree) {
+                if let Err(err) =
+                    super::aria::update_aria_tree(self.canvas_id(), &accessibility_tree)
+                {
+                    tracing::error!("Failed to update the ARIA elements: {:?}", err);
+                }
+                self.accessibility_tree = Some(accessibility_tree);
+            }
+        }
+
+        for command in window_commands {
+            match command {
+                egui::WindowCommand::SetTitle(title) => {
+                    set_document_title(&title);
+                }
+                // The browser tab is not ours to resize or close:
+                egui::WindowCommand::SetInnerSize(_)
+                | egui::WindowCommand::Minimize
+                | egui::WindowCommand::Maximize(_)
+                | egui::WindowCommand::Close => {}
+            }
+        }
     }
 }
 
@@ -511,6 +700,18 @@ pub fn start(
     start_runner(runner)
 }
 
+/// Like [`start`], but paints with WebGPU if the `wgpu` feature is enabled
+/// and the browser supports it, and otherwise with WebGL.
+pub async fn start_async(
+    canvas_id: &str,
+    web_options: crate::WebOptions,
+    app_creator: epi::AppCreator,
+) -> Result<AppRunnerRef, JsValue> {
+    let mut runner = AppRunner::new_async(canvas_id, web_options, app_creator).await?;
+    runner.warm_up()?;
+    start_runner(runner)
+}
+
 /// Install event listeners to register different input events
 /// and starts running the given [`AppRunner`].
 fn start_runner(app_runner: AppRunner) -> Result<AppRunnerRef, JsValue> {
@@ -541,20 +742,3 @@ fn start_runner(app_runner: AppRunner) -> Result<AppRunnerRef, JsValue> {
 
     Ok(runner_container.runner)
 }
-
-// ----------------------------------------------------------------------------
-
-#[derive(Default)]
-struct LocalStorage {}
-
-impl epi::Storage for LocalStorage {
-    fn get_string(&self, key: &str) -> Option<String> {
-        local_storage_get(key)
-    }
-
-    fn set_string(&mut self, key: &str, value: String) {
-        local_storage_set(key, &value);
-    }
-
-    fn flush(&mut self) {}
-}
@@ -11,9 +11,15 @@ pub fn paint_and_schedule(
         let mut runner_lock = runner_ref.lock();
         let is_destroyed = runner_lock.is_destroyed.fetch();
 
-        if !is_destroyed && runner_lock.needs_repaint.when_to_repaint() <= now_sec() {
+        if !is_destroyed && runner_lock.has_screen_changed() {
+            runner_lock.needs_repaint.repaint_asap();
+        }
+
+        if !is_destroyed
+            && !runner_lock.is_context_lost
+            && runner_lock.needs_repaint.when_to_repaint() <= now_sec()
+        {
             runner_lock.needs_repaint.clear();
-            runner_lock.clear_color_buffer();
             let (repaint_after, clipped_primitives) = runner_lock.logic()?;
             runner_lock.paint(&clipped_primitives)?;
             runner_lock
@@ -52,6 +58,17 @@ pub fn install_document_events(runner_container: &mut AppRunnerContainer) -> Res
     let window = web_sys::window().unwrap();
     let document = window.document().unwrap();
 
+    if let Some(media_query_list) = window.match_media("(prefers-color-scheme: dark)")? {
+        runner_container.add_event_listener(
+            &media_query_list,
+            "change",
+            |_event: web_sys::Event, mut runner_lock| {
+                runner_lock.input.raw.system_theme = system_theme();
+                runner_lock.needs_repaint.repaint_asap();
+            },
+        )?;
+    }
+
     runner_container.add_event_listener(
         &document,
         "keydown",
@@ -133,12 +150,11 @@ pub fn install_document_events(runner_container: &mut AppRunnerContainer) -> Res
         },
     )?;
 
-    #[cfg(web_sys_unstable_apis)]
     runner_container.add_event_listener(
         &document,
         "paste",
-        |event: web_sys::ClipboardEvent, mut runner_lock| {
-            if let Some(data) = event.clipboard_data() {
+        |event: web_sys::Event, mut runner_lock| {
+            if let Some(data) = clipboard_data(&event) {
                 if let Ok(text) = data.get_data("text") {
                     let text = text.replace("\r\n", "\n");
                     if !text.is_empty() {
@@ -152,26 +168,36 @@ pub fn install_document_events(runner_container: &mut AppRunnerContainer) -> Res
         },
     )?;
 
-    #[cfg(web_sys_unstable_apis)]
     runner_container.add_event_listener(
         &document,
         "cut",
-        |_: web_sys::ClipboardEvent, mut runner_lock| {
+        |_: web_sys::Event, mut runner_lock| {
             runner_lock.input.raw.events.push(egui::Event::Cut);
             runner_lock.needs_repaint.repaint_asap();
         },
     )?;
 
-    #[cfg(web_sys_unstable_apis)]
     runner_container.add_event_listener(
         &document,
         "copy",
-        |_: web_sys::ClipboardEvent, mut runner_lock| {
+        |_: web_sys::Event, mut runner_lock| {
+            if is_copying_with_fallback() {
+                return; // we triggered this ourselves, in `set_clipboard_text`
+            }
             runner_lock.input.raw.events.push(egui::Event::Copy);
             runner_lock.needs_repaint.repaint_asap();
         },
     )?;
 
+    // The page may be closed after this, and won't get another chance to auto-save:
+    runner_container.add_event_listener(
+        &window,
+        "pagehide",
+        |_: web_sys::Event, mut runner_lock| {
+            runner_lock.save();
+        },
+    )?;
+
     for event_name in &["load", "pagehide", "pageshow", "resize"] {
         runner_container.add_event_listener(
             &window,
@@ -182,18 +208,33 @@ pub fn install_document_events(runner_container: &mut AppRunnerContainer) -> Res
         )?;
     }
 
-    runner_container.add_event_listener(
-        &window,
-        "hashchange",
-        |_: web_sys::Event, mut runner_lock| {
-            // `epi::Frame::info(&self)` clones `epi::IntegrationInfo`, but we need to modify the original here
-            runner_lock.frame.info.web_info.location.hash = location_hash();
-        },
-    )?;
+    // The user navigated back or forward, or something else changed the URL:
+    for event_name in ["hashchange", "popstate"] {
+        runner_container.add_event_listener(
+            &window,
+            event_name,
+            |_: web_sys::Event, mut runner_lock| {
+                // `epi::Frame::info(&self)` clones `epi::IntegrationInfo`, but we need to modify the original here
+                runner_lock.frame.info.web_info.location.hash = location_hash();
+                runner_lock.needs_repaint.repaint_asap();
+            },
+        )?;
+    }
 
     Ok(())
 }
 
+/// The `clipboardData` of a `ClipboardEvent`.
+///
+/// `web_sys::ClipboardEvent` requires `web_sys_unstable_apis`, so we read it by hand.
+fn clipboard_data(event: &web_sys::Event) -> Option<web_sys::DataTransfer> {
+    use wasm_bindgen::JsCast as _;
+    js_sys::Reflect::get(event, &JsValue::from_str("clipboardData"))
+        .ok()?
+        .dyn_into()
+        .ok()
+}
+
 pub fn install_canvas_events(runner_container: &mut AppRunnerContainer) -> Result<(), JsValue> {
     let canvas = canvas_element(runner_container.runner.lock().canvas_id()).unwrap();
 
@@ -211,6 +252,49 @@ pub fn install_canvas_events(runner_container: &mut AppRunnerContainer) -> Resul
         runner_container.add_event_listener(&canvas, event_name, closure)?;
     }
 
+    runner_container.add_event_listener(
+        &canvas,
+        "webglcontextlost",
+        |event: web_sys::Event, mut runner_lock: egui::mutex::MutexGuard<AppRunner>| {
+            // Tell the browser we want the context back when possible:
+            event.prevent_default();
+            runner_lock.on_context_lost();
+        },
+    )?;
+
+    runner_container.add_event_listener(
+        &canvas,
+        "webglcontextrestored",
+        |_event: web_sys::Event, mut runner_lock: egui::mutex::MutexGuard<AppRunner>| {
+            runner_lock.on_context_restored();
+        },
+    )?;
+
+    for event_name in ["pointerdown", "pointermove"] {
+        // We get the position and buttons from the mouse events that follow,
+        // but only pointer events know about pen pressure and tilt, and which pointer to capture:
+        runner_container.add_event_listener(
+            &canvas,
+            event_name,
+            |event: web_sys::PointerEvent, mut runner_lock: egui::mutex::MutexGuard<AppRunner>| {
+                runner_lock.pointer_id = Some(event.pointer_id());
+                if event.pointer_type() == "pen" {
+                    let tilt = egui::vec2(event.tilt_x() as f32, event.tilt_y() as f32);
+                    runner_lock
+                        .input
+                        .raw
+                        .events
+                        .push(egui::Event::PointerPressure {
+                            pressure: event.pressure(),
+                            tilt: Some(tilt * (std::f32::consts::PI / 180.0)),
+                        });
+                    runner_lock.needs_repaint.repaint_asap();
+                }
+                // Note: prevent_default here would suppress the mouse events.
+            },
+        )?;
+    }
+
     runner_container.add_event_listener(
         &canvas,
         "mousedown",
@@ -245,6 +329,14 @@ pub fn install_canvas_events(runner_container: &mut AppRunnerContainer) -> Resul
                 .raw
                 .events
                 .push(egui::Event::PointerMoved(pos));
+            runner_lock
+                .input
+                .raw
+                .events
+                .push(egui::Event::MouseMoved(egui::vec2(
+                    event.movement_x() as f32,
+                    event.movement_y() as f32,
+                )));
             runner_lock.needs_repaint.repaint_asap();
             event.stop_propagation();
             event.prevent_default();
@@ -1,5 +1,6 @@
 use crate::WebGlContextOption;
-use egui::{ClippedPrimitive, Rgba};
+use egui::epaint::{ImageData, ImageDelta};
+use egui::{ClippedPrimitive, Rgba, TextureId};
 use egui_glow::glow;
 use wasm_bindgen::JsCast;
 use wasm_bindgen::JsValue;
@@ -11,25 +12,63 @@ pub(crate) struct WrappedGlowPainter {
     pub(crate) canvas: HtmlCanvasElement,
     pub(crate) canvas_id: String,
     pub(crate) painter: egui_glow::Painter,
+    options: WebGlContextOption,
+    /// Which WebGL version we ended up with: `"WebGL1"` or `"WebGL2"`.
+    webgl_version: &'static str,
+
+    /// A copy of every user texture, so that we can upload them again if the WebGL context is lost.
+    ///
+    /// The font texture is not in here: egui can send us that again
+    /// (see [`egui::Context::invalidate_font_texture`]).
+    user_textures: std::collections::HashMap<TextureId, ImageDelta>,
 }
 
 impl WrappedGlowPainter {
     pub fn new(canvas_id: &str, options: WebGlContextOption) -> Result<Self, String> {
         let canvas = super::canvas_element_or_die(canvas_id);
-
-        let (gl, shader_prefix) = init_glow_context_from_canvas(&canvas, options)?;
-        let gl = std::sync::Arc::new(gl);
-
-        let dimension = [canvas.width() as i32, canvas.height() as i32];
-        let painter = egui_glow::Painter::new(gl, Some(dimension), shader_prefix)
-            .map_err(|error| format!("Error starting glow painter: {}", error))?;
+        let (painter, webgl_version) = create_painter(&canvas, options)?;
 
         Ok(Self {
             canvas,
             canvas_id: canvas_id.to_owned(),
             painter,
+            options,
+            webgl_version,
+            user_textures: Default::default(),
         })
     }
+
+    /// Create a new painter after the WebGL context was lost and then restored,
+    /// and upload all user textures again.
+    ///
+    /// The caller must ask egui for the font texture with [`egui::Context::invalidate_font_texture`].
+    ///
+    /// All GPU resources of the old painter are already gone, so there is nothing to free.
+    pub fn recreate(&mut self) -> Result<(), String> {
+        let (painter, webgl_version) = create_painter(&self.canvas, self.options)?;
+        self.webgl_version = webgl_version;
+        let mut old_painter = std::mem::replace(&mut self.painter, painter);
+        old_painter.destroy(); // no-op on a lost context, but silences the leak warning
+
+        for (id, delta) in &self.user_textures {
+            self.painter.set_texture(*id, delta);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the painter and the WebGL version.
+fn create_painter(
+    canvas: &HtmlCanvasElement,
+    options: WebGlContextOption,
+) -> Result<(egui_glow::Painter, &'static str), String> {
+    let (gl, shader_prefix, webgl_version) = init_glow_context_from_canvas(canvas, options)?;
+    let gl = std::sync::Arc::new(gl);
+
+    let dimension = [canvas.width() as i32, canvas.height() as i32];
+    let painter = egui_glow::Painter::new(gl, Some(dimension), shader_prefix)
+        .map_err(|error| format!("Error starting glow painter: {}", error))?;
+    Ok((painter, webgl_version))
 }
 
 impl WrappedGlowPainter {
@@ -45,12 +84,42 @@ impl WrappedGlowPainter {
         &self.canvas_id
     }
 
-    pub fn set_texture(&mut self, tex_id: egui::TextureId, delta: &egui::epaint::ImageDelta) {
+    /// Which WebGL version was chosen, and what the browser reports about it.
+    pub fn debug_info(&self) -> String {
+        use egui_glow::glow::HasContext as _;
+        let gl = self.painter.gl();
+        let (version, renderer) = unsafe {
+            (
+                gl.get_parameter_string(glow::VERSION),
+                gl.get_parameter_string(glow::RENDERER),
+            )
+        };
+        format!(
+            "{} (version: {:?}, renderer: {:?})",
+            self.webgl_version, version, renderer
+        )
+    }
+
+    pub fn set_texture(&mut self, tex_id: TextureId, delta: &ImageDelta) {
         self.painter.set_texture(tex_id, delta);
+
+        if tex_id == TextureId::default() {
+            return; // the font texture, which egui can send again
+        }
+
+        if let Some(pos) = delta.pos {
+            if let Some(copy) = self.user_textures.get_mut(&tex_id) {
+                copy.options = delta.options;
+                patch_image(&mut copy.image, &delta.image, pos);
+            }
+        } else {
+            self.user_textures.insert(tex_id, delta.clone());
+        }
     }
 
-    pub fn free_texture(&mut self, tex_id: egui::TextureId) {
+    pub fn free_texture(&mut self, tex_id: TextureId) {
         self.painter.free_texture(tex_id);
+        self.user_textures.remove(&tex_id);
     }
 
     pub fn clear(&self, clear_color: Rgba) {
@@ -93,11 +162,56 @@ impl WrappedGlowPainter {
     }
 }
 
-/// Returns glow context and shader prefix.
+/// Copy `patch` into `image` at `pos`.
+fn patch_image(image: &mut ImageData, patch: &ImageData, pos: [usize; 2]) {
+    match (image, patch) {
+        (ImageData::Color(image), ImageData::Color(patch)) => {
+            patch_pixels(
+                &mut image.pixels,
+                image.size,
+                &patch.pixels,
+                patch.size,
+                pos,
+            );
+        }
+        (ImageData::Font(image), ImageData::Font(patch)) => {
+            patch_pixels(
+                &mut image.pixels,
+                image.size,
+                &patch.pixels,
+                patch.size,
+                pos,
+            );
+        }
+        _ => {
+            tracing::warn!("Texture patch of a different kind than the texture; ignoring it");
+        }
+    }
+}
+
+fn patch_pixels<T: Copy>(
+    pixels: &mut [T],
+    [width, height]: [usize; 2],
+    patch: &[T],
+    [patch_width, patch_height]: [usize; 2],
+    [x, y]: [usize; 2],
+) {
+    if width < x + patch_width || height < y + patch_height {
+        tracing::warn!("Texture patch outside of the texture; ignoring it");
+        return;
+    }
+    for row in 0..patch_height {
+        let start = (y + row) * width + x;
+        pixels[start..start + patch_width]
+            .copy_from_slice(&patch[row * patch_width..(row + 1) * patch_width]);
+    }
+}
+
+/// Returns glow context, shader prefix and WebGL version.
 fn init_glow_context_from_canvas(
     canvas: &HtmlCanvasElement,
     options: WebGlContextOption,
-) -> Result<(glow::Context, &'static str), String> {
+) -> Result<(glow::Context, &'static str, &'static str), String> {
     let result = match options {
         // Force use WebGl1
         WebGlContextOption::WebGl1 => init_webgl1(canvas),
@@ -118,7 +232,7 @@ fn init_glow_context_from_canvas(
     }
 }
 
-fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str)> {
+fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str, &'static str)> {
     let gl1_ctx = canvas
         .get_context("webgl")
         .expect("Failed to query about WebGL2 context");
@@ -139,10 +253,10 @@ fn init_webgl1(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static st
 
     let gl = glow::Context::from_webgl1_context(gl1_ctx);
 
-    Some((gl, shader_prefix))
+    Some((gl, shader_prefix, "WebGL1"))
 }
 
-fn init_webgl2(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str)> {
+fn init_webgl2(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static str, &'static str)> {
     let gl2_ctx = canvas
         .get_context("webgl2")
         .expect("Failed to query about WebGL2 context");
@@ -156,7 +270,7 @@ fn init_webgl2(canvas: &HtmlCanvasElement) -> Option<(glow::Context, &'static st
     let gl = glow::Context::from_webgl2_context(gl2_ctx);
     let shader_prefix = "";
 
-    Some((gl, shader_prefix))
+    Some((gl, shader_prefix, "WebGL2"))
 }
 
 trait DummyWebGLConstructor {
@@ -132,6 +132,9 @@ pub fn translate_key(key: &str) -> Option<egui::Key> {
         "PageUp" => Some(egui::Key::PageUp),
         "PageDown" => Some(egui::Key::PageDown),
 
+        "-" => Some(egui::Key::Minus),
+        "+" | "=" => Some(egui::Key::PlusEquals),
+
         "0" => Some(egui::Key::Num0),
         "1" => Some(egui::Key::Num1),
         "2" => Some(egui::Key::Num2),
@@ -2,6 +2,7 @@
 
 #![allow(clippy::missing_errors_doc)] // So many `-> Result<_, JsValue>`
 
+mod aria;
 pub mod backend;
 mod events;
 mod glow_wrapping;
@@ -9,6 +10,9 @@ mod input;
 pub mod screen_reader;
 pub mod storage;
 mod text_agent;
+mod web_painter;
+#[cfg(feature = "wgpu")]
+mod wgpu_wrapping;
 
 pub use backend::*;
 pub use events::*;
@@ -96,17 +100,18 @@ pub fn canvas_size_in_points(canvas_id: &str) -> egui::Vec2 {
     )
 }
 
+/// The size of the element containing the canvas, which is the size we resize the canvas to.
+fn canvas_parent_size(canvas_id: &str) -> Option<Vec2> {
+    let parent = canvas_element(canvas_id)?.parent_element()?;
+    Some(Vec2 {
+        x: parent.scroll_width() as f32,
+        y: parent.scroll_height() as f32,
+    })
+}
+
 pub fn resize_canvas_to_screen_size(canvas_id: &str, max_size_points: egui::Vec2) -> Option<()> {
     let canvas = canvas_element(canvas_id)?;
-    let parent = canvas.parent_element()?;
-
-    let width = parent.scroll_width();
-    let height = parent.scroll_height();
-
-    let canvas_real_size = Vec2 {
-        x: width as f32,
-        y: height as f32,
-    };
+    let canvas_real_size = canvas_parent_size(canvas_id)?;
 
     let pixels_per_point = native_pixels_per_point();
 
@@ -145,17 +150,86 @@ pub fn resize_canvas_to_screen_size(canvas_id: &str, max_size_points: egui::Vec2
 
 // ----------------------------------------------------------------------------
 
+pub fn set_document_title(title: &str) -> Option<()> {
+    web_sys::window()?.document()?.set_title(title);
+    Some(())
+}
+
+/// Lock (and hide) the pointer to the canvas, or release it again.
+///
+/// The browser only grants the lock shortly after a user gesture (e.g. a click),
+/// and releases it by itself when the user presses escape.
+pub fn set_pointer_lock(canvas_id: &str, locked: bool) -> Option<()> {
+    if locked {
+        canvas_element(canvas_id)?.request_pointer_lock();
+    } else {
+        web_sys::window()?.document()?.exit_pointer_lock();
+    }
+    Some(())
+}
+
 pub fn set_cursor_icon(cursor: egui::CursorIcon) -> Option<()> {
+    set_cursor_css(cursor_web_name(cursor))
+}
+
+/// Set the CSS `cursor` property of the body, e.g. to what [`custom_cursor_css`] returns.
+pub fn set_cursor_css(css: &str) -> Option<()> {
     let document = web_sys::window()?.document()?;
-    document
-        .body()?
-        .style()
-        .set_property("cursor", cursor_web_name(cursor))
-        .ok()
+    document.body()?.style().set_property("cursor", css).ok()
+}
+
+/// The CSS `cursor` value for showing the image of the given cursor,
+/// falling back to `fallback` if the browser rejects the image (e.g. because it is too large).
+pub fn custom_cursor_css(
+    cursor: &egui::CustomCursor,
+    fallback: egui::CursorIcon,
+) -> Result<String, JsValue> {
+    use wasm_bindgen::JsCast as _;
+
+    let [width, height] = cursor.image.size;
+    let rgba: Vec<u8> = cursor
+        .image
+        .pixels
+        .iter()
+        .flat_map(|color| color.to_srgba_unmultiplied())
+        .collect();
+    let image_data = web_sys::ImageData::new_with_u8_clamped_array_and_sh(
+        wasm_bindgen::Clamped(&rgba),
+        width as u32,
+        height as u32,
+    )?;
+
+    // Encode the image as a PNG data url by drawing it onto a canvas that is never shown:
+    let canvas: web_sys::HtmlCanvasElement = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document"))?
+        .create_element("canvas")?
+        .dyn_into()?;
+    canvas.set_width(width as u32);
+    canvas.set_height(height as u32);
+    let context: web_sys::CanvasRenderingContext2d = canvas
+        .get_context("2d")?
+        .ok_or_else(|| JsValue::from_str("no 2d context"))?
+        .dyn_into()?;
+    context.put_image_data(&image_data, 0.0, 0.0)?;
+    let url = canvas.to_data_url()?;
+
+    let [x, y] = cursor.hotspot;
+    Ok(format!(
+        "url({}) {} {}, {}",
+        url,
+        x,
+        y,
+        cursor_web_name(fallback)
+    ))
 }
 
-#[cfg(web_sys_unstable_apis)]
+/// Put text in the clipboard.
+///
+/// Uses the async Clipboard API when available (requires `web_sys_unstable_apis`
+/// and a secure context), and falls back to `document.execCommand("copy")` otherwise.
 pub fn set_clipboard_text(s: &str) {
+    #[cfg(web_sys_unstable_apis)]
     if let Some(window) = web_sys::window() {
         if let Some(clipboard) = window.navigator().clipboard() {
             let promise = clipboard.write_text(s);
@@ -166,8 +240,57 @@ pub fn set_clipboard_text(s: &str) {
                 }
             };
             wasm_bindgen_futures::spawn_local(future);
+            return;
         }
     }
+
+    if set_clipboard_text_with_exec_command(s).is_none() {
+        tracing::error!("Copy/cut action denied");
+    }
+}
+
+/// Set while we call `document.execCommand("copy")`, so we can ignore the resulting `copy` event.
+static IS_COPYING_WITH_FALLBACK: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn is_copying_with_fallback() -> bool {
+    IS_COPYING_WITH_FALLBACK.load(Ordering::SeqCst)
+}
+
+/// `execCommand("copy")` copies the current selection,
+/// so we put the text in a hidden `<textarea>` and select it.
+fn set_clipboard_text_with_exec_command(s: &str) -> Option<()> {
+    use wasm_bindgen::JsCast as _;
+
+    let document = web_sys::window()?.document()?;
+    let previously_focused = document.active_element();
+
+    let textarea = document
+        .create_element("textarea")
+        .ok()?
+        .dyn_into::<web_sys::HtmlTextAreaElement>()
+        .ok()?;
+    textarea.set_value(s);
+    let style = textarea.style();
+    style.set_property("position", "fixed").ok()?;
+    style.set_property("opacity", "0").ok()?;
+    document.body()?.append_child(&textarea).ok()?;
+    textarea.select();
+
+    IS_COPYING_WITH_FALLBACK.store(true, Ordering::SeqCst);
+    let copied = document
+        .dyn_ref::<web_sys::HtmlDocument>()?
+        .exec_command("copy")
+        .unwrap_or(false);
+    IS_COPYING_WITH_FALLBACK.store(false, Ordering::SeqCst);
+
+    textarea.remove();
+    if let Some(element) =
+        previously_focused.and_then(|e| e.dyn_into::<web_sys::HtmlElement>().ok())
+    {
+        element.focus().ok();
+    }
+
+    copied.then(|| ())
 }
 
 fn cursor_web_name(cursor: egui::CursorIcon) -> &'static str {
@@ -234,6 +357,11 @@ pub fn location_hash() -> String {
     )
 }
 
+/// Adds an entry to the browser history.
+pub fn set_location_hash(hash: &str) -> Option<()> {
+    web_sys::window()?.location().set_hash(hash).ok()
+}
+
 pub fn percent_decode(s: &str) -> String {
     percent_encoding::percent_decode_str(s)
         .decode_utf8_lossy()
@@ -1,3 +1,5 @@
+use crate::epi;
+
 fn local_storage() -> Option<web_sys::Storage> {
     web_sys::window()?.local_storage().ok()?
 }
@@ -10,28 +12,71 @@ pub fn local_storage_set(key: &str, value: &str) {
     local_storage().map(|storage| storage.set_item(key, value));
 }
 
+pub fn local_storage_remove(key: &str) {
+    local_storage().map(|storage| storage.remove_item(key));
+}
+
+/// [`epi::Storage`] backed by the browser's `localStorage`.
+#[derive(Default)]
+pub struct LocalStorage {}
+
+impl epi::Storage for LocalStorage {
+    fn get_string(&self, key: &str) -> Option<String> {
+        local_storage_get(key)
+    }
+
+    fn set_string(&mut self, key: &str, value: String) {
+        local_storage_set(key, &value);
+    }
+
+    fn flush(&mut self) {}
+}
+
+// ----------------------------------------------------------------------------
+
+#[cfg(feature = "persistence")]
+const MEMORY_KEY: &str = "egui_memory_ron";
+
+/// Stored next to the memory, so that we can discard memory saved by another version of egui,
+/// which may not have the same format.
+#[cfg(feature = "persistence")]
+const MEMORY_VERSION_KEY: &str = "egui_memory_version";
+
 #[cfg(feature = "persistence")]
-pub fn load_memory(ctx: &egui::Context) {
-    if let Some(memory_string) = local_storage_get("egui_memory_ron") {
+const MEMORY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Load the egui memory from the given storage, unless it was saved by another version.
+#[cfg(feature = "persistence")]
+pub fn load_memory(ctx: &egui::Context, storage: &dyn epi::Storage) {
+    if storage.get_string(MEMORY_VERSION_KEY).as_deref() != Some(MEMORY_VERSION) {
+        if storage.get_string(MEMORY_KEY).is_some() {
+            tracing::info!("Ignoring egui memory saved by another version of eframe");
+        }
+        return;
+    }
+
+    if let Some(memory_string) = storage.get_string(MEMORY_KEY) {
         match ron::from_str(&memory_string) {
             Ok(memory) => {
                 *ctx.memory() = memory;
             }
             Err(err) => {
-                tracing::error!("Failed to parse memory RON: {}", err);
+                tracing::error!("Failed to parse memory RON, ignoring it: {}", err);
             }
         }
     }
 }
 
 #[cfg(not(feature = "persistence"))]
-pub fn load_memory(_: &egui::Context) {}
+pub fn load_memory(_: &egui::Context, _: &dyn epi::Storage) {}
 
+/// Save the egui memory to the given storage, together with the current version.
 #[cfg(feature = "persistence")]
-pub fn save_memory(ctx: &egui::Context) {
+pub fn save_memory(ctx: &egui::Context, storage: &mut dyn epi::Storage) {
     match ron::to_string(&*ctx.memory()) {
         Ok(ron) => {
-            local_storage_set("egui_memory_ron", &ron);
+            storage.set_string(MEMORY_KEY, ron);
+            storage.set_string(MEMORY_VERSION_KEY, MEMORY_VERSION.to_owned());
         }
         Err(err) => {
             tracing::error!("Failed to serialize memory as RON: {}", err);
@@ -40,4 +85,4 @@ pub fn save_memory(ctx: &egui::Context) {
 }
 
 #[cfg(not(feature = "persistence"))]
-pub fn save_memory(_: &egui::Context) {}
+pub fn save_memory(_: &egui::Context, _: &mut dyn epi::Storage) {}
@@ -0,0 +1,119 @@
+use egui::{ClippedPrimitive, Rgba};
+use wasm_bindgen::JsValue;
+
+use super::glow_wrapping::WrappedGlowPainter;
+#[cfg(feature = "wgpu")]
+use super::wgpu_wrapping::WrappedWgpuPainter;
+
+/// Paints with WebGPU if we can, and otherwise with WebGL.
+#[allow(clippy::large_enum_variant)] // there is only one of these
+pub(crate) enum WebPainter {
+    Glow(WrappedGlowPainter),
+    #[cfg(feature = "wgpu")]
+    Wgpu(WrappedWgpuPainter),
+}
+
+impl WebPainter {
+    /// With the `wgpu` feature, try WebGPU first and fall back to WebGL if the browser does not support it.
+    #[allow(clippy::unused_async)] // only awaits with the `wgpu` feature
+    pub async fn new(canvas_id: &str, options: &crate::WebOptions) -> Result<Self, String> {
+        #[cfg(feature = "wgpu")]
+        match WrappedWgpuPainter::new(canvas_id).await {
+            Ok(painter) => {
+                tracing::debug!("Painting with WebGPU");
+                return Ok(Self::Wgpu(painter));
+            }
+            Err(err) => {
+                tracing::info!("Can't paint with WebGPU ({}). Falling back to WebGL.", err);
+            }
+        }
+
+        Self::new_webgl(canvas_id, options)
+    }
+
+    pub fn new_webgl(canvas_id: &str, options: &crate::WebOptions) -> Result<Self, String> {
+        let painter = WrappedGlowPainter::new(canvas_id, options.webgl_context_option)?;
+        tracing::debug!("Painting with {}", painter.debug_info());
+        Ok(Self::Glow(painter))
+    }
+
+    pub fn canvas_id(&self) -> &str {
+        match self {
+            Self::Glow(painter) => painter.canvas_id(),
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(painter) => painter.canvas_id(),
+        }
+    }
+
+    pub fn max_texture_side(&self) -> usize {
+        match self {
+            Self::Glow(painter) => painter.max_texture_side(),
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(painter) => painter.max_texture_side(),
+        }
+    }
+
+    /// Which graphics API is used for painting, and details about it.
+    pub fn debug_info(&self) -> String {
+        match self {
+            Self::Glow(painter) => painter.debug_info(),
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => "WebGPU".to_owned(),
+        }
+    }
+
+    /// The `glow` context, if we are painting with WebGL.
+    #[cfg(feature = "glow")]
+    pub fn gl(&self) -> Option<&std::sync::Arc<glow::Context>> {
+        match self {
+            Self::Glow(painter) => Some(painter.gl()),
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => None,
+        }
+    }
+
+    /// The `wgpu` device and friends, if we are painting with WebGPU.
+    #[cfg(feature = "wgpu")]
+    pub fn render_state(&self) -> Option<egui_wgpu::RenderState> {
+        match self {
+            Self::Glow(_) => None,
+            Self::Wgpu(painter) => Some(painter.render_state()),
+        }
+    }
+
+    /// Clear the canvas with `clear_color`, then paint.
+    pub fn paint_and_update_textures(
+        &mut self,
+        clear_color: Rgba,
+        clipped_primitives: &[ClippedPrimitive],
+        pixels_per_point: f32,
+        textures_delta: &egui::TexturesDelta,
+    ) -> Result<(), JsValue> {
+        match self {
+            Self::Glow(painter) => {
+                painter.clear(clear_color);
+                painter.paint_and_update_textures(
+                    clipped_primitives,
+                    pixels_per_point,
+                    textures_delta,
+                )
+            }
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(painter) => painter.paint_and_update_textures(
+                clear_color,
+                clipped_primitives,
+                pixels_per_point,
+                textures_delta,
+            ),
+        }
+    }
+
+    pub fn destroy(&mut self) {
+        match self {
+            Self::Glow(painter) => painter.destroy(),
+            // The device and surface are freed when dropped:
+            #[cfg(feature = "wgpu")]
+            Self::Wgpu(_) => {}
+        }
+    }
+}
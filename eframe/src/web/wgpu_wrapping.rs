@@ -0,0 +1,173 @@
+use std::sync::Arc;
+
+use egui::{mutex::RwLock, ClippedPrimitive, Rgba};
+use egui_wgpu::{renderer, wgpu, RenderState};
+use wasm_bindgen::JsValue;
+use web_sys::HtmlCanvasElement;
+
+/// Paints with WebGPU, via [`egui_wgpu`].
+pub(crate) struct WrappedWgpuPainter {
+    canvas: HtmlCanvasElement,
+    canvas_id: String,
+    surface: wgpu::Surface,
+    /// The size the surface was last configured with, in pixels.
+    surface_size: [u32; 2],
+    render_state: RenderState,
+}
+
+impl WrappedWgpuPainter {
+    /// Fails if the browser does not support WebGPU, or we could not get a device.
+    ///
+    /// Nothing is done to the canvas before we have a device,
+    /// so on failure the canvas can still be used for WebGL.
+    pub async fn new(canvas_id: &str) -> Result<Self, String> {
+        if !is_webgpu_supported() {
+            return Err("The browser does not support WebGPU".to_owned());
+        }
+
+        let canvas = super::canvas_element_or_die(canvas_id);
+        let instance = wgpu::Instance::new(wgpu::Backends::BROWSER_WEBGPU);
+
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await
+            .ok_or_else(|| "No WebGPU adapter found".to_owned())?;
+
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("egui"),
+                    features: wgpu::Features::empty(),
+                    limits: wgpu::Limits::default(),
+                },
+                None,
+            )
+            .await
+            .map_err(|err| format!("Failed to create a WebGPU device: {}", err))?;
+
+        // Only now that we know WebGPU works do we claim the canvas for it:
+        let surface = instance.create_surface_from_canvas(&canvas);
+        let target_format = surface.get_supported_formats(&adapter)[0];
+        let egui_rpass = renderer::RenderPass::new(&device, target_format, 1);
+
+        Ok(Self {
+            canvas,
+            canvas_id: canvas_id.to_owned(),
+            surface,
+            surface_size: [0, 0],
+            render_state: RenderState {
+                device: Arc::new(device),
+                queue: Arc::new(queue),
+                target_format,
+                egui_rpass: Arc::new(RwLock::new(egui_rpass)),
+            },
+        })
+    }
+
+    pub fn render_state(&self) -> RenderState {
+        self.render_state.clone()
+    }
+
+    pub fn max_texture_side(&self) -> usize {
+        self.render_state.device.limits().max_texture_dimension_2d as usize
+    }
+
+    pub fn canvas_id(&self) -> &str {
+        &self.canvas_id
+    }
+
+    pub fn paint_and_update_textures(
+        &mut self,
+        clear_color: Rgba,
+        clipped_primitives: &[ClippedPrimitive],
+        pixels_per_point: f32,
+        textures_delta: &egui::TexturesDelta,
+    ) -> Result<(), JsValue> {
+        let size_in_pixels = [self.canvas.width(), self.canvas.height()];
+        if size_in_pixels[0] == 0 || size_in_pixels[1] == 0 {
+            return Ok(()); // can't configure a surface of zero size
+        }
+        if self.surface_size != size_in_pixels {
+            self.surface.configure(
+                &self.render_state.device,
+                &wgpu::SurfaceConfiguration {
+                    usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+                    format: self.render_state.target_format,
+                    width: size_in_pixels[0],
+                    height: size_in_pixels[1],
+                    present_mode: wgpu::PresentMode::Fifo,
+                },
+            );
+            self.surface_size = size_in_pixels;
+        }
+
+        let output_frame = self.surface.get_current_texture().map_err(|err| {
+            JsValue::from_str(&format!("Failed to get the next WebGPU texture: {}", err))
+        })?;
+        let output_view = output_frame
+            .texture
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let RenderState {
+            device,
+            queue,
+            egui_rpass,
+            ..
+        } = &self.render_state;
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("egui_webgpu"),
+        });
+
+        let screen_descriptor = renderer::ScreenDescriptor {
+            size_in_pixels,
+            pixels_per_point,
+        };
+
+        {
+            let mut rpass = egui_rpass.write();
+            for (id, image_delta) in &textures_delta.set {
+                rpass.update_texture(device, queue, *id, image_delta);
+            }
+            rpass.update_buffers(device, queue, clipped_primitives, &screen_descriptor);
+        }
+
+        egui_rpass.read().execute(
+            &mut encoder,
+            &output_view,
+            clipped_primitives,
+            &screen_descriptor,
+            Some(wgpu::Color {
+                r: clear_color.r() as f64,
+                g: clear_color.g() as f64,
+                b: clear_color.b() as f64,
+                a: clear_color.a() as f64,
+            }),
+        );
+
+        {
+            let mut rpass = egui_rpass.write();
+            for id in &textures_delta.free {
+                rpass.free_texture(id);
+            }
+        }
+
+        queue.submit(std::iter::once(encoder.finish()));
+        output_frame.present();
+
+        Ok(())
+    }
+}
+
+/// Does the browser have `navigator.gpu`?
+///
+/// We must check this ourselves, since [`wgpu`] assumes it is there.
+fn is_webgpu_supported() -> bool {
+    web_sys::window().map_or(false, |window| {
+        js_sys::Reflect::has(&window.navigator(), &JsValue::from_str("gpu")).unwrap_or(false)
+    })
+}
@@ -528,14 +528,22 @@ impl RenderPass {
                 format: wgpu::TextureFormat::Rgba8UnormSrgb,
                 usage: wgpu::TextureUsages::TEXTURE_BINDING | wgpu::TextureUsages::COPY_DST,
             });
-            let filter = match image_delta.filter {
+            // `image_delta.options.mipmap_mode` is ignored, see the docs of `TextureOptions::mipmap_mode`.
+            let filter = match image_delta.options.filter {
                 egui::TextureFilter::Nearest => wgpu::FilterMode::Nearest,
                 egui::TextureFilter::Linear => wgpu::FilterMode::Linear,
             };
+            let address_mode = match image_delta.options.wrap_mode {
+                egui::TextureWrapMode::ClampToEdge => wgpu::AddressMode::ClampToEdge,
+                egui::TextureWrapMode::Repeat => wgpu::AddressMode::Repeat,
+                egui::TextureWrapMode::MirroredRepeat => wgpu::AddressMode::MirrorRepeat,
+            };
             let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
                 label: None,
                 mag_filter: filter,
                 min_filter: filter,
+                address_mode_u: address_mode,
+                address_mode_v: address_mode,
                 ..Default::default()
             });
             let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
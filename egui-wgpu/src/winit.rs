@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use egui::mutex::RwLock;
 use tracing::error;
@@ -34,7 +34,8 @@ pub struct Painter<'a> {
     instance: Instance,
     adapter: Option<Adapter>,
     render_state: Option<RenderState>,
-    surface_state: Option<SurfaceState>,
+    /// One surface per window, see [`Self::set_viewport_window`].
+    surfaces: HashMap<egui::ViewportId, SurfaceState>,
 }
 
 impl<'a> Painter<'a> {
@@ -68,7 +69,7 @@ impl<'a> Painter<'a> {
             instance,
             adapter: None,
             render_state: None,
-            surface_state: None,
+            surfaces: Default::default(),
         }
     }
 
@@ -121,7 +122,12 @@ impl<'a> Painter<'a> {
         }
     }
 
-    fn configure_surface(&mut self, width_in_pixels: u32, height_in_pixels: u32) {
+    fn configure_surface(
+        &mut self,
+        viewport_id: egui::ViewportId,
+        width_in_pixels: u32,
+        height_in_pixels: u32,
+    ) {
         let render_state = self
             .render_state
             .as_ref()
@@ -137,8 +143,8 @@ impl<'a> Painter<'a> {
         };
 
         let surface_state = self
-            .surface_state
-            .as_mut()
+            .surfaces
+            .get_mut(&viewport_id)
             .expect("Surface state should exist before surface configuration");
         surface_state
             .surface
@@ -172,6 +178,23 @@ impl<'a> Painter<'a> {
     /// surface upon and must remain valid for the lifetime of the created surface. (The surface may
     /// be cleared by passing `None`).
     pub unsafe fn set_window(&mut self, window: Option<&winit::window::Window>) {
+        self.set_viewport_window(egui::ViewportId::ROOT, window);
+    }
+
+    /// Like [`Self::set_window`], but for the window of any viewport
+    /// (see [`egui::Context::show_viewport`]).
+    ///
+    /// All viewports share the same device and [`RenderState`], but each has its own surface.
+    /// Call this with `None` when the window of a viewport is closed.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`Self::set_window`].
+    pub unsafe fn set_viewport_window(
+        &mut self,
+        viewport_id: egui::ViewportId,
+        window: Option<&winit::window::Window>,
+    ) {
         match window {
             Some(window) => {
                 let surface = self.instance.create_surface(&window);
@@ -181,15 +204,18 @@ impl<'a> Painter<'a> {
                 let size = window.inner_size();
                 let width = size.width;
                 let height = size.height;
-                self.surface_state = Some(SurfaceState {
-                    surface,
-                    width,
-                    height,
-                });
-                self.configure_surface(width, height);
+                self.surfaces.insert(
+                    viewport_id,
+                    SurfaceState {
+                        surface,
+                        width,
+                        height,
+                    },
+                );
+                self.configure_surface(viewport_id, width, height);
             }
             None => {
-                self.surface_state = None;
+                self.surfaces.remove(&viewport_id);
             }
         }
     }
@@ -206,8 +232,18 @@ impl<'a> Painter<'a> {
     }
 
     pub fn on_window_resized(&mut self, width_in_pixels: u32, height_in_pixels: u32) {
-        if self.surface_state.is_some() {
-            self.configure_surface(width_in_pixels, height_in_pixels);
+        self.on_viewport_resized(egui::ViewportId::ROOT, width_in_pixels, height_in_pixels);
+    }
+
+    /// Like [`Self::on_window_resized`], but for the window of any viewport.
+    pub fn on_viewport_resized(
+        &mut self,
+        viewport_id: egui::ViewportId,
+        width_in_pixels: u32,
+        height_in_pixels: u32,
+    ) {
+        if self.surfaces.contains_key(&viewport_id) {
+            self.configure_surface(viewport_id, width_in_pixels, height_in_pixels);
         } else {
             error!("Ignoring window resize notification with no surface created via Painter::set_window()");
         }
@@ -219,12 +255,33 @@ impl<'a> Painter<'a> {
         clear_color: egui::Rgba,
         clipped_primitives: &[egui::ClippedPrimitive],
         textures_delta: &egui::TexturesDelta,
+    ) {
+        self.paint_viewport_and_update_textures(
+            egui::ViewportId::ROOT,
+            pixels_per_point,
+            clear_color,
+            clipped_primitives,
+            textures_delta,
+        );
+    }
+
+    /// Like [`Self::paint_and_update_textures`], but paints in the window of any viewport.
+    ///
+    /// The textures are shared by all viewports, so only pass the [`egui::TexturesDelta`]
+    /// when painting the first viewport of a frame.
+    pub fn paint_viewport_and_update_textures(
+        &mut self,
+        viewport_id: egui::ViewportId,
+        pixels_per_point: f32,
+        clear_color: egui::Rgba,
+        clipped_primitives: &[egui::ClippedPrimitive],
+        textures_delta: &egui::TexturesDelta,
     ) {
         let render_state = match self.render_state.as_mut() {
             Some(rs) => rs,
             None => return,
         };
-        let surface_state = match self.surface_state.as_ref() {
+        let surface_state = match self.surfaces.get(&viewport_id) {
             Some(rs) => rs,
             None => return,
         };
@@ -39,6 +39,38 @@ pub fn screen_size_in_pixels(window: &winit::window::Window) -> egui::Vec2 {
     egui::vec2(size.width as f32, size.height as f32)
 }
 
+/// Create the window for a viewport, see [`egui::Context::show_viewport`].
+///
+/// `pixels_per_point` converts the sizes in the [`egui::ViewportBuilder`] to pixels.
+pub fn viewport_window_builder(
+    builder: &egui::ViewportBuilder,
+    pixels_per_point: f32,
+) -> winit::window::WindowBuilder {
+    let egui::ViewportBuilder {
+        title,
+        inner_size,
+        position,
+        resizable,
+    } = builder;
+
+    let mut window_builder = winit::window::WindowBuilder::new()
+        .with_title(title)
+        .with_resizable(*resizable);
+    if let Some(inner_size) = inner_size {
+        window_builder = window_builder.with_inner_size(winit::dpi::PhysicalSize::new(
+            pixels_per_point * inner_size.x,
+            pixels_per_point * inner_size.y,
+        ));
+    }
+    if let Some(position) = position {
+        window_builder = window_builder.with_position(winit::dpi::PhysicalPosition::new(
+            pixels_per_point * position.x,
+            pixels_per_point * position.y,
+        ));
+    }
+    window_builder
+}
+
 /// Handles the integration between egui and winit.
 pub struct State {
     start_time: instant::Instant,
@@ -46,6 +78,8 @@ pub struct State {
     pointer_pos_in_points: Option<egui::Pos2>,
     any_pointer_button_down: bool,
     current_cursor_icon: egui::CursorIcon,
+    /// Have we grabbed the cursor because of [`egui::PlatformOutput::lock_pointer`]?
+    pointer_locked: bool,
     /// What egui uses.
     current_pixels_per_point: f32,
 
@@ -62,6 +96,9 @@ pub struct State {
     ///
     /// Only one touch will be interpreted as pointer at any time.
     pointer_touch_id: Option<u64>,
+
+    /// From the last [`egui::PlatformOutput`].
+    accessibility_tree: Option<egui::AccessibilityTree>,
 }
 
 impl State {
@@ -81,6 +118,7 @@ impl State {
             pointer_pos_in_points: None,
             any_pointer_button_down: false,
             current_cursor_icon: egui::CursorIcon::Default,
+            pointer_locked: false,
             current_pixels_per_point: 1.0,
 
             clipboard: clipboard::Clipboard::new(wayland_display),
@@ -88,6 +126,8 @@ impl State {
 
             simulate_touch_screen: false,
             pointer_touch_id: None,
+
+            accessibility_tree: None,
         }
     }
 
@@ -110,6 +150,14 @@ impl State {
         self.current_pixels_per_point = pixels_per_point;
     }
 
+    /// Call this at startup with the dark/light mode of the operating system, if you know it.
+    ///
+    /// winit only reports changes to it (and only on some platforms), with
+    /// [`winit::event::WindowEvent::ThemeChanged`], which updates [`egui::RawInput::system_theme`].
+    pub fn set_system_theme(&mut self, system_theme: Option<egui::Theme>) {
+        self.egui_input.system_theme = system_theme;
+    }
+
     /// The number of physical pixels per logical point,
     /// as configured on the current egui context (see [`egui::Context::pixels_per_point`]).
     #[inline]
@@ -117,6 +165,18 @@ impl State {
         self.current_pixels_per_point
     }
 
+    /// All widgets of the last frame, for exposing them through the accessibility API of the platform.
+    ///
+    /// `egui-winit` itself does not talk to any native accessibility API:
+    /// it is up to you to read this and hand it to one.
+    ///
+    /// This is only set if [`egui::Options::accessibility_tree`] is on.
+    /// Updated by [`Self::handle_platform_output`].
+    #[inline]
+    pub fn accessibility_tree(&self) -> Option<&egui::AccessibilityTree> {
+        self.accessibility_tree.as_ref()
+    }
+
     /// The current input state.
     /// This is changed by [`Self::on_event`] and cleared by [`Self::take_egui_input`].
     #[inline]
@@ -245,6 +305,17 @@ impl State {
                 });
                 false
             }
+            WindowEvent::CloseRequested => {
+                self.egui_input.close_requested = true;
+                false
+            }
+            WindowEvent::ThemeChanged(theme) => {
+                self.egui_input.system_theme = Some(match theme {
+                    winit::window::Theme::Dark => egui::Theme::Dark,
+                    winit::window::Theme::Light => egui::Theme::Light,
+                });
+                false
+            }
             WindowEvent::ModifiersChanged(state) => {
                 self.egui_input.modifiers.alt = state.alt();
                 self.egui_input.modifiers.ctrl = state.ctrl();
@@ -264,6 +335,25 @@ impl State {
         }
     }
 
+    /// Call this on [`winit::event::DeviceEvent::MouseMotion`], which isn't part of [`Self::on_event`]
+    /// since it is a device event rather than a window event.
+    ///
+    /// This is how egui learns about mouse movement while the pointer is locked
+    /// (see [`egui::PlatformOutput::lock_pointer`]).
+    /// Device events are sent even when the window isn't focused, so the motion is ignored
+    /// unless the window is focused and we have locked the pointer.
+    ///
+    /// Returns `true` if egui got the motion, and so should be repainted.
+    pub fn on_mouse_motion(&mut self, delta: (f64, f64)) -> bool {
+        if !self.pointer_locked || !self.egui_input.has_focus {
+            return false;
+        }
+        self.egui_input.events.push(egui::Event::MouseMoved(
+            egui::vec2(delta.0 as f32, delta.1 as f32) / self.pixels_per_point(),
+        ));
+        true
+    }
+
     fn on_mouse_button_input(
         &mut self,
         state: winit::event::ElementState,
@@ -375,9 +465,11 @@ impl State {
                         winit::event::ElementState::Pressed,
                         winit::event::MouseButton::Left,
                     );
+                    self.on_touch_force(touch.force);
                 }
                 winit::event::TouchPhase::Moved => {
                     self.on_cursor_moved(touch.location);
+                    self.on_touch_force(touch.force);
                 }
                 winit::event::TouchPhase::Ended => {
                     self.pointer_touch_id = None;
@@ -399,6 +491,16 @@ impl State {
         }
     }
 
+    fn on_touch_force(&mut self, force: Option<winit::event::Force>) {
+        if let Some(force) = force {
+            // winit only gives us the altitude of the pen, not in which direction it is tilted.
+            self.egui_input.events.push(egui::Event::PointerPressure {
+                pressure: force.normalized() as f32,
+                tilt: None,
+            });
+        }
+    }
+
     fn on_mouse_wheel(&mut self, delta: winit::event::MouseScrollDelta) {
         let mut delta = match delta {
             winit::event::MouseScrollDelta::LineDelta(x, y) => {
@@ -465,7 +567,7 @@ impl State {
     /// * copy text to the clipboard
     /// * open any clicked urls
     /// * update the IME
-    /// *
+    /// * execute the [`egui::WindowCommand`]s (except [`egui::WindowCommand::Close`], which is up to you)
     pub fn handle_platform_output(
         &mut self,
         window: &winit::window::Window,
@@ -479,15 +581,28 @@ impl State {
 
         let egui::PlatformOutput {
             cursor_icon,
+            custom_cursor: _, // winit can't create cursors from images yet, so we fall back to `cursor_icon`
             open_url,
             copied_text,
             events: _,                    // handled above
             mutable_text_under_cursor: _, // only used in eframe web
             text_cursor_pos,
+            capture_pointer: _, // the OS captures the pointer while a button is held
+            lock_pointer,
+            window_commands,
+            accessibility_tree,
         } = platform_output;
         self.current_pixels_per_point = egui_ctx.pixels_per_point(); // someone can have changed it to scale the UI
 
-        self.set_cursor_icon(window, cursor_icon);
+        self.accessibility_tree = accessibility_tree;
+
+        self.set_pointer_locked(window, lock_pointer);
+
+        if lock_pointer {
+            self.set_cursor_icon(window, egui::CursorIcon::None);
+        } else {
+            self.set_cursor_icon(window, cursor_icon);
+        }
 
         if let Some(open_url) = open_url {
             open_url_in_browser(&open_url.url);
@@ -500,6 +615,42 @@ impl State {
         if let Some(egui::Pos2 { x, y }) = text_cursor_pos {
             window.set_ime_position(winit::dpi::LogicalPosition { x, y });
         }
+
+        for command in window_commands {
+            self.handle_window_command(window, command);
+        }
+    }
+
+    fn handle_window_command(&self, window: &winit::window::Window, command: egui::WindowCommand) {
+        match command {
+            egui::WindowCommand::SetTitle(title) => {
+                window.set_title(&title);
+            }
+            egui::WindowCommand::SetInnerSize(size) => {
+                window.set_inner_size(winit::dpi::PhysicalSize {
+                    width: (self.current_pixels_per_point * size.x).round(),
+                    height: (self.current_pixels_per_point * size.y).round(),
+                });
+            }
+            egui::WindowCommand::Minimize => {
+                window.set_minimized(true);
+            }
+            egui::WindowCommand::Maximize(maximized) => {
+                window.set_maximized(maximized);
+            }
+            egui::WindowCommand::Close => {} // We don't own the event loop
+        }
+    }
+
+    fn set_pointer_locked(&mut self, window: &winit::window::Window, locked: bool) {
+        if self.pointer_locked == locked {
+            return;
+        }
+        self.pointer_locked = locked;
+
+        if let Err(err) = window.set_cursor_grab(locked) {
+            tracing::warn!("Failed to set cursor grab to {}: {}", locked, err);
+        }
     }
 
     fn set_cursor_icon(&mut self, window: &winit::window::Window, cursor_icon: egui::CursorIcon) {
@@ -602,6 +753,11 @@ fn translate_virtual_key_code(key: winit::event::VirtualKeyCode) -> Option<egui:
         VirtualKeyCode::PageUp => Key::PageUp,
         VirtualKeyCode::PageDown => Key::PageDown,
 
+        VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => Key::Minus,
+        VirtualKeyCode::Equals | VirtualKeyCode::Plus | VirtualKeyCode::NumpadAdd => {
+            Key::PlusEquals
+        }
+
         VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => Key::Num0,
         VirtualKeyCode::Key1 | VirtualKeyCode::Numpad1 => Key::Num1,
         VirtualKeyCode::Key2 | VirtualKeyCode::Numpad2 => Key::Num2,
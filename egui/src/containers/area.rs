@@ -20,6 +20,10 @@ pub(crate) struct State {
     /// If false, clicks goes straight through to what is behind us.
     /// Good for tooltips etc.
     pub interactable: bool,
+
+    /// If true, this state is not saved when egui shuts down. See [`Area::persist`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub temporary: bool,
 }
 
 impl State {
@@ -50,9 +54,12 @@ pub struct Area {
     enabled: bool,
     order: Order,
     default_pos: Option<Pos2>,
+    pivot: Align2,
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
+    persist: bool,
 }
 
 impl Area {
@@ -64,9 +71,12 @@ impl Area {
             enabled: true,
             order: Order::Middle,
             default_pos: None,
+            pivot: Align2::LEFT_TOP,
             new_pos: None,
             anchor: None,
             drag_bounds: None,
+            constrain_rect: None,
+            persist: true,
         }
     }
 
@@ -111,6 +121,19 @@ impl Area {
         self
     }
 
+    /// If false, the position of the area is not saved when egui shuts down
+    /// (with the `persistence` feature), so next time it starts out at its default position.
+    ///
+    /// Default: `true`.
+    pub fn persist(mut self, persist: bool) -> Self {
+        self.persist = persist;
+        self
+    }
+
+    pub fn is_persistent(&self) -> bool {
+        self.persist
+    }
+
     /// `order(Order::Foreground)` for an Area that should always be on top
     pub fn order(mut self, order: Order) -> Self {
         self.order = order;
@@ -135,6 +158,27 @@ impl Area {
         self
     }
 
+    /// Which point of the area is put at the position given to [`Self::fixed_pos`] or [`Self::current_pos`].
+    ///
+    /// For instance, with `Align2::RIGHT_BOTTOM` the right-bottom corner of the area is put at that position,
+    /// so the area grows up and to the left from there.
+    ///
+    /// Default: `Align2::LEFT_TOP`.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let corner = ctx.available_rect().right_bottom() - egui::vec2(8.0, 8.0);
+    /// egui::Area::new("status")
+    ///     .pivot(egui::Align2::RIGHT_BOTTOM)
+    ///     .fixed_pos(corner)
+    ///     .show(ctx, |ui| ui.label("All changes saved"));
+    /// # });
+    /// ```
+    pub fn pivot(mut self, pivot: Align2) -> Self {
+        self.pivot = pivot;
+        self
+    }
+
     /// Set anchor and distance.
     ///
     /// An anchor of `Align2::RIGHT_TOP` means "put the right-top corner of the window
@@ -157,11 +201,22 @@ impl Area {
         self
     }
 
+    /// Always keep the area inside this rectangle, e.g. to keep a tool palette inside a panel.
+    ///
+    /// Unlike [`Self::drag_bounds`], this is enforced even if the area is not movable,
+    /// and even if the area does not fit (then its left top corner is kept inside).
+    /// New areas without a [`Self::default_pos`] are put in the left top corner of the rectangle.
+    pub fn constrain_to(mut self, rect: Rect) -> Self {
+        self.drag_bounds = Some(rect);
+        self.constrain_rect = Some(rect);
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
         } else {
-            Align2::LEFT_TOP
+            self.pivot
         }
     }
 }
@@ -172,6 +227,7 @@ pub(crate) struct Prepared {
     pub(crate) movable: bool,
     enabled: bool,
     drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
     /// Set the first frame of new windows with anchors.
     ///
     /// This is so that we use the first frame to calculate the window size,
@@ -201,9 +257,12 @@ impl Area {
             interactable,
             enabled,
             default_pos,
+            pivot,
             new_pos,
             anchor,
             drag_bounds,
+            constrain_rect,
+            persist,
         } = self;
 
         let layer_id = LayerId::new(order, id);
@@ -214,14 +273,26 @@ impl Area {
             ctx.request_repaint(); // if we don't know the previous size we are likely drawing the area in the wrong place
         }
         let mut state = state.unwrap_or_else(|| State {
-            pos: default_pos.unwrap_or_else(|| automatic_area_position(ctx)),
+            pos: default_pos
+                .or_else(|| constrain_rect.map(|rect| rect.min))
+                .unwrap_or_else(|| automatic_area_position(ctx)),
             size: Vec2::ZERO,
             interactable,
+            temporary: !persist,
         });
-        state.pos = new_pos.unwrap_or(state.pos);
         state.interactable = interactable;
+        state.temporary = !persist;
         let mut temporarily_invisible = false;
 
+        if let Some(new_pos) = new_pos {
+            state.pos = pivot
+                .anchor_rect(Rect::from_min_size(new_pos, state.size))
+                .min;
+            if is_new && pivot != Align2::LEFT_TOP {
+                temporarily_invisible = true; // we don't know our size yet
+            }
+        }
+
         if let Some((anchor, offset)) = anchor {
             if is_new {
                 temporarily_invisible = true;
@@ -239,10 +310,15 @@ impl Area {
             movable,
             enabled,
             drag_bounds,
+            constrain_rect,
             temporarily_invisible,
         }
     }
 
+    /// Paint a fading [`Frame`] for a while after the area is closed.
+    ///
+    /// While the animation plays, the area is not yet considered closed
+    /// by [`Memory::area_just_closed`].
     pub fn show_open_close_animation(&self, ctx: &Context, frame: &Frame, is_open: bool) {
         // must be called first so animation managers know the latest state
         let visibility_factor = ctx.animate_bool(self.id.with("close_animation"), is_open);
@@ -257,6 +333,7 @@ impl Area {
         }
 
         let layer_id = LayerId::new(self.order, self.id);
+        ctx.memory().areas.set_closing(layer_id);
         let area_rect = ctx.memory().areas.get(self.id).map(|area| area.rect());
         if let Some(area_rect) = area_rect {
             let clip_rect = ctx.available_rect();
@@ -285,6 +362,16 @@ impl Prepared {
         self.drag_bounds
     }
 
+    /// See [`Area::constrain_to`].
+    pub(crate) fn constrain_rect(&self) -> Option<Rect> {
+        self.constrain_rect
+    }
+
+    /// Move a rect of this area so that it is within the drag bounds, or the constrain rect.
+    pub(crate) fn constrain(&self, ctx: &Context, rect: Rect) -> Rect {
+        constrain(ctx, rect, self.drag_bounds, self.constrain_rect)
+    }
+
     pub(crate) fn content_ui(&self, ctx: &Context) -> Ui {
         let screen_rect = ctx.input().screen_rect();
 
@@ -333,6 +420,7 @@ impl Prepared {
             movable,
             enabled,
             drag_bounds,
+            constrain_rect,
             temporarily_invisible: _,
         } = self;
 
@@ -356,14 +444,21 @@ impl Prepared {
         );
 
         if move_response.dragged() && movable {
-            state.pos += ctx.input().pointer.delta();
+            // Remember where we would be without snapping, so that we don't get stuck to an edge:
+            let unsnapped_pos: Option<Pos2> = ctx.data().get_temp(interact_id);
+            let unsnapped_pos = unsnapped_pos.unwrap_or(state.pos) + ctx.input().pointer.delta();
+            ctx.data().insert_temp(interact_id, unsnapped_pos);
+
+            let rect = Rect::from_min_size(unsnapped_pos, state.size);
+            state.pos = ctx.snap_window_rect(layer_id, rect, drag_bounds).min;
+            ctx.frame_state().dragged_area = Some(layer_id);
+        } else if move_response.drag_released() {
+            ctx.data().remove::<Pos2>(interact_id);
         }
 
         // Important check - don't try to move e.g. a combobox popup!
-        if movable {
-            state.pos = ctx
-                .constrain_window_rect_to_area(state.rect(), drag_bounds)
-                .min;
+        if movable || constrain_rect.is_some() {
+            state.pos = constrain(ctx, state.rect(), drag_bounds, constrain_rect).min;
         }
 
         if (move_response.dragged() || move_response.clicked())
@@ -379,6 +474,24 @@ impl Prepared {
     }
 }
 
+fn constrain(
+    ctx: &Context,
+    rect: Rect,
+    drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
+) -> Rect {
+    if let Some(bounds) = constrain_rect {
+        let mut pos = rect.min;
+        pos.x = pos.x.at_most(bounds.right() - rect.width());
+        pos.x = pos.x.at_least(bounds.left()); // keep the left top corner inside if too large
+        pos.y = pos.y.at_most(bounds.bottom() - rect.height());
+        pos.y = pos.y.at_least(bounds.top());
+        Rect::from_min_size(ctx.round_pos_to_pixels(pos), rect.size())
+    } else {
+        ctx.constrain_window_rect_to_area(rect, drag_bounds)
+    }
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input().pointer.any_pressed();
@@ -0,0 +1,281 @@
+//! An infinite canvas that can be panned and zoomed, e.g. for node editors and diagrams.
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+/// State of a [`Canvas`], stored in [`Memory`].
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct State {
+    /// Where the canvas origin is, relative to the top left corner of the canvas rectangle.
+    pan: Vec2,
+
+    /// Screen points per canvas unit.
+    zoom: f32,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            pan: Vec2::ZERO,
+            zoom: 1.0,
+        }
+    }
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data().get_persisted(id)
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data().insert_persisted(id, self);
+    }
+}
+
+/// Maps between canvas coordinates and screen coordinates for a [`Canvas`].
+///
+/// The contents of the canvas are already in canvas coordinates.
+/// Use this for things outside of it, e.g. [`Self::from_screen`] to know where on the canvas
+/// the pointer from [`crate::InputState::pointer`] is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CanvasTransform {
+    /// Screen position of the canvas origin.
+    origin: Pos2,
+
+    /// Screen points per canvas unit.
+    zoom: f32,
+}
+
+impl CanvasTransform {
+    pub(crate) const IDENTITY: Self = Self {
+        origin: Pos2::ZERO,
+        zoom: 1.0,
+    };
+
+    /// First `inner`, then `self`.
+    pub(crate) fn after(self, inner: Self) -> Self {
+        Self {
+            origin: self.to_screen(inner.origin),
+            zoom: self.zoom * inner.zoom,
+        }
+    }
+
+    /// Screen points per canvas unit.
+    ///
+    /// Multiply stroke widths and font sizes with this to make them follow the zoom.
+    #[inline]
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Canvas position to screen position.
+    #[inline]
+    pub fn to_screen(&self, canvas_pos: Pos2) -> Pos2 {
+        self.origin + self.zoom * canvas_pos.to_vec2()
+    }
+
+    /// Screen position (e.g. of the pointer) to canvas position.
+    #[inline]
+    pub fn from_screen(&self, screen_pos: Pos2) -> Pos2 {
+        Pos2::ZERO + (screen_pos - self.origin) / self.zoom
+    }
+
+    /// Canvas rectangle to screen rectangle.
+    pub fn to_screen_rect(&self, canvas_rect: Rect) -> Rect {
+        Rect::from_min_max(
+            self.to_screen(canvas_rect.min),
+            self.to_screen(canvas_rect.max),
+        )
+    }
+
+    /// Screen rectangle to canvas rectangle.
+    pub fn from_screen_rect(&self, screen_rect: Rect) -> Rect {
+        Rect::from_min_max(
+            self.from_screen(screen_rect.min),
+            self.from_screen(screen_rect.max),
+        )
+    }
+
+    /// Screen movement (e.g. [`Response::drag_delta`]) to canvas movement.
+    #[inline]
+    pub fn vec_from_screen(&self, screen_delta: Vec2) -> Vec2 {
+        screen_delta / self.zoom
+    }
+}
+
+/// An infinite canvas that can be panned by dragging the background (or scrolling)
+/// and zoomed with ctrl-scroll or a pinch gesture.
+///
+/// The canvas fills the available space. Your contents are laid out, painted and interacted with
+/// in canvas coordinates, on a layer of their own, which is panned and zoomed at the end of the frame.
+/// Pointer positions in the [`Response`]s of your widgets (e.g. [`Response::drag_delta`])
+/// are in canvas coordinates too.
+/// Widgets and shapes you add to the canvas take precedence over the canvas itself,
+/// so you can have draggable nodes.
+///
+/// The contents are zoomed after they are tessellated, so zoomed-in text is blurry.
+/// Popups and tooltips are shown in screen coordinates, next to where the pointer is.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Canvas::new("my_canvas").show(ui, |ui, _transform| {
+///     let node = egui::Rect::from_min_size(egui::pos2(10.0, 10.0), egui::vec2(100.0, 50.0));
+///     ui.painter().rect_filled(node, 4.0, egui::Color32::DARK_GRAY);
+///     ui.put(node, egui::Button::new("Click me"));
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Canvas {
+    id_source: Id,
+    zoom_range: RangeInclusive<f32>,
+}
+
+impl Canvas {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            zoom_range: 0.1..=10.0,
+        }
+    }
+
+    /// How far you can zoom out and in.
+    ///
+    /// Default: `0.1..=10.0`.
+    pub fn zoom_range(mut self, zoom_range: RangeInclusive<f32>) -> Self {
+        self.zoom_range = zoom_range;
+        self
+    }
+
+    /// The returned [`Response`] is that of the canvas background.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui, &CanvasTransform) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            zoom_range,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = State::load(ui.ctx(), id).unwrap_or_default();
+
+        let (rect, _) = ui.allocate_exact_size(ui.available_size_before_wrap(), Sense::hover());
+
+        let transform = CanvasTransform {
+            origin: rect.min + state.pan,
+            zoom: state.zoom,
+        };
+
+        // Paint the contents on a layer of their own, which is put here in the paint order
+        // of the parent layer with the transform, see `Context::paint_embedded_layers`:
+        let layer_id = LayerId::new(ui.layer_id().order, id.with("layer"));
+        let idx = ui.painter().add(Shape::Noop);
+        ui.ctx()
+            .frame_state()
+            .embedded_layers
+            .push(crate::frame_state::EmbeddedLayer {
+                layer_id,
+                parent: ui.layer_id(),
+                idx,
+                transform,
+            });
+
+        let mut content_ui = Ui::new(
+            ui.ctx().clone(),
+            layer_id,
+            id.with("contents"),
+            transform.from_screen_rect(rect),
+            transform.from_screen_rect(rect.intersect(ui.clip_rect())),
+        );
+        content_ui.set_style(ui.style().clone());
+        if !ui.is_enabled() {
+            content_ui.set_enabled(false);
+        }
+        if !ui.is_visible() {
+            content_ui.set_visible(false);
+        }
+        let inner = content_ui
+            .with_layout(*ui.layout(), |ui| add_contents(ui, &transform))
+            .inner;
+
+        // Interact with the background after the contents, so that widgets on the canvas get priority:
+        let response = ui.interact(rect, id, Sense::click_and_drag());
+
+        if response.dragged() {
+            state.pan += response.drag_delta();
+        }
+
+        if response.hovered() {
+            let zoom_delta = ui.input().zoom_delta();
+            let hover_pos = ui.input().pointer.hover_pos();
+            if zoom_delta != 1.0 {
+                if let Some(hover_pos) = hover_pos {
+                    // Keep the canvas position under the pointer fixed:
+                    let canvas_pos = transform.from_screen(hover_pos);
+                    state.zoom =
+                        (state.zoom * zoom_delta).clamp(*zoom_range.start(), *zoom_range.end());
+                    state.pan = hover_pos - rect.min - state.zoom * canvas_pos.to_vec2();
+                }
+            } else {
+                // Clear scroll delta so no parent scroll area will use it:
+                let scroll_delta = std::mem::take(&mut ui.ctx().frame_state().scroll_delta);
+                state.pan += scroll_delta;
+            }
+        }
+
+        if state.pan != transform.origin - rect.min || state.zoom != transform.zoom {
+            ui.ctx().request_repaint();
+        }
+
+        state.store(ui.ctx(), id);
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+#[test]
+fn test_canvas_transforms_contents() {
+    use crate::util::Harness;
+
+    let mut harness = Harness::new_state(
+        |ctx, clicks: &mut usize| {
+            CentralPanel::default().show(ctx, |ui| {
+                Canvas::new("canvas").show(ui, |ui, _transform| {
+                    let rect = Rect::from_min_size(pos2(10.0, 10.0), vec2(100.0, 30.0));
+                    if ui.put(rect, Button::new("Node")).clicked() {
+                        *clicks += 1;
+                    }
+                });
+            });
+        },
+        0,
+    );
+
+    let before = harness.get("Node").unwrap().rect;
+    harness.push_event(Event::PointerMoved(pos2(20.0, 20.0)));
+    harness.push_event(Event::Zoom(2.0));
+    harness.run();
+    harness.run();
+
+    let after = harness.get("Node").unwrap().rect;
+    assert_eq!(after.size(), 2.0 * before.size());
+    assert_ne!(after.min, before.min);
+
+    let painted_at = |harness: &Harness<'_, usize>, rect: Rect| {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .any(|epaint::ClippedShape(_, shape)| shape.visual_bounding_rect().contains_rect(rect))
+    };
+    assert!(painted_at(&harness, after.shrink(1.0)));
+
+    harness.click("Node");
+    assert_eq!(*harness.state(), 1);
+}
@@ -78,6 +78,12 @@ impl CollapsingState {
         button_size: Vec2,
     ) -> Response {
         let (_id, rect) = ui.allocate_space(button_size);
+        self.show_default_button_at(ui, rect)
+    }
+
+    /// Like [`Self::show_default_button_with_size`], but at the given rectangle
+    /// instead of allocating space for it.
+    pub(crate) fn show_default_button_at(&mut self, ui: &mut Ui, rect: Rect) -> Response {
         let response = ui.interact(rect, self.id, Sense::click());
         if response.clicked() {
             self.toggle(ui);
@@ -320,7 +326,13 @@ pub fn paint_default_icon(ui: &mut Ui, openness: f32, response: &Response) {
     let rect = rect.expand(visuals.expansion);
     let mut points = vec![rect.left_top(), rect.right_top(), rect.center_bottom()];
     use std::f32::consts::TAU;
-    let rotation = emath::Rot2::from_angle(remap(openness, 0.0..=1.0, -TAU / 4.0..=0.0));
+    // When closed the arrow points in the reading direction: right, or left for `Style::right_to_left`.
+    let closed_angle = if ui.style().right_to_left {
+        TAU / 4.0
+    } else {
+        -TAU / 4.0
+    };
+    let rotation = emath::Rot2::from_angle(remap(openness, 0.0..=1.0, closed_angle..=0.0));
     for p in &mut points {
         *p = rect.center() + rotation * (*p - rect.center());
     }
@@ -406,6 +418,23 @@ impl CollapsingHeader {
         self
     }
 
+    /// Also use where in the source code this is called from as an [`Id`] source,
+    /// so that headers with the same title on different lines of code don't clash.
+    ///
+    /// See [`Id::with_caller_location`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::CollapsingHeader::new("Details").id_from_caller().show(ui, |ui| { /* … */ });
+    /// egui::CollapsingHeader::new("Details").id_from_caller().show(ui, |ui| { /* … */ });
+    /// # });
+    /// ```
+    #[track_caller]
+    pub fn id_from_caller(mut self) -> Self {
+        self.id_source = self.id_source.with_caller_location();
+        self
+    }
+
     /// If you set this to `false`, the [`CollapsingHeader`] will be grayed out and un-clickable.
     ///
     /// This is a convenience for [`Ui::set_enabled`].
@@ -507,14 +536,15 @@ impl CollapsingHeader {
         let id = ui.make_persistent_id(id_source);
         let button_padding = ui.spacing().button_padding;
 
+        let right_to_left = ui.style().right_to_left;
+        let indent = ui.spacing().indent;
+
         let available = ui.available_rect_before_wrap();
-        let text_pos = available.min + vec2(ui.spacing().indent, 0.0);
-        let wrap_width = available.right() - text_pos.x;
+        let wrap_width = available.width() - indent;
         let wrap = Some(false);
         let text = text.into_galley(ui, wrap, wrap_width, TextStyle::Button);
-        let text_max_x = text_pos.x + text.size().x;
 
-        let mut desired_width = text_max_x + button_padding.x - available.left();
+        let mut desired_width = indent + text.size().x + button_padding.x;
         if ui.visuals().collapsing_header_frame {
             desired_width = desired_width.max(available.width()); // fill full width
         }
@@ -524,8 +554,13 @@ impl CollapsingHeader {
         let (_, rect) = ui.allocate_space(desired_size);
 
         let mut header_response = ui.interact(rect, id, Sense::click());
+        let text_x = if right_to_left {
+            rect.right() - indent - text.size().x
+        } else {
+            rect.left() + indent
+        };
         let text_pos = pos2(
-            text_pos.x,
+            text_x,
             header_response.rect.center().y - text.size().y / 2.0,
         );
 
@@ -568,10 +603,12 @@ impl CollapsingHeader {
 
             {
                 let (mut icon_rect, _) = ui.spacing().icon_rectangles(header_response.rect);
-                icon_rect.set_center(pos2(
-                    header_response.rect.left() + ui.spacing().indent / 2.0,
-                    header_response.rect.center().y,
-                ));
+                let icon_x = if right_to_left {
+                    header_response.rect.right() - indent / 2.0
+                } else {
+                    header_response.rect.left() + indent / 2.0
+                };
+                icon_rect.set_center(pos2(icon_x, header_response.rect.center().y));
                 let icon_response = Response {
                     rect: icon_rect,
                     ..header_response.clone()
@@ -599,13 +636,33 @@ impl CollapsingHeader {
         ui: &mut Ui,
         add_body: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
-        self.show_dyn(ui, Box::new(add_body))
+        self.show_dyn(ui, Box::new(add_body), true)
+    }
+
+    /// Like [`Self::show`], but the body is not indented,
+    /// e.g. for when the body is a full-width table or image.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::CollapsingHeader::new("Wide content").show_unindented(ui, |ui| {
+    ///     ui.label("Not indented");
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn show_unindented<R>(
+        self,
+        ui: &mut Ui,
+        add_body: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        self.show_dyn(ui, Box::new(add_body), false)
     }
 
     fn show_dyn<'c, R>(
         self,
         ui: &mut Ui,
         add_body: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        indented: bool,
     ) -> CollapsingResponse<R> {
         // Make sure body is bellow header,
         // and make sure it is one unit (necessary for putting a [`CollapsingHeader`] in a grid).
@@ -618,7 +675,11 @@ impl CollapsingHeader {
                 openness,
             } = self.begin(ui); // show the header
 
-            let ret_response = state.show_body_indented(&header_response, ui, add_body);
+            let ret_response = if indented {
+                state.show_body_indented(&header_response, ui, add_body)
+            } else {
+                state.show_body_unindented(ui, add_body)
+            };
 
             if let Some(ret_response) = ret_response {
                 CollapsingResponse {
@@ -65,6 +65,16 @@ impl ComboBox {
         }
     }
 
+    /// Also use where in the source code this is called from as an [`Id`] source,
+    /// so that combo boxes with the same label on different lines of code don't clash.
+    ///
+    /// See [`Id::with_caller_location`].
+    #[track_caller]
+    pub fn id_from_caller(mut self) -> Self {
+        self.id_source = self.id_source.with_caller_location();
+        self
+    }
+
     /// Set the width of the button and menu
     pub fn width(mut self, width: f32) -> Self {
         self.width = Some(width);
@@ -0,0 +1,511 @@
+//! A [`DockArea`] lets the user arrange tabs into split layouts and tab groups,
+//! or undock them into floating windows.
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// A node in the layout tree of a [`DockArea`].
+///
+/// Each tab is identified by its title, which must be unique within the [`DockArea`].
+///
+/// ```
+/// use egui::DockNode;
+/// let tree = DockNode::horizontal(
+///     DockNode::tabs(["Files"]),
+///     DockNode::vertical(DockNode::tabs(["Editor", "Notes"]), DockNode::tabs(["Log"])),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockNode {
+    /// A group of tabs, of which one is shown.
+    Tabs { tabs: Vec<String>, selected: usize },
+
+    /// Two nodes next to each other (`horizontal`) or above each other.
+    Split {
+        horizontal: bool,
+
+        /// How much of the space goes to the first child, in the range `0.0..=1.0`.
+        fraction: f32,
+
+        children: Box<[DockNode; 2]>,
+    },
+}
+
+impl Default for DockNode {
+    fn default() -> Self {
+        Self::Tabs {
+            tabs: vec![],
+            selected: 0,
+        }
+    }
+}
+
+impl DockNode {
+    /// A tab group, with the first tab selected.
+    pub fn tabs(tabs: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self::Tabs {
+            tabs: tabs.into_iter().map(Into::into).collect(),
+            selected: 0,
+        }
+    }
+
+    /// `left` and `right` next to each other, sharing the space equally.
+    pub fn horizontal(left: Self, right: Self) -> Self {
+        Self::Split {
+            horizontal: true,
+            fraction: 0.5,
+            children: Box::new([left, right]),
+        }
+    }
+
+    /// `top` above `bottom`, sharing the space equally.
+    pub fn vertical(top: Self, bottom: Self) -> Self {
+        Self::Split {
+            horizontal: false,
+            fraction: 0.5,
+            children: Box::new([top, bottom]),
+        }
+    }
+
+    /// A tab group without any tabs.
+    fn is_empty(&self) -> bool {
+        matches!(self, Self::Tabs { tabs, .. } if tabs.is_empty())
+    }
+
+    /// Remove the tab with the given title, collapsing any split that is left with an empty side.
+    fn remove_tab(&mut self, title: &str) -> bool {
+        match self {
+            Self::Tabs { tabs, selected } => {
+                if let Some(index) = tabs.iter().position(|tab| tab == title) {
+                    tabs.remove(index);
+                    if index < *selected {
+                        *selected -= 1;
+                    }
+                    *selected = (*selected).min(tabs.len().saturating_sub(1));
+                    true
+                } else {
+                    false
+                }
+            }
+            Self::Split { children, .. } => {
+                if let Some(i) = (0..2).find(|&i| children[i].remove_tab(title)) {
+                    if children[i].is_empty() {
+                        let other = std::mem::take(&mut children[1 - i]);
+                        *self = other;
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Insert a new tab next to, or in the same group as, the tab `anchor`.
+    fn insert_tab(&mut self, anchor: &str, zone: DropZone, title: &str) -> bool {
+        match self {
+            Self::Tabs { tabs, selected } => {
+                if !tabs.iter().any(|tab| tab == anchor) {
+                    return false;
+                }
+
+                let (horizontal, new_first) = match zone {
+                    DropZone::Center => {
+                        tabs.push(title.to_owned());
+                        *selected = tabs.len() - 1;
+                        return true;
+                    }
+                    DropZone::Left => (true, true),
+                    DropZone::Right => (true, false),
+                    DropZone::Top => (false, true),
+                    DropZone::Bottom => (false, false),
+                };
+
+                let old = std::mem::take(self);
+                let new = Self::tabs([title]);
+                let children = if new_first { [new, old] } else { [old, new] };
+                *self = Self::Split {
+                    horizontal,
+                    fraction: 0.5,
+                    children: Box::new(children),
+                };
+                true
+            }
+            Self::Split { children, .. } => children
+                .iter_mut()
+                .any(|child| child.insert_tab(anchor, zone, title)),
+        }
+    }
+}
+
+/// Where on a tab group a tab is dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DropZone {
+    Center,
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl DropZone {
+    /// Near the edges of the rectangle we split, otherwise we add to the tab group.
+    fn at(rect: Rect, pos: Pos2) -> Self {
+        let rel = (pos - rect.min) / rect.size();
+        let (dist, zone) = [
+            (rel.x, Self::Left),
+            (1.0 - rel.x, Self::Right),
+            (rel.y, Self::Top),
+            (1.0 - rel.y, Self::Bottom),
+        ]
+        .iter()
+        .copied()
+        .fold((f32::INFINITY, Self::Center), |closest, edge| {
+            if edge.0 < closest.0 {
+                edge
+            } else {
+                closest
+            }
+        });
+
+        if dist < 0.25 {
+            zone
+        } else {
+            Self::Center
+        }
+    }
+
+    /// Where the dropped tab would end up.
+    fn preview_rect(self, rect: Rect) -> Rect {
+        let center = rect.center();
+        match self {
+            Self::Center => rect,
+            Self::Left => Rect::from_min_max(rect.min, pos2(center.x, rect.max.y)),
+            Self::Right => Rect::from_min_max(pos2(center.x, rect.min.y), rect.max),
+            Self::Top => Rect::from_min_max(rect.min, pos2(rect.max.x, center.y)),
+            Self::Bottom => Rect::from_min_max(pos2(rect.min.x, center.y), rect.max),
+        }
+    }
+}
+
+/// State of a [`DockArea`], stored in [`Memory`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct State {
+    root: DockNode,
+
+    /// Tabs that have been undocked into windows,
+    /// together with where to put the window on the first frame after undocking.
+    floating: Vec<(String, Option<Pos2>)>,
+
+    /// The floating window that was being dragged last frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dragged_window: Option<String>,
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data().get_persisted(id)
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data().insert_persisted(id, self);
+    }
+}
+
+/// A tab group shown this frame.
+struct Leaf {
+    rect: Rect,
+    tabs: Vec<String>,
+}
+
+/// Tabs that the user can arrange by dragging them.
+///
+/// * Drag a tab onto the edge of a tab group to split it, or onto its middle to join the group.
+/// * Drag a tab out of the [`DockArea`] to undock it into a floating [`Window`].
+/// * Drag a floating window onto a tab group to dock it again.
+/// * Drag the space between two tab groups to resize them.
+///
+/// The layout is stored in [`Memory`], so it is persisted together with the rest of the egui state.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::{DockArea, DockNode};
+/// let tree = DockNode::horizontal(DockNode::tabs(["Files"]), DockNode::tabs(["Editor", "Log"]));
+/// DockArea::new("my_dock", tree).show(ui, |ui, tab| {
+///     ui.label(format!("Contents of {}", tab));
+/// });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct DockArea {
+    id_source: Id,
+    default_tree: DockNode,
+}
+
+impl DockArea {
+    /// `default_tree` is the layout used until the user changes it.
+    pub fn new(id_source: impl Hash, default_tree: DockNode) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            default_tree,
+        }
+    }
+
+    /// Fill the available space with the docked tabs, calling `add_tab` with the title of each visible tab.
+    pub fn show(self, ui: &mut Ui, mut add_tab: impl FnMut(&mut Ui, &str)) -> Response {
+        let Self {
+            id_source,
+            default_tree,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = State::load(ui.ctx(), id).unwrap_or_else(|| State {
+            root: default_tree,
+            ..Default::default()
+        });
+
+        let (rect, response) =
+            ui.allocate_exact_size(ui.available_size_before_wrap(), Sense::hover());
+
+        let mut leaves = vec![];
+        let mut dragged_tab = None;
+        show_node(
+            ui,
+            id,
+            id.with("tab"),
+            &mut state.root,
+            rect,
+            &mut add_tab,
+            &mut leaves,
+            &mut dragged_tab,
+        );
+
+        let mut dragged_window = None;
+        for (title, pos) in &mut state.floating {
+            let window_id = id.with("floating").with(&*title);
+            let mut window = Window::new(title.as_str()).id(window_id);
+            if let Some(pos) = pos.take() {
+                window = window.current_pos(pos);
+            }
+            window.show(ui.ctx(), |ui| add_tab(ui, title));
+
+            if ui.ctx().frame_state().dragged_area == Some(LayerId::new(Order::Middle, window_id)) {
+                dragged_window = Some(title.clone());
+            }
+        }
+
+        // A window is no longer dragged on the frame it is released:
+        let released_window = if dragged_window.is_none() && ui.input().pointer.any_released() {
+            state.dragged_window.take()
+        } else {
+            None
+        };
+        state.dragged_window = dragged_window.clone();
+
+        let dragged = match (dragged_tab, dragged_window, released_window) {
+            (Some((title, released)), _, _) => Some((title, released, false)),
+            (None, Some(title), _) => Some((title, false, true)),
+            (None, None, Some(title)) => Some((title, true, true)),
+            (None, None, None) => None,
+        };
+
+        let pointer_pos = ui.input().pointer.interact_pos();
+        if let (Some((title, released, is_floating)), Some(pointer_pos)) = (dragged, pointer_pos) {
+            let target = leaves
+                .iter()
+                .find(|leaf| leaf.rect.contains(pointer_pos))
+                .and_then(|leaf| {
+                    if leaf.tabs.is_empty() {
+                        // Everything is undocked, so we can drop anywhere:
+                        Some((None, DropZone::Center, leaf.rect))
+                    } else {
+                        let anchor = leaf.tabs.iter().find(|tab| **tab != title)?;
+                        let zone = DropZone::at(leaf.rect, pointer_pos);
+                        Some((Some(anchor.clone()), zone, leaf.rect))
+                    }
+                });
+
+            if released {
+                if let Some((anchor, zone, _)) = target {
+                    if is_floating {
+                        state.floating.retain(|(tab, _)| *tab != title);
+                    } else {
+                        state.root.remove_tab(&title);
+                    }
+                    let inserted = match anchor {
+                        Some(anchor) => state.root.insert_tab(&anchor, zone, &title),
+                        None => {
+                            state.root = DockNode::tabs([title.clone()]);
+                            true
+                        }
+                    };
+                    if !inserted {
+                        state.floating.push((title, Some(pointer_pos)));
+                    }
+                } else if !is_floating && !rect.contains(pointer_pos) {
+                    state.root.remove_tab(&title);
+                    state.floating.push((title, Some(pointer_pos)));
+                }
+                ui.ctx().request_repaint();
+            } else if let Some((_, zone, leaf_rect)) = target {
+                let painter = ui
+                    .ctx()
+                    .layer_painter(LayerId::new(Order::Foreground, id.with("drop_preview")));
+                let selection = ui.visuals().selection;
+                painter.rect(
+                    zone.preview_rect(leaf_rect),
+                    ui.visuals().widgets.noninteractive.rounding,
+                    selection.bg_fill.linear_multiply(0.4),
+                    selection.stroke,
+                );
+            }
+        }
+
+        state.store(ui.ctx(), id);
+
+        response
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn show_node(
+    ui: &mut Ui,
+    id: Id,
+    tab_id: Id,
+    node: &mut DockNode,
+    rect: Rect,
+    add_tab: &mut dyn FnMut(&mut Ui, &str),
+    leaves: &mut Vec<Leaf>,
+    dragged_tab: &mut Option<(String, bool)>,
+) {
+    match node {
+        DockNode::Split {
+            horizontal,
+            fraction,
+            children,
+        } => {
+            let d = if *horizontal { 0 } else { 1 };
+            let gap = ui.spacing().item_spacing[d];
+            let split = emath::lerp(rect.min[d]..=rect.max[d], *fraction);
+
+            let mut first = rect;
+            first.max[d] = split - 0.5 * gap;
+            let mut second = rect;
+            second.min[d] = split + 0.5 * gap;
+            let mut separator = rect;
+            separator.min[d] = first.max[d];
+            separator.max[d] = second.min[d];
+
+            let response = ui.interact(separator, id.with("separator"), Sense::drag());
+            if response.dragged() {
+                if let Some(pointer_pos) = response.interact_pointer_pos() {
+                    *fraction = ((pointer_pos[d] - rect.min[d]) / rect.size()[d]).clamp(0.1, 0.9);
+                }
+            }
+            if response.hovered() || response.dragged() {
+                ui.output().cursor_icon = if *horizontal {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                };
+            }
+
+            let stroke = ui.style().interact(&response).bg_stroke;
+            if *horizontal {
+                ui.painter()
+                    .vline(separator.center().x, separator.y_range(), stroke);
+            } else {
+                ui.painter()
+                    .hline(separator.x_range(), separator.center().y, stroke);
+            }
+
+            let [first_child, second_child] = &mut **children;
+            show_node(
+                ui,
+                id.with(0),
+                tab_id,
+                first_child,
+                first,
+                add_tab,
+                leaves,
+                dragged_tab,
+            );
+            show_node(
+                ui,
+                id.with(1),
+                tab_id,
+                second_child,
+                second,
+                add_tab,
+                leaves,
+                dragged_tab,
+            );
+        }
+        DockNode::Tabs { tabs, selected } => {
+            *selected = (*selected).min(tabs.len().saturating_sub(1));
+
+            let header_height = ui.spacing().interact_size.y;
+            let header_rect =
+                Rect::from_min_max(rect.min, pos2(rect.max.x, rect.min.y + header_height));
+            let content_rect = Rect::from_min_max(
+                pos2(rect.min.x, header_rect.max.y + ui.spacing().item_spacing.y),
+                rect.max,
+            );
+
+            let mut header_ui = ui.child_ui(header_rect, Layout::left_to_right(Align::Center));
+            header_ui.set_clip_rect(header_rect.intersect(ui.clip_rect()));
+            let is_dragging = ui.input().pointer.has_moved_too_much_for_a_click;
+            for (index, title) in tabs.iter().enumerate() {
+                let (response, _) = tabs::tab_header(
+                    &mut header_ui,
+                    tab_id.with(title),
+                    title.as_str().into(),
+                    index == *selected,
+                    false,
+                );
+                if response.clicked() {
+                    *selected = index;
+                }
+                if is_dragging && (response.dragged() || response.drag_released()) {
+                    *dragged_tab = Some((title.clone(), response.drag_released()));
+                }
+            }
+            ui.painter().hline(
+                rect.x_range(),
+                header_rect.bottom(),
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+
+            if let Some(title) = tabs.get(*selected) {
+                let mut content_ui =
+                    ui.child_ui_with_id_source(content_rect, Layout::top_down(Align::Min), title);
+                content_ui.set_clip_rect(content_rect.intersect(ui.clip_rect()));
+                add_tab(&mut content_ui, title);
+            }
+
+            leaves.push(Leaf {
+                rect,
+                tabs: tabs.clone(),
+            });
+        }
+    }
+}
+
+#[test]
+fn test_dock_tree_split_and_collapse() {
+    let mut tree = DockNode::tabs(["A", "B"]);
+    assert!(tree.insert_tab("B", DropZone::Right, "C"));
+    assert_eq!(
+        tree,
+        DockNode::horizontal(DockNode::tabs(["A", "B"]), DockNode::tabs(["C"]))
+    );
+
+    assert!(!tree.insert_tab("D", DropZone::Center, "E"));
+
+    assert!(tree.remove_tab("C"));
+    assert_eq!(tree, DockNode::tabs(["A", "B"]));
+}
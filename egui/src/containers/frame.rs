@@ -25,6 +25,55 @@ pub struct Frame {
     pub shadow: Shadow,
     pub fill: Color32,
     pub stroke: Stroke,
+
+    /// An image to paint on top of the fill, e.g. for skinned UIs.
+    pub image: Option<FrameImage>,
+}
+
+/// A "9-slice" image used to paint a [`Frame`].
+///
+/// The corners of the image keep their size, while the edges and center are stretched to fit the frame.
+/// See [`epaint::Mesh::add_nine_slice`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameImage {
+    pub texture_id: TextureId,
+
+    /// The region of the texture to use. Default: the whole texture.
+    pub uv: Rect,
+
+    /// Size of the corners in points.
+    pub border: Vec2,
+
+    /// Size of the corners in texture coordinates (within `uv`).
+    pub uv_border: Vec2,
+
+    /// Multiply the image with this color.
+    pub tint: Color32,
+}
+
+impl FrameImage {
+    /// Use the whole texture, with the given border size in points and in texture coordinates.
+    pub fn new(texture_id: impl Into<TextureId>, border: Vec2, uv_border: Vec2) -> Self {
+        Self {
+            texture_id: texture_id.into(),
+            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            border,
+            uv_border,
+            tint: Color32::WHITE,
+        }
+    }
+
+    /// Use only this region of the texture.
+    pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
+        self.uv = uv.into();
+        self
+    }
+
+    /// Multiply the image with this color.
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
 }
 
 impl Frame {
@@ -156,10 +205,21 @@ impl Frame {
         self
     }
 
+    /// Paint a "9-slice" image on top of the fill, e.g. for skinned UIs.
+    ///
+    /// Use a transparent fill and no stroke if you only want the image.
+    pub fn image(mut self, image: FrameImage) -> Self {
+        self.image = Some(image);
+        self
+    }
+
     pub fn multiply_with_opacity(mut self, opacity: f32) -> Self {
         self.fill = self.fill.linear_multiply(opacity);
         self.stroke.color = self.stroke.color.linear_multiply(opacity);
         self.shadow.color = self.shadow.color.linear_multiply(opacity);
+        if let Some(image) = &mut self.image {
+            image.tint = image.tint.linear_multiply(opacity);
+        }
         self
     }
 }
@@ -217,15 +277,28 @@ impl Frame {
             shadow,
             fill,
             stroke,
+            image,
         } = *self;
 
-        let frame_shape = Shape::Rect(epaint::RectShape {
+        let mut frame_shape = Shape::Rect(epaint::RectShape {
             rect: outer_rect,
             rounding,
             fill,
             stroke,
         });
 
+        if let Some(image) = image {
+            let mut mesh = Mesh::with_texture(image.texture_id);
+            mesh.add_nine_slice(
+                outer_rect,
+                image.uv,
+                image.border,
+                image.uv_border,
+                image.tint,
+            );
+            frame_shape = Shape::Vec(vec![frame_shape, Shape::mesh(mesh)]);
+        }
+
         if shadow == Default::default() {
             frame_shape
         } else {
@@ -248,11 +321,15 @@ impl Prepared {
         let paint_rect = self.paint_rect();
 
         let Prepared {
-            frame,
+            mut frame,
             where_to_put_background,
             ..
         } = self;
 
+        if ui.ctx().frame_timing().reduced_quality {
+            frame.shadow = Default::default(); // shadows are expensive to tessellate
+        }
+
         if ui.is_rect_visible(paint_rect) {
             let shape = frame.paint(paint_rect);
             ui.painter().set(where_to_put_background, shape);
@@ -0,0 +1,205 @@
+use std::collections::BTreeSet;
+use std::hash::Hash;
+
+use crate::*;
+
+/// State of a [`ListBox`], stored in [`Memory`].
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct State {
+    /// Where a shift-click or shift-arrow selection range starts.
+    anchor: Option<usize>,
+}
+
+impl State {
+    fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data().get_temp(id)
+    }
+
+    fn store(self, ctx: &Context, id: Id) {
+        ctx.data().insert_temp(id, self);
+    }
+}
+
+/// A vertical list of items where one or more items can be selected.
+///
+/// You provide the selection (a set of item indices) and a function for showing each item.
+/// The item function gets told if the item is selected, and must return the [`Response`]
+/// of the item. Usually you'd use a [`SelectableLabel`] for this.
+///
+/// * Click selects a single item.
+/// * Ctrl-click (⌘-click on Mac) toggles an item (when [`Self::multi_select`] is on).
+/// * Shift-click selects a range of items (when [`Self::multi_select`] is on).
+/// * The up and down arrow keys moves the selection when an item has keyboard focus.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let items = ["Apple", "Banana", "Cherry"];
+/// let mut selection = std::collections::BTreeSet::new();
+/// egui::ListBox::new("fruits")
+///     .multi_select(true)
+///     .show(ui, &mut selection, items.len(), |ui, index, selected| {
+///         ui.add(egui::SelectableLabel::new(selected, items[index]))
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ListBox {
+    id_source: Id,
+    multi_select: bool,
+}
+
+impl ListBox {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            multi_select: false,
+        }
+    }
+
+    /// Allow selecting more than one item at once using ctrl-click and shift-click.
+    ///
+    /// Default: `false`.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Show `num_items` items, with `add_item` called for each item index.
+    ///
+    /// The returned [`Response`] covers all items,
+    /// and [`Response::changed`] is set if the selection was changed.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        selection: &mut BTreeSet<usize>,
+        num_items: usize,
+        mut add_item: impl FnMut(&mut Ui, usize, bool) -> Response,
+    ) -> Response {
+        let Self {
+            id_source,
+            multi_select,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+        let mut state = State::load(ui.ctx(), id).unwrap_or_default();
+
+        let InnerResponse {
+            inner: item_responses,
+            mut response,
+        } = ui.vertical(|ui| {
+            (0..num_items)
+                .map(|index| add_item(ui, index, selection.contains(&index)))
+                .collect::<Vec<_>>()
+        });
+
+        let mut changed = false;
+
+        for (index, item_response) in item_responses.iter().enumerate() {
+            if item_response.clicked() {
+                let modifiers = ui.input().modifiers;
+                changed |= select(selection, &mut state, index, multi_select, modifiers);
+            }
+        }
+
+        let focused_index = item_responses.iter().position(|r| r.has_focus());
+        if let Some(focused_index) = focused_index {
+            let (up, down, modifiers) = {
+                let input = ui.input();
+                (
+                    input.key_pressed(Key::ArrowUp),
+                    input.key_pressed(Key::ArrowDown),
+                    input.modifiers,
+                )
+            };
+
+            let new_index = if up {
+                focused_index.checked_sub(1)
+            } else if down {
+                Some(focused_index + 1).filter(|&index| index < num_items)
+            } else {
+                None
+            };
+
+            if let Some(new_index) = new_index {
+                // Moving with the arrow keys never toggles, only extends or replaces:
+                let modifiers = Modifiers {
+                    command: false,
+                    ..modifiers
+                };
+                changed |= select(selection, &mut state, new_index, multi_select, modifiers);
+                item_responses[new_index].request_focus();
+                item_responses[new_index].scroll_to_me(None);
+            }
+        }
+
+        state.store(ui.ctx(), id);
+
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Apply a click (or arrow key press) on `index`. Returns `true` if the selection changed.
+fn select(
+    selection: &mut BTreeSet<usize>,
+    state: &mut State,
+    index: usize,
+    multi_select: bool,
+    modifiers: Modifiers,
+) -> bool {
+    let old_selection = selection.clone();
+
+    if multi_select && modifiers.shift {
+        let anchor = state.anchor.unwrap_or(index);
+        if !modifiers.command {
+            selection.clear();
+        }
+        selection.extend(anchor.min(index)..=anchor.max(index));
+        state.anchor = Some(anchor);
+    } else if multi_select && modifiers.command {
+        if !selection.remove(&index) {
+            selection.insert(index);
+        }
+        state.anchor = Some(index);
+    } else {
+        selection.clear();
+        selection.insert(index);
+        state.anchor = Some(index);
+    }
+
+    *selection != old_selection
+}
+
+#[test]
+fn list_box_selection() {
+    let mut selection = BTreeSet::new();
+    let mut state = State::default();
+
+    let ctrl = Modifiers::COMMAND;
+    let shift = Modifiers::SHIFT;
+
+    assert!(select(&mut selection, &mut state, 2, true, Modifiers::NONE));
+    assert_eq!(selection, [2].into());
+
+    assert!(select(&mut selection, &mut state, 5, true, shift));
+    assert_eq!(selection, [2, 3, 4, 5].into());
+
+    assert!(select(&mut selection, &mut state, 0, true, shift));
+    assert_eq!(selection, [0, 1, 2].into());
+
+    assert!(select(&mut selection, &mut state, 1, true, ctrl));
+    assert_eq!(selection, [0, 2].into());
+
+    assert!(select(&mut selection, &mut state, 4, false, shift));
+    assert_eq!(selection, [4].into());
+
+    assert!(!select(
+        &mut selection,
+        &mut state,
+        4,
+        true,
+        Modifiers::NONE
+    ));
+}
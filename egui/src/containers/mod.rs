@@ -3,23 +3,33 @@
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
 pub(crate) mod area;
+pub(crate) mod canvas;
 pub mod collapsing_header;
 mod combo_box;
+pub mod dock;
 pub(crate) mod frame;
+pub(crate) mod list_box;
 pub mod panel;
 pub mod popup;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
+pub(crate) mod section;
+pub(crate) mod tabs;
 pub(crate) mod window;
 
 pub use {
     area::Area,
+    canvas::{Canvas, CanvasTransform},
     collapsing_header::{CollapsingHeader, CollapsingResponse},
     combo_box::*,
-    frame::Frame,
+    dock::{DockArea, DockNode},
+    frame::{Frame, FrameImage},
+    list_box::ListBox,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
     popup::*,
     resize::Resize,
     scroll_area::ScrollArea,
+    section::Section,
+    tabs::Tabs,
     window::Window,
 };
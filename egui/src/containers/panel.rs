@@ -317,6 +317,46 @@ impl SidePanel {
         }
         inner_response
     }
+
+    /// Show the panel if `is_expanded` is `true`,
+    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let mut show_side_panel = true;
+    /// egui::SidePanel::left("tools").show_animated(ctx, show_side_panel, |ui| {
+    ///     ui.label("Tools");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_animated<R>(
+        self,
+        ctx: &Context,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = ctx.animate_bool(self.id.with("animation"), is_expanded);
+
+        if how_expanded <= 0.0 {
+            None
+        } else if how_expanded < 1.0 {
+            // Show an empty panel of the in-between width, so the rest of the ui moves smoothly:
+            let expanded_width = PanelState::load(ctx, self.id)
+                .map_or(self.default_width, |state| state.rect.width());
+            let fake_width = how_expanded * expanded_width;
+            Self {
+                id: self.id.with("animating_panel"),
+                ..self
+            }
+            .resizable(false)
+            .default_width(fake_width)
+            .width_range(fake_width..=fake_width)
+            .show(ctx, |_ui| {});
+            None
+        } else {
+            Some(self.show(ctx, add_contents))
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -608,6 +648,39 @@ impl TopBottomPanel {
 
         inner_response
     }
+
+    /// Show the panel if `is_expanded` is `true`,
+    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    pub fn show_animated<R>(
+        self,
+        ctx: &Context,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = ctx.animate_bool(self.id.with("animation"), is_expanded);
+
+        if how_expanded <= 0.0 {
+            None
+        } else if how_expanded < 1.0 {
+            // Show an empty panel of the in-between height, so the rest of the ui moves smoothly:
+            let expanded_height = PanelState::load(ctx, self.id)
+                .map(|state| state.rect.height())
+                .or(self.default_height)
+                .unwrap_or_else(|| ctx.style().spacing.interact_size.y);
+            let fake_height = how_expanded * expanded_height;
+            Self {
+                id: self.id.with("animating_panel"),
+                ..self
+            }
+            .resizable(false)
+            .default_height(fake_height)
+            .height_range(fake_height..=fake_height)
+            .show(ctx, |_ui| {});
+            None
+        } else {
+            Some(self.show(ctx, add_contents))
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -622,6 +695,8 @@ impl TopBottomPanel {
 ///
 /// NOTE: Any [`Window`]s and [`Area`]s will cover the top-level [`CentralPanel`].
 ///
+/// The contents are clipped to the panel, so they never cover any other panel.
+///
 /// See the [module level docs](crate::containers::panel) for more details.
 ///
 /// ```
@@ -668,6 +743,7 @@ impl CentralPanel {
 
         let panel_rect = ui.available_rect_before_wrap();
         let mut panel_ui = ui.child_ui(panel_rect, Layout::top_down(Align::Min));
+        panel_ui.set_clip_rect(panel_rect.intersect(ui.clip_rect()));
 
         let frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
         frame.show(&mut panel_ui, |ui| {
@@ -695,7 +771,8 @@ impl CentralPanel {
         let layer_id = LayerId::background();
         let id = Id::new("central_panel");
 
-        let clip_rect = ctx.input().screen_rect();
+        // Don't paint over the other panels:
+        let clip_rect = available_rect;
         let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, available_rect, clip_rect);
 
         let inner_response = self.show_inside_dyn(&mut panel_ui, add_contents);
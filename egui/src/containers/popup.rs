@@ -96,12 +96,13 @@ pub fn show_tooltip_at_pointer<R>(
     id: Id,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
-    let suggested_pos = ctx
+    // Below and to the right of the pointer, so it doesn't cover what is being hovered:
+    let anchor = ctx
         .input()
         .pointer
         .hover_pos()
-        .map(|pointer_pos| pointer_pos + vec2(16.0, 16.0));
-    show_tooltip_at(ctx, id, suggested_pos, add_contents)
+        .map(|pointer_pos| Rect::from_min_size(pointer_pos + vec2(16.0, 0.0), vec2(0.0, 16.0)));
+    show_tooltip_next_to_dyn(ctx, id, anchor, PopupSide::Below, Box::new(add_contents))
 }
 
 /// Show a tooltip under the given area.
@@ -114,19 +115,13 @@ pub fn show_tooltip_for<R>(
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
     let expanded_rect = rect.expand2(vec2(2.0, 4.0));
-    let (above, position) = if ctx.input().any_touches() {
-        (true, expanded_rect.left_top())
+    // Don't cover the widget with the finger:
+    let side = if ctx.input().any_touches() {
+        PopupSide::Above
     } else {
-        (false, expanded_rect.left_bottom())
+        PopupSide::Below
     };
-    show_tooltip_at_avoid_dyn(
-        ctx,
-        id,
-        Some(position),
-        above,
-        expanded_rect,
-        Box::new(add_contents),
-    )
+    show_tooltip_next_to_dyn(ctx, id, Some(expanded_rect), side, Box::new(add_contents))
 }
 
 /// Show a tooltip at the given position.
@@ -138,23 +133,18 @@ pub fn show_tooltip_at<R>(
     suggested_position: Option<Pos2>,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
-    let above = false;
-    show_tooltip_at_avoid_dyn(
-        ctx,
-        id,
-        suggested_position,
-        above,
-        Rect::NOTHING,
-        Box::new(add_contents),
-    )
+    let anchor = suggested_position.map(|pos| Rect::from_min_size(pos, Vec2::ZERO));
+    show_tooltip_next_to_dyn(ctx, id, anchor, PopupSide::Below, Box::new(add_contents))
 }
 
-fn show_tooltip_at_avoid_dyn<'c, R>(
+/// Show a tooltip next to the `anchor`, placed with [`popup_position`].
+///
+/// If there already is a tooltip this frame, the new one is put next to that instead.
+fn show_tooltip_next_to_dyn<'c, R>(
     ctx: &Context,
     mut id: Id,
-    suggested_position: Option<Pos2>,
-    above: bool,
-    mut avoid_rect: Rect,
+    anchor: Option<Rect>,
+    side: PopupSide,
     add_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
 ) -> Option<R> {
     let mut tooltip_rect = Rect::NOTHING;
@@ -162,21 +152,16 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
 
     let stored = ctx.frame_state().tooltip_rect;
 
-    let mut position = if let Some(stored) = stored {
+    let anchor = if let Some(stored) = stored {
         // if there are multiple tooltips open they should use the same id for the `tooltip_size` caching to work.
         id = stored.id;
         tooltip_rect = stored.rect;
         count = stored.count;
-        avoid_rect = avoid_rect.union(tooltip_rect);
-        if above {
-            tooltip_rect.left_top()
-        } else {
-            tooltip_rect.left_bottom()
-        }
-    } else if let Some(position) = suggested_position {
-        position
+        tooltip_rect
+    } else if let Some(anchor) = anchor {
+        anchor
     } else if ctx.memory().everything_is_visible() {
-        Pos2::ZERO
+        Rect::from_min_size(Pos2::ZERO, Vec2::ZERO)
     } else {
         return None; // No good place for a tooltip :(
     };
@@ -185,29 +170,7 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
     let expected_size = state.tooltip_size(id, count);
     let expected_size = expected_size.unwrap_or_else(|| vec2(64.0, 32.0));
 
-    if above {
-        position.y -= expected_size.y;
-    }
-
-    position = position.at_most(ctx.input().screen_rect().max - expected_size);
-
-    // check if we intersect the avoid_rect
-    {
-        let new_rect = Rect::from_min_size(position, expected_size);
-
-        // Note: We do not use Rect::intersects() since it returns true even if the rects only touch.
-        if new_rect.shrink(1.0).intersects(avoid_rect) {
-            if above {
-                // place below instead:
-                position = avoid_rect.left_bottom();
-            } else {
-                // place above instead:
-                position = Pos2::new(position.x, avoid_rect.min.y - expected_size.y);
-            }
-        }
-    }
-
-    let position = position.at_least(ctx.input().screen_rect().min);
+    let position = popup_position(anchor, expected_size, side, ctx.input().screen_rect());
 
     let InnerResponse { inner, response } = show_tooltip_area_dyn(ctx, id, position, add_contents);
 
@@ -266,38 +229,116 @@ fn show_tooltip_area_dyn<'c, R>(
         })
 }
 
-/// Shows a popup below another widget.
+/// Which side of a widget (or other anchor rectangle) to put a popup on.
 ///
-/// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
+/// See [`popup_position`] and [`popup_next_to_widget`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PopupSide {
+    Below,
+    Above,
+    Left,
+    Right,
+}
+
+impl PopupSide {
+    /// The other side of the anchor.
+    pub fn flip(self) -> Self {
+        match self {
+            Self::Below => Self::Above,
+            Self::Above => Self::Below,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    /// How much room there is on this side of the `anchor`, within `screen`.
+    fn room(self, anchor: Rect, screen: Rect) -> f32 {
+        match self {
+            Self::Below => screen.bottom() - anchor.bottom(),
+            Self::Above => anchor.top() - screen.top(),
+            Self::Left => anchor.left() - screen.left(),
+            Self::Right => screen.right() - anchor.right(),
+        }
+    }
+
+    /// Top-left corner of a popup of the given size on this side of the `anchor`.
+    fn position(self, anchor: Rect, size: Vec2) -> Pos2 {
+        match self {
+            Self::Below => anchor.left_bottom(),
+            Self::Above => anchor.left_top() - vec2(0.0, size.y),
+            Self::Left => anchor.left_top() - vec2(size.x, 0.0),
+            Self::Right => anchor.right_top(),
+        }
+    }
+}
+
+/// Where to put the top-left corner of a popup of the given `size` next to the `anchor` rectangle.
+///
+/// The popup is put on the preferred `side` of the anchor if it fits there within the `screen`,
+/// else on the opposite side if there is more room there.
+/// Finally it is moved to be within the `screen`, as far as possible.
+///
+/// ```
+/// use egui::{pos2, vec2, PopupSide, Rect};
+/// let screen = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+/// let anchor = Rect::from_min_size(pos2(10.0, 80.0), vec2(20.0, 10.0));
+/// // No room below the anchor, so the popup goes above it:
+/// let pos = egui::popup_position(anchor, vec2(50.0, 30.0), PopupSide::Below, screen);
+/// assert_eq!(pos, pos2(10.0, 50.0));
+/// ```
+pub fn popup_position(anchor: Rect, size: Vec2, side: PopupSide, screen: Rect) -> Pos2 {
+    let needed = match side {
+        PopupSide::Below | PopupSide::Above => size.y,
+        PopupSide::Left | PopupSide::Right => size.x,
+    };
+    let side = if side.room(anchor, screen) < needed
+        && side.flip().room(anchor, screen) > side.room(anchor, screen)
+    {
+        side.flip()
+    } else {
+        side
+    };
+
+    let pos = side.position(anchor, size);
+    let max = (screen.max - size).max(screen.min);
+    pos.clamp(screen.min, max)
+}
+
+/// Shows a popup next to another widget, on the given side if there is room for it.
+///
+/// Combo boxes, context menus and tooltips are all placed this way.
+///
+/// The popup has the same width as the widget if it is above or below it.
 ///
 /// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
 ///
 /// Returns `None` if the popup is not open.
 ///
-/// ```
-/// # egui::__run_test_ui(|ui| {
-/// let response = ui.button("Open popup");
-/// let popup_id = ui.make_persistent_id("my_unique_id");
-/// if response.clicked() {
-///     ui.memory().toggle_popup(popup_id);
-/// }
-/// egui::popup::popup_below_widget(ui, popup_id, &response, |ui| {
-///     ui.set_min_width(200.0); // if you want to control the size
-///     ui.label("Some more info, or things you can select:");
-///     ui.label("…");
-/// });
-/// # });
-/// ```
-pub fn popup_below_widget<R>(
+/// See also [`popup_below_widget`] and [`popup_position`].
+pub fn popup_next_to_widget<R>(
     ui: &Ui,
     popup_id: Id,
     widget_response: &Response,
+    side: PopupSide,
     add_contents: impl FnOnce(&mut Ui) -> R,
 ) -> Option<R> {
     if ui.memory().is_popup_open(popup_id) {
+        // Use the size from last frame to decide where to put it:
+        let expected_size = ui
+            .memory()
+            .areas
+            .get(popup_id)
+            .map_or(Vec2::ZERO, |state| state.size);
+        let position = popup_position(
+            widget_response.rect,
+            expected_size,
+            side,
+            ui.ctx().input().screen_rect(),
+        );
+
         let inner = Area::new(popup_id)
             .order(Order::Foreground)
-            .fixed_pos(widget_response.rect.left_bottom())
+            .fixed_pos(position)
             .show(ui.ctx(), |ui| {
                 // Note: we use a separate clip-rect for this area, so the popup can be outside the parent.
                 // See https://github.com/emilk/egui/issues/825
@@ -306,7 +347,9 @@ pub fn popup_below_widget<R>(
                 frame
                     .show(ui, |ui| {
                         ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                            ui.set_width(widget_response.rect.width() - frame_margin.sum().x);
+                            if matches!(side, PopupSide::Below | PopupSide::Above) {
+                                ui.set_width(widget_response.rect.width() - frame_margin.sum().x);
+                            }
                             add_contents(ui)
                         })
                         .inner
@@ -323,3 +366,42 @@ pub fn popup_below_widget<R>(
         None
     }
 }
+
+/// Shows a popup below another widget.
+///
+/// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
+///
+/// If there is not enough room below the widget, the popup is shown above it instead.
+///
+/// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
+///
+/// Returns `None` if the popup is not open.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.button("Open popup");
+/// let popup_id = ui.make_persistent_id("my_unique_id");
+/// if response.clicked() {
+///     ui.memory().toggle_popup(popup_id);
+/// }
+/// egui::popup::popup_below_widget(ui, popup_id, &response, |ui| {
+///     ui.set_min_width(200.0); // if you want to control the size
+///     ui.label("Some more info, or things you can select:");
+///     ui.label("…");
+/// });
+/// # });
+/// ```
+pub fn popup_below_widget<R>(
+    ui: &Ui,
+    popup_id: Id,
+    widget_response: &Response,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> Option<R> {
+    popup_next_to_widget(
+        ui,
+        popup_id,
+        widget_response,
+        PopupSide::Below,
+        add_contents,
+    )
+}
@@ -9,6 +9,11 @@ pub(crate) struct State {
     /// Or the user requested a large area, but the content don't need that much space.
     pub(crate) desired_size: Vec2,
 
+    /// Where the user has moved the left top corner by dragging the left or top side,
+    /// relative to where the layout put it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    offset: Vec2,
+
     /// Actual size of content last frame
     last_content_size: Vec2,
 
@@ -24,9 +29,29 @@ impl State {
     pub fn store(self, ctx: &Context, id: Id) {
         ctx.data().insert_persisted(id, self);
     }
+
+    /// Request a new size for the next frame, or set the size of a [`Resize`] that has not been shown yet.
+    pub(crate) fn set_size(ctx: &Context, id: Id, size: Vec2) {
+        let state = Self::load(ctx, id).map_or_else(
+            || Self {
+                desired_size: size,
+                offset: Vec2::ZERO,
+                last_content_size: Vec2::ZERO,
+                requested_size: None,
+            },
+            |state| Self {
+                requested_size: Some(size),
+                ..state
+            },
+        );
+        state.store(ctx, id);
+    }
 }
 
-/// A region that can be resized by dragging the bottom right corner.
+/// A region that can be resized by dragging any of its sides or corners.
+///
+/// The layout decides where the left top corner starts out,
+/// but dragging the left or top side moves it from there.
 #[derive(Clone, Copy, Debug)]
 #[must_use = "You should call .show()"]
 pub struct Resize {
@@ -39,6 +64,9 @@ pub struct Resize {
     pub(crate) min_size: Vec2,
     pub(crate) max_size: Vec2,
 
+    /// Width divided by height.
+    aspect_ratio: Option<f32>,
+
     default_size: Vec2,
 
     with_stroke: bool,
@@ -52,6 +80,7 @@ impl Default for Resize {
             resizable: true,
             min_size: Vec2::splat(16.0),
             max_size: Vec2::splat(f32::INFINITY),
+            aspect_ratio: None,
             default_size: vec2(320.0, 128.0), // TODO(emilk): preferred size of [`Resize`] area.
             with_stroke: true,
         }
@@ -123,6 +152,34 @@ impl Resize {
         self
     }
 
+    /// Won't expand to larger than this
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_size.x = max_width;
+        self
+    }
+
+    /// Won't expand to larger than this
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_size.y = max_height;
+        self
+    }
+
+    /// Keep the width divided by the height at this ratio when resizing,
+    /// e.g. `16.0 / 9.0` for a video player.
+    ///
+    /// Contents that don't fit may still make the region larger.
+    ///
+    /// The ratio must be positive and finite. Other values are ignored.
+    pub fn fixed_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        crate::egui_assert!(
+            aspect_ratio > 0.0 && aspect_ratio.is_finite(),
+            "Invalid aspect ratio: {}",
+            aspect_ratio
+        );
+        self.aspect_ratio = (aspect_ratio > 0.0 && aspect_ratio.is_finite()).then(|| aspect_ratio);
+        self
+    }
+
     /// Can you resize it with the mouse?
     /// Note that a window can still auto-resize
     pub fn resizable(mut self, resizable: bool) -> Self {
@@ -157,10 +214,69 @@ impl Resize {
     }
 }
 
+impl Resize {
+    /// A size with the given aspect ratio that is within the min and max size,
+    /// following whichever side changed the most compared to `previous_size`.
+    fn with_aspect_ratio(&self, size: Vec2, previous_size: Vec2, aspect_ratio: f32) -> Vec2 {
+        let width_change = (size.x - previous_size.x).abs();
+        let height_change = (size.y - previous_size.y).abs() * aspect_ratio;
+        let width = if width_change >= height_change {
+            size.x
+        } else {
+            size.y * aspect_ratio
+        };
+        let width = width
+            .at_least(self.min_size.x)
+            .at_least(self.min_size.y * aspect_ratio)
+            .at_most(self.max_size.x)
+            .at_most(self.max_size.y * aspect_ratio);
+        vec2(width, width / aspect_ratio)
+    }
+}
+
+/// The sides and corners that can be dragged to resize, in order of precedence.
+const HANDLES: [Align2; 8] = [
+    Align2::RIGHT_BOTTOM,
+    Align2::LEFT_BOTTOM,
+    Align2::RIGHT_TOP,
+    Align2::LEFT_TOP,
+    Align2::RIGHT_CENTER,
+    Align2::CENTER_BOTTOM,
+    Align2::LEFT_CENTER,
+    Align2::CENTER_TOP,
+];
+
+fn handle_cursor_icon(handle: Align2) -> CursorIcon {
+    match (handle.x(), handle.y()) {
+        (Align::Center, _) => CursorIcon::ResizeVertical,
+        (_, Align::Center) => CursorIcon::ResizeHorizontal,
+        (x, y) if x == y => CursorIcon::ResizeNwSe,
+        _ => CursorIcon::ResizeNeSw,
+    }
+}
+
+/// Where to grab the given side or corner of `rect`.
+fn handle_rect(handle: Align2, rect: Rect, corner_size: f32, grab_radius: f32) -> Rect {
+    let range = |align: Align, min: f32, max: f32| match align {
+        Align::Min => min - grab_radius..=min + grab_radius,
+        Align::Center => min + corner_size..=max - corner_size,
+        Align::Max => max - grab_radius..=max + grab_radius,
+    };
+    match (handle.x(), handle.y()) {
+        (Align::Center, _) | (_, Align::Center) => Rect::from_x_y_ranges(
+            range(handle.x(), rect.left(), rect.right()),
+            range(handle.y(), rect.top(), rect.bottom()),
+        ),
+        // The corners are inside of the rect, where we paint the resize corner:
+        _ => handle.align_size_within_rect(Vec2::splat(corner_size), rect),
+    }
+}
+
 struct Prepared {
     id: Id,
     state: State,
-    corner_response: Option<Response>,
+    /// The responses of the [`HANDLES`], if resizable.
+    handle_responses: Vec<(Align2, Response)>,
     content_ui: Ui,
 }
 
@@ -185,6 +301,7 @@ impl Resize {
 
             State {
                 desired_size: default_size,
+                offset: Vec2::ZERO,
                 last_content_size: vec2(0.0, 0.0),
                 requested_size: None,
             }
@@ -197,23 +314,44 @@ impl Resize {
 
         let mut user_requested_size = state.requested_size.take();
 
-        let corner_response = if self.resizable {
-            // Resize-corner:
-            let corner_size = Vec2::splat(ui.visuals().resize_corner_size);
-            let corner_rect =
-                Rect::from_min_size(position + state.desired_size - corner_size, corner_size);
-            let corner_response = ui.interact(corner_rect, id.with("corner"), Sense::drag());
-
-            if let Some(pointer_pos) = corner_response.interact_pointer_pos() {
-                user_requested_size =
-                    Some(pointer_pos - position + 0.5 * corner_response.rect.size());
+        // The sides being dragged, and where the pointer wants them:
+        let mut dragged: Option<(Align2, Rect)> = None;
+        let mut handle_responses = vec![];
+        if self.resizable {
+            let rect = Rect::from_min_size(position + state.offset, state.desired_size);
+            let corner_size = ui.visuals().resize_corner_size;
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+
+            for handle in HANDLES {
+                let handle_rect = handle_rect(handle, rect, corner_size, grab_radius);
+                let response = ui.interact(handle_rect, id.with(handle), Sense::drag());
+
+                if dragged.is_none() {
+                    if let Some(pointer_pos) = response.interact_pointer_pos() {
+                        // Keep the pointer where it grabbed the handle, relative to the side:
+                        let side = handle.pos_in_rect(&rect);
+                        let target = pointer_pos + (side - handle_rect.center());
+                        let mut new_rect = rect;
+                        match handle.x() {
+                            Align::Min => new_rect.min.x = target.x,
+                            Align::Center => {}
+                            Align::Max => new_rect.max.x = target.x,
+                        }
+                        match handle.y() {
+                            Align::Min => new_rect.min.y = target.y,
+                            Align::Center => {}
+                            Align::Max => new_rect.max.y = target.y,
+                        }
+                        user_requested_size = Some(new_rect.size());
+                        dragged = Some((handle, rect));
+                    }
+                }
+
+                handle_responses.push((handle, response));
             }
+        }
 
-            Some(corner_response)
-        } else {
-            None
-        };
-
+        let previous_size = state.desired_size;
         if let Some(user_requested_size) = user_requested_size {
             state.desired_size = user_requested_size;
         } else {
@@ -228,9 +366,24 @@ impl Resize {
             .at_least(self.min_size)
             .at_most(self.max_size);
 
+        if let Some(aspect_ratio) = self.aspect_ratio {
+            state.desired_size =
+                self.with_aspect_ratio(state.desired_size, previous_size, aspect_ratio);
+        }
+
+        if let Some((handle, rect)) = dragged {
+            // Keep the opposite sides where they were:
+            if handle.x() == Align::Min {
+                state.offset.x = rect.right() - state.desired_size.x - position.x;
+            }
+            if handle.y() == Align::Min {
+                state.offset.y = rect.bottom() - state.desired_size.y - position.y;
+            }
+        }
+
         // ------------------------------
 
-        let inner_rect = Rect::from_min_size(position, state.desired_size);
+        let inner_rect = Rect::from_min_size(position + state.offset, state.desired_size);
 
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
 
@@ -251,7 +404,7 @@ impl Resize {
         Prepared {
             id,
             state,
-            corner_response,
+            handle_responses,
             content_ui,
         }
     }
@@ -267,7 +420,7 @@ impl Resize {
         let Prepared {
             id,
             mut state,
-            corner_response,
+            handle_responses,
             content_ui,
         } = prepared;
 
@@ -287,11 +440,15 @@ impl Resize {
             // Probably a window.
             state.last_content_size
         };
-        ui.advance_cursor_after_rect(Rect::from_min_size(content_ui.min_rect().min, size));
+        let position = content_ui.min_rect().min - state.offset;
+        ui.advance_cursor_after_rect(
+            Rect::from_min_size(content_ui.min_rect().min, size)
+                .union(Rect::from_min_size(position, Vec2::ZERO)),
+        );
 
         // ------------------------------
 
-        if self.with_stroke && corner_response.is_some() {
+        if self.with_stroke && !handle_responses.is_empty() {
             let rect = Rect::from_min_size(content_ui.min_rect().left_top(), state.desired_size);
             let rect = rect.expand(2.0); // breathing room for content
             ui.painter().add(Shape::rect_stroke(
@@ -301,11 +458,37 @@ impl Resize {
             ));
         }
 
-        if let Some(corner_response) = corner_response {
-            paint_resize_corner(ui, &corner_response);
-
-            if corner_response.hovered() || corner_response.dragged() {
-                ui.ctx().output().cursor_icon = CursorIcon::ResizeNwSe;
+        let rect = Rect::from_min_size(content_ui.min_rect().left_top(), state.desired_size);
+        for (handle, response) in &handle_responses {
+            let active = response.hovered() || response.dragged();
+            if *handle == Align2::RIGHT_BOTTOM {
+                paint_resize_corner(ui, response);
+            } else if active {
+                let stroke = ui.visuals().widgets.hovered.fg_stroke;
+                match (handle.x(), handle.y()) {
+                    (Align::Center, y) => {
+                        let y = if y == Align::Min {
+                            rect.top()
+                        } else {
+                            rect.bottom()
+                        };
+                        ui.painter().hline(rect.x_range(), y, stroke);
+                    }
+                    (x, Align::Center) => {
+                        let x = if x == Align::Min {
+                            rect.left()
+                        } else {
+                            rect.right()
+                        };
+                        ui.painter().vline(x, rect.y_range(), stroke);
+                    }
+                    _ => {
+                        paint_resize_corner_with_style(ui, &response.rect, stroke, *handle);
+                    }
+                }
+            }
+            if active {
+                ui.ctx().output().cursor_icon = handle_cursor_icon(*handle);
             }
         }
 
@@ -23,6 +23,11 @@ pub struct State {
     /// Mouse offset relative to the top of the handle when started moving the handle.
     scroll_start_offset_from_top_left: [Option<f32>; 2],
 
+    /// Set while the pointer is held down on the scroll bar trough,
+    /// when [`crate::style::Interaction::scroll_bar_click_to_page`] is on.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    paging: [bool; 2],
+
     /// Is the scroll sticky. This is true while scroll handle is in the end position
     /// and remains that way until the user moves the scroll_handle. Once unstuck (false)
     /// it remains false until the scroll touches the end position, which reenables stickiness.
@@ -36,6 +41,7 @@ impl Default for State {
             show_scroll: [false; 2],
             vel: Vec2::ZERO,
             scroll_start_offset_from_top_left: [None; 2],
+            paging: [false; 2],
             scroll_stuck_to_end: [true; 2],
         }
     }
@@ -63,6 +69,44 @@ pub struct ScrollAreaOutput<R> {
 
     /// Where on the screen the content is (excludes scroll bars).
     pub inner_rect: Rect,
+
+    /// The size of the contents, i.e. how much can be scrolled.
+    pub content_size: Vec2,
+}
+
+impl<R> ScrollAreaOutput<R> {
+    /// The largest possible [`State::offset`] on each axis.
+    pub fn max_offset(&self) -> Vec2 {
+        (self.content_size - self.inner_rect.size()).max(Vec2::ZERO)
+    }
+
+    /// Is the scroll area scrolled all the way to the right and bottom respectively?
+    ///
+    /// This is also `true` if there is nothing to scroll.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let messages = vec!["Hi!".to_owned()];
+    /// let output = egui::ScrollArea::vertical()
+    ///     .stick_to_bottom(true)
+    ///     .show(ui, |ui| {
+    ///         for message in &messages {
+    ///             ui.label(message);
+    ///         }
+    ///     });
+    /// if !output.is_at_end()[1] {
+    ///     ui.label("Scroll down for newer messages");
+    /// }
+    /// # });
+    /// ```
+    pub fn is_at_end(&self) -> [bool; 2] {
+        let max_offset = self.max_offset();
+        // Allow for some rounding errors:
+        [
+            self.state.offset.x >= max_offset.x - 0.5,
+            self.state.offset.y >= max_offset.y - 0.5,
+        ]
+    }
 }
 
 /// Add vertical and/or horizontal scrolling to a contained [`Ui`].
@@ -99,6 +143,9 @@ pub struct ScrollArea {
 
 impl ScrollArea {
     /// Create a horizontal scroll area.
+    ///
+    /// It can be scrolled with the horizontal mouse wheel, or with the vertical one
+    /// (as long as it isn't at the end already, in which case a parent may scroll instead).
     pub fn horizontal() -> Self {
         Self::new([true, false])
     }
@@ -306,6 +353,8 @@ struct Prepared {
     viewport: Rect,
     scrolling_enabled: bool,
     stick_to_end: [bool; 2],
+    /// Put the vertical scroll bar on the left side (see [`crate::Style::right_to_left`]).
+    scroll_bar_on_left: bool,
 }
 
 impl ScrollArea {
@@ -376,7 +425,13 @@ impl ScrollArea {
             inner_size
         };
 
-        let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
+        let scroll_bar_on_left = ui.style().right_to_left;
+        let inner_min = if scroll_bar_on_left {
+            available_outer.min + vec2(current_bar_use.x, 0.0)
+        } else {
+            available_outer.min
+        };
+        let inner_rect = Rect::from_min_size(inner_min, inner_size);
 
         let mut content_max_size = inner_size;
 
@@ -418,6 +473,7 @@ impl ScrollArea {
             viewport,
             scrolling_enabled,
             stick_to_end,
+            scroll_bar_on_left,
         }
     }
 
@@ -434,6 +490,10 @@ impl ScrollArea {
 
     /// Efficiently show only the visible part of a large number of rows.
     ///
+    /// All rows must have the same height. `add_contents` is only given the range of rows
+    /// that are visible, and space is reserved for all the others,
+    /// so this stays fast even with millions of rows.
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     /// let text_style = egui::TextStyle::Body;
@@ -463,6 +523,8 @@ impl ScrollArea {
             let min_row = (viewport.min.y / row_height_with_spacing).floor() as usize;
             let max_row = (viewport.max.y / row_height_with_spacing).ceil() as usize + 1;
             let max_row = max_row.at_most(total_rows);
+            // The viewport can be past the end if `total_rows` shrunk since last frame:
+            let min_row = min_row.at_most(max_row);
 
             let y_min = ui.max_rect().top() + min_row as f32 * row_height_with_spacing;
             let y_max = ui.max_rect().top() + max_row as f32 * row_height_with_spacing;
@@ -498,18 +560,20 @@ impl ScrollArea {
         let id = prepared.id;
         let inner_rect = prepared.inner_rect;
         let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
-        let state = prepared.end(ui);
+        let (state, content_size) = prepared.end(ui);
         ScrollAreaOutput {
             inner,
             id,
             state,
             inner_rect,
+            content_size,
         }
     }
 }
 
 impl Prepared {
-    fn end(self, ui: &mut Ui) -> State {
+    /// Returns the new state and the size of the contents.
+    fn end(self, ui: &mut Ui) -> (State, Vec2) {
         let Prepared {
             id,
             mut state,
@@ -522,6 +586,7 @@ impl Prepared {
             viewport: _,
             scrolling_enabled,
             stick_to_end,
+            scroll_bar_on_left,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -599,7 +664,12 @@ impl Prepared {
             inner_rect
         };
 
-        let outer_rect = Rect::from_min_size(inner_rect.min, inner_rect.size() + current_bar_use);
+        let outer_min = if scroll_bar_on_left {
+            inner_rect.min - vec2(current_bar_use.x, 0.0)
+        } else {
+            inner_rect.min
+        };
+        let outer_rect = Rect::from_min_size(outer_min, inner_rect.size() + current_bar_use);
 
         let content_is_too_large = [
             content_size.x > inner_rect.width(),
@@ -631,7 +701,10 @@ impl Prepared {
                 let dt = ui.input().unstable_dt;
 
                 let friction = friction_coeff * dt;
-                if friction > state.vel.length() || state.vel.length() < stop_speed {
+                if ui.style().reduce_motion
+                    || friction > state.vel.length()
+                    || state.vel.length() < stop_speed
+                {
                     state.vel = Vec2::ZERO;
                 } else {
                     state.vel -= friction * state.vel.normalized();
@@ -645,6 +718,20 @@ impl Prepared {
 
         let max_offset = content_size - inner_rect.size();
         if scrolling_enabled && ui.rect_contains_pointer(outer_rect) {
+            if has_bar == [true, false] {
+                // A horizontal-only scroll area has no use for the vertical mouse wheel,
+                // so let it scroll horizontally (just like holding down shift).
+                let mut frame_state = ui.ctx().frame_state();
+                if frame_state.scroll_delta.x == 0.0 {
+                    let scroll_delta = frame_state.scroll_delta;
+                    let scrolling_left = state.offset.x > 0.0 && scroll_delta.y > 0.0;
+                    let scrolling_right = state.offset.x < max_offset.x && scroll_delta.y < 0.0;
+                    if scrolling_left || scrolling_right {
+                        frame_state.scroll_delta = vec2(scroll_delta.y, 0.0);
+                    }
+                }
+            }
+
             for d in 0..2 {
                 if has_bar[d] {
                     let mut frame_state = ui.ctx().frame_state();
@@ -687,8 +774,17 @@ impl Prepared {
 
             // margin between contents and scroll bar
             let margin = animation_t * ui.spacing().item_spacing.x;
-            let min_cross = inner_rect.max[1 - d] + margin; // left of vertical scroll (d == 1)
-            let max_cross = outer_rect.max[1 - d]; // right of vertical scroll (d == 1)
+            let (min_cross, max_cross) = if d == 1 && scroll_bar_on_left {
+                (
+                    inner_rect.left() - current_bar_use.x,
+                    inner_rect.left() - margin,
+                )
+            } else {
+                (
+                    inner_rect.max[1 - d] + margin, // left of vertical scroll (d == 1)
+                    outer_rect.max[1 - d],          // right of vertical scroll (d == 1)
+                )
+            };
             let min_main = inner_rect.min[d]; // top of vertical scroll (d == 1)
             let max_main = inner_rect.max[d]; // bottom of vertical scroll (d == 1)
 
@@ -733,12 +829,40 @@ impl Prepared {
             } else {
                 Sense::hover()
             };
-            let response = ui.interact(outer_scroll_rect, interact_id, sense);
+
+            // Fingers are less precise than mouse pointers, so be more forgiving for touch:
+            let mut grab_margin = Vec2::ZERO;
+            if ui.input().any_touches() {
+                grab_margin[1 - d] = ui.style().interaction.scroll_bar_touch_grab_margin;
+            }
+            let response = ui.interact(outer_scroll_rect.expand2(grab_margin), interact_id, sense);
+            let handle_grab_rect = handle_rect.expand2(grab_margin);
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
+                if ui.style().interaction.scroll_bar_click_to_page
+                    && state.scroll_start_offset_from_top_left[d].is_none()
+                    && !handle_grab_rect.contains(pointer_pos)
+                    && ui.input().pointer.any_pressed()
+                {
+                    // Clicked the trough: move one page towards the pointer.
+                    let page = inner_rect.size()[d];
+                    if pointer_pos[d] < handle_rect.min[d] {
+                        state.offset[d] -= page;
+                    } else {
+                        state.offset[d] += page;
+                    }
+                    state.paging[d] = true;
+                    state.scroll_stuck_to_end[d] = false;
+                }
+            } else {
+                state.paging[d] = false;
+            }
+
+            if let Some(pointer_pos) = response.interact_pointer_pos().filter(|_| !state.paging[d])
+            {
                 let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
                     .get_or_insert_with(|| {
-                        if handle_rect.contains(pointer_pos) {
+                        if handle_grab_rect.contains(pointer_pos) {
                             pointer_pos[d] - handle_rect.min[d]
                         } else {
                             let handle_top_pos_at_bottom = max_main - handle_rect.size()[d];
@@ -840,7 +964,7 @@ impl Prepared {
 
         state.store(ui.ctx(), id);
 
-        state
+        (state, content_size)
     }
 }
 
@@ -0,0 +1,141 @@
+use std::hash::Hash;
+
+use crate::collapsing_header::{paint_default_icon, CollapsingState};
+use crate::*;
+
+/// A titled part of a [`Ui`], e.g. for grouping the settings in an options panel.
+///
+/// Each section gets a strong title, a separator line below the title,
+/// and the same amount of space above and below it, so that a column of sections has an even rhythm.
+///
+/// Use [`Ui::section`] for the common case of a section that cannot be collapsed.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut zoom = 1.0;
+/// egui::Section::new("Display")
+///     .collapsible(true)
+///     .show(ui, |ui| {
+///         ui.add(egui::Slider::new(&mut zoom, 0.5..=2.0).text("zoom"));
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Section {
+    title: WidgetText,
+    id_source: Id,
+    collapsible: bool,
+    default_open: bool,
+}
+
+impl Section {
+    /// The title is used as the [`Id`] source for remembering if a collapsible section is open.
+    /// If the title is not unique or changes, provide a unique id source with [`Self::id_source`].
+    pub fn new(title: impl Into<WidgetText>) -> Self {
+        let title = title.into();
+        let id_source = Id::new(title.text());
+        Self {
+            title,
+            id_source,
+            collapsible: false,
+            default_open: true,
+        }
+    }
+
+    /// Show a button next to the title for collapsing the section. Clicking the title also toggles it.
+    ///
+    /// Default: `false`.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// Should a collapsible section start out open?
+    ///
+    /// Default: `true`.
+    pub fn default_open(mut self, default_open: bool) -> Self {
+        self.default_open = default_open;
+        self
+    }
+
+    /// Explicitly set the source of the [`Id`] of this section, instead of using the title.
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Id::new(id_source);
+        self
+    }
+
+    /// Also use where in the source code this is called from as an [`Id`] source,
+    /// so that sections with the same title on different lines of code don't clash.
+    ///
+    /// See [`Id::with_caller_location`].
+    #[track_caller]
+    pub fn id_from_caller(mut self) -> Self {
+        self.id_source = self.id_source.with_caller_location();
+        self
+    }
+
+    /// The inner value is `None` if the section is collapsed.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let Self {
+            title,
+            id_source,
+            collapsible,
+            default_open,
+        } = self;
+
+        if !collapsible {
+            let InnerResponse { inner, response } = show_section(ui, title, add_contents);
+            return InnerResponse::new(Some(inner), response);
+        }
+
+        let id = ui.make_persistent_id(id_source);
+        ui.vertical(|ui| {
+            space_before_section(ui);
+
+            let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
+            ui.horizontal(|ui| {
+                state.show_toggle_button(ui, paint_default_icon);
+                if ui
+                    .add(Label::new(title.strong()).sense(Sense::click()))
+                    .clicked()
+                {
+                    state.toggle(ui);
+                }
+            });
+            ui.separator();
+
+            let inner = state
+                .show_body_unindented(ui, add_contents)
+                .map(|body| body.inner);
+            ui.add_space(ui.spacing().item_spacing.y);
+            inner
+        })
+    }
+}
+
+/// Add some extra space, unless this is the first thing in the [`Ui`].
+fn space_before_section(ui: &mut Ui) {
+    if ui.cursor().top() > ui.max_rect().top() {
+        ui.add_space(ui.spacing().item_spacing.y);
+    }
+}
+
+/// A section that cannot be collapsed. See [`Ui::section`].
+pub(crate) fn show_section<R>(
+    ui: &mut Ui,
+    title: WidgetText,
+    add_contents: impl FnOnce(&mut Ui) -> R,
+) -> InnerResponse<R> {
+    ui.vertical(|ui| {
+        space_before_section(ui);
+        ui.label(title.strong());
+        ui.separator();
+        let inner = add_contents(ui);
+        ui.add_space(ui.spacing().item_spacing.y);
+        inner
+    })
+}
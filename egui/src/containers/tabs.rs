@@ -0,0 +1,257 @@
+use std::hash::Hash;
+
+use crate::*;
+
+/// A strip of tab headers, selecting which tab is shown below it.
+///
+/// You provide the tabs, the index of the selected tab, a function for the title of each tab,
+/// and a function for showing the contents of the selected tab.
+///
+/// * Click a header to select that tab.
+/// * Drag a header to move the tab (when [`Self::reorderable`] is on).
+/// * Click the `×` of a header to remove the tab (when [`Self::closable`] is on).
+///
+/// If the headers don't fit, the tab strip can be scrolled horizontally.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut tabs = vec!["Apple".to_owned(), "Banana".to_owned(), "Cherry".to_owned()];
+/// let mut selected = 0;
+/// egui::Tabs::new("fruit_tabs").closable(true).show(
+///     ui,
+///     &mut tabs,
+///     &mut selected,
+///     |tab| tab.as_str().into(),
+///     |ui, tab| ui.label(format!("This is the {} tab", tab)),
+/// );
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Tabs {
+    id_source: Id,
+    closable: bool,
+    reorderable: bool,
+}
+
+impl Tabs {
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            closable: false,
+            reorderable: true,
+        }
+    }
+
+    /// Show a close button on each tab header.
+    ///
+    /// Default: `false`.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Allow moving tabs by dragging their headers.
+    ///
+    /// Default: `true`.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Show the tab headers, followed by the contents of the selected tab.
+    ///
+    /// Closed and moved tabs are removed from or moved within `tabs`,
+    /// and `selected` is updated to keep pointing to the same tab where possible.
+    ///
+    /// The inner value is `None` if there are no tabs.
+    /// [`Response::changed`] is set if the tabs or the selection was changed.
+    pub fn show<T, R>(
+        self,
+        ui: &mut Ui,
+        tabs: &mut Vec<T>,
+        selected: &mut usize,
+        mut tab_title: impl FnMut(&T) -> WidgetText,
+        add_contents: impl FnOnce(&mut Ui, &mut T) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let Self {
+            id_source,
+            closable,
+            reorderable,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+
+        let InnerResponse {
+            inner: (inner, changed),
+            mut response,
+        } = ui.vertical(|ui| {
+            let mut changed = false;
+            *selected = (*selected).min(tabs.len().saturating_sub(1));
+
+            let mut closed = None;
+            let mut moved = None;
+
+            ScrollArea::horizontal()
+                .id_source(id.with("tab_bar"))
+                .auto_shrink([false, true])
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        let mut header_rects = Vec::with_capacity(tabs.len());
+                        let mut dragged = None;
+
+                        for (index, tab) in tabs.iter().enumerate() {
+                            let is_selected = index == *selected;
+                            let (response, close_clicked) = tab_header(
+                                ui,
+                                id.with(index),
+                                tab_title(tab),
+                                is_selected,
+                                closable,
+                            );
+
+                            if close_clicked {
+                                closed = Some(index);
+                            } else if response.clicked() && !is_selected {
+                                *selected = index;
+                                changed = true;
+                                response.scroll_to_me(None);
+                            }
+
+                            if reorderable && (response.dragged() || response.drag_released()) {
+                                dragged = Some((index, response.drag_released()));
+                            }
+
+                            header_rects.push(response.rect);
+                        }
+
+                        let pointer_pos = ui.input().pointer.interact_pos();
+                        let is_dragging = ui.input().pointer.has_moved_too_much_for_a_click;
+                        if let (Some((from, released)), Some(pointer_pos)) = (dragged, pointer_pos)
+                        {
+                            // Where the tab would be inserted, counted in the tabs before it is removed:
+                            let to = header_rects
+                                .iter()
+                                .filter(|rect| rect.center().x < pointer_pos.x)
+                                .count();
+
+                            if released {
+                                if to != from && to != from + 1 {
+                                    moved = Some((from, to));
+                                }
+                            } else if is_dragging {
+                                let x = if to == 0 {
+                                    header_rects[0].left()
+                                } else {
+                                    header_rects[to - 1].right() + 0.5 * ui.spacing().item_spacing.x
+                                };
+                                let y_range = header_rects[from].y_range();
+                                ui.painter()
+                                    .vline(x, y_range, ui.visuals().selection.stroke);
+                            }
+                        }
+                    });
+                });
+
+            if let Some(index) = closed {
+                tabs.remove(index);
+                if index < *selected {
+                    *selected -= 1;
+                }
+                *selected = (*selected).min(tabs.len().saturating_sub(1));
+                changed = true;
+            } else if let Some((from, to)) = moved {
+                let to = if from < to { to - 1 } else { to };
+                let tab = tabs.remove(from);
+                tabs.insert(to, tab);
+                if *selected == from {
+                    *selected = to;
+                } else if from < *selected && *selected <= to {
+                    *selected -= 1;
+                } else if to <= *selected && *selected < from {
+                    *selected += 1;
+                }
+                changed = true;
+            }
+
+            ui.separator();
+
+            let inner = tabs.get_mut(*selected).map(|tab| add_contents(ui, tab));
+            (inner, changed)
+        });
+
+        if changed {
+            response.mark_changed();
+        }
+
+        InnerResponse::new(inner, response)
+    }
+}
+
+/// Returns the response of the header, and whether or not its close button was clicked.
+pub(crate) fn tab_header(
+    ui: &mut Ui,
+    id: Id,
+    title: WidgetText,
+    selected: bool,
+    closable: bool,
+) -> (Response, bool) {
+    let button_padding = ui.spacing().button_padding;
+    let close_size = ui.spacing().icon_width;
+
+    let text = title.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+
+    let mut desired_size = text.size() + 2.0 * button_padding;
+    if closable {
+        desired_size.x += ui.spacing().item_spacing.x + close_size;
+    }
+    desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
+    let (rect, _) = ui.allocate_exact_size(desired_size, Sense::hover());
+
+    // Interact with the close button first, so that it takes precedence over the header:
+    let close_rect = Rect::from_center_size(
+        pos2(
+            rect.right() - button_padding.x - 0.5 * close_size,
+            rect.center().y,
+        ),
+        Vec2::splat(close_size),
+    );
+    let close_response =
+        closable.then(|| ui.interact(close_rect, id.with("close"), Sense::click()));
+
+    let response = ui.interact(rect, id, Sense::click_and_drag());
+    response
+        .widget_info(|| WidgetInfo::selected(WidgetType::SelectableLabel, selected, text.text()));
+
+    if ui.is_rect_visible(rect) {
+        let visuals = ui.style().interact_selectable(&response, selected);
+
+        if selected || response.hovered() {
+            let rounding = Rounding {
+                sw: 0.0,
+                se: 0.0,
+                ..visuals.rounding
+            };
+            ui.painter()
+                .rect(rect, rounding, visuals.bg_fill, visuals.bg_stroke);
+        }
+
+        let text_pos = pos2(
+            rect.left() + button_padding.x,
+            rect.center().y - 0.5 * text.size().y,
+        );
+        text.paint_with_visuals(ui.painter(), text_pos, &visuals);
+
+        if let Some(close_response) = &close_response {
+            let close_visuals = ui.style().interact(close_response);
+            let rect = close_rect.shrink(3.0).expand(close_visuals.expansion);
+            let stroke = close_visuals.fg_stroke;
+            ui.painter()
+                .line_segment([rect.left_top(), rect.right_bottom()], stroke);
+            ui.painter()
+                .line_segment([rect.right_top(), rect.left_bottom()], stroke);
+        }
+    }
+
+    let close_clicked = close_response.map_or(false, |response| response.clicked());
+    (response, close_clicked)
+}
@@ -30,7 +30,10 @@ pub struct Window<'open> {
     resize: Resize,
     scroll: ScrollArea,
     collapsible: bool,
+    default_collapsed: bool,
+    collapsed: Option<bool>,
     with_title_bar: bool,
+    right_to_left: bool,
 }
 
 impl<'open> Window<'open> {
@@ -51,7 +54,10 @@ impl<'open> Window<'open> {
                 .default_size([340.0, 420.0]), // Default inner size of a window
             scroll: ScrollArea::neither(),
             collapsible: true,
+            default_collapsed: false,
+            collapsed: None,
             with_title_bar: true,
+            right_to_left: false,
         }
     }
 
@@ -66,11 +72,24 @@ impl<'open> Window<'open> {
     /// * If `*open == false`, the window will not be visible.
     /// * If `*open == true`, the window will have a close button.
     /// * If the close button is pressed, `*open` will be set to `false`.
+    ///
+    /// When closed, the window fades out before it is gone.
+    /// Use [`Memory::area_just_opened`] and [`Memory::area_just_closed`]
+    /// to know when the window appears and disappears.
     pub fn open(mut self, open: &'open mut bool) -> Self {
         self.open = Some(open);
         self
     }
 
+    /// If `false`, the position, size and collapsed state of the window are not saved
+    /// when egui shuts down (with the `persistence` feature).
+    ///
+    /// Default: `true`.
+    pub fn persist(mut self, persist: bool) -> Self {
+        self.area = self.area.persist(persist);
+        self
+    }
+
     /// If `false` the window will be grayed out and non-interactive.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.area = self.area.enabled(enabled);
@@ -109,6 +128,45 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Set maximum width of the window.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.resize = self.resize.max_width(max_width);
+        self
+    }
+
+    /// Set maximum height of the window.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.resize = self.resize.max_height(max_height);
+        self
+    }
+
+    /// Set maximum size of the window, excluding the title bar and frame.
+    pub fn max_size(mut self, max_size: impl Into<Vec2>) -> Self {
+        self.resize = self.resize.max_size(max_size);
+        self
+    }
+
+    /// Keep the width divided by the height of the window contents at this ratio when resizing,
+    /// e.g. `16.0 / 9.0` for a window showing a video.
+    ///
+    /// The ratio must be positive and finite. Other values are ignored.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("Preview")
+    ///     .resizable(true)
+    ///     .fixed_aspect_ratio(16.0 / 9.0)
+    ///     .max_width(800.0)
+    ///     .show(ctx, |ui| {
+    ///         ui.allocate_space(ui.available_size());
+    ///     });
+    /// # });
+    /// ```
+    pub fn fixed_aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        self.resize = self.resize.fixed_aspect_ratio(aspect_ratio);
+        self
+    }
+
     /// Set current position of the window.
     /// If the window is movable it is up to you to keep track of where it moved to!
     pub fn current_pos(mut self, current_pos: impl Into<Pos2>) -> Self {
@@ -122,6 +180,14 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Which point of the window is put at the position given to [`Self::fixed_pos`] or [`Self::current_pos`].
+    ///
+    /// See [`Area::pivot`].
+    pub fn pivot(mut self, pivot: Align2) -> Self {
+        self.area = self.area.pivot(pivot);
+        self
+    }
+
     /// Set anchor and distance.
     ///
     /// An anchor of `Align2::RIGHT_TOP` means "put the right-top corner of the window
@@ -191,6 +257,56 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Should the window start out collapsed to just its title bar? Default: `false`.
+    pub fn default_collapsed(mut self, default_collapsed: bool) -> Self {
+        self.default_collapsed = default_collapsed;
+        self
+    }
+
+    /// Calling `.collapsed(Some(true))` will collapse the window to its title bar this frame (or keep it collapsed).
+    ///
+    /// Calling `.collapsed(Some(false))` will expand the window this frame (or keep it expanded).
+    ///
+    /// Calling `.collapsed(None)` has no effect (default).
+    ///
+    /// See also [`Self::set_collapsed`] for collapsing a window from outside of where it is shown.
+    pub fn collapsed(mut self, collapsed: Option<bool>) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Is the window with this [`Id`] collapsed to its title bar?
+    ///
+    /// The [`Id`] of a window is `Id::new(title)`, unless you set it with [`Self::id`].
+    pub fn is_collapsed(ctx: &Context, id: Id) -> bool {
+        CollapsingState::load(ctx, Self::collapsing_id(id)).map_or(false, |state| !state.is_open())
+    }
+
+    /// Collapse the window with this [`Id`] to its title bar, or expand it again.
+    /// Takes effect the next time the window is shown.
+    ///
+    /// The [`Id`] of a window is `Id::new(title)`, unless you set it with [`Self::id`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("Log").show(ctx, |ui| {
+    ///     ui.label("…");
+    /// });
+    /// egui::Window::set_collapsed(ctx, egui::Id::new("Log"), true);
+    /// assert!(egui::Window::is_collapsed(ctx, egui::Id::new("Log")));
+    /// # });
+    /// ```
+    pub fn set_collapsed(ctx: &Context, id: Id, collapsed: bool) {
+        let id = Self::collapsing_id(id);
+        let mut state = CollapsingState::load_with_default_open(ctx, id, !collapsed);
+        state.set_open(!collapsed);
+        state.store(ctx);
+    }
+
+    fn collapsing_id(window_id: Id) -> Id {
+        window_id.with("collapsing")
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     pub fn title_bar(mut self, title_bar: bool) -> Self {
@@ -198,6 +314,19 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Lay out the window for right-to-left languages.
+    ///
+    /// The contents will be right-aligned, the collapse button goes to the right side of the title bar,
+    /// the close button to the left side, and the resize corner to the bottom left.
+    /// [`crate::Style::right_to_left`] is set for the title bar and the contents,
+    /// so collapsing headers, sliders etc. in the window are mirrored too.
+    ///
+    /// Default: `false`.
+    pub fn right_to_left(mut self, right_to_left: bool) -> Self {
+        self.right_to_left = right_to_left;
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -230,6 +359,26 @@ impl<'open> Window<'open> {
         self.area = self.area.drag_bounds(bounds);
         self
     }
+
+    /// Always keep the window inside this rectangle, e.g. to keep a tool palette inside a panel.
+    ///
+    /// The window can neither be dragged nor resized outside of it.
+    /// See [`Area::constrain_to`].
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let panel = egui::CentralPanel::default().show(ctx, |ui| {
+    ///     ui.label("Canvas");
+    /// });
+    /// egui::Window::new("Palette")
+    ///     .constrain_to(panel.response.rect)
+    ///     .show(ctx, |ui| ui.label("🖌"));
+    /// # });
+    /// ```
+    pub fn constrain_to(mut self, rect: Rect) -> Self {
+        self.area = self.area.constrain_to(rect);
+        self
+    }
 }
 
 impl<'open> Window<'open> {
@@ -257,7 +406,10 @@ impl<'open> Window<'open> {
             resize,
             scroll,
             collapsible,
+            default_collapsed,
+            collapsed,
             with_title_bar,
+            right_to_left,
         } = self;
 
         let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
@@ -271,9 +423,16 @@ impl<'open> Window<'open> {
 
         let area_id = area.id;
         let area_layer_id = area.layer();
+        let persist = area.is_persistent();
         let resize_id = area_id.with("resize");
-        let mut collapsing =
-            CollapsingState::load_with_default_open(ctx, area_id.with("collapsing"), true);
+        let mut collapsing = CollapsingState::load_with_default_open(
+            ctx,
+            Self::collapsing_id(area_id),
+            !default_collapsed,
+        );
+        if let Some(collapsed) = collapsed {
+            collapsing.set_open(!collapsed);
+        }
 
         let is_collapsed = with_title_bar && !collapsing.is_open();
         let possible = PossibleInteractions::new(&area, &resize, is_collapsed);
@@ -286,6 +445,16 @@ impl<'open> Window<'open> {
 
         let title_content_spacing = 2.0 * ctx.style().spacing.item_spacing.y;
 
+        // Calculate roughly how much larger the window size is compared to the inner rect
+        let title_bar_height = if with_title_bar {
+            let style = ctx.style();
+            title.font_height(&ctx.fonts(), &style) + title_content_spacing
+        } else {
+            0.0
+        };
+        let margins =
+            frame.outer_margin.sum() + frame.inner_margin.sum() + vec2(0.0, title_bar_height);
+
         // First interact (move etc) to avoid frame delay:
         let last_frame_outer_rect = area.state().rect();
         let interaction = if possible.movable || possible.resizable() {
@@ -297,17 +466,6 @@ impl<'open> Window<'open> {
                 last_frame_outer_rect,
             )
             .and_then(|window_interaction| {
-                // Calculate roughly how much larger the window size is compared to the inner rect
-                let title_bar_height = if with_title_bar {
-                    let style = ctx.style();
-                    title.font_height(&ctx.fonts(), &style) + title_content_spacing
-                } else {
-                    0.0
-                };
-                let margins = frame.outer_margin.sum()
-                    + frame.inner_margin.sum()
-                    + vec2(0.0, title_bar_height);
-
                 interact(
                     window_interaction,
                     ctx,
@@ -323,6 +481,9 @@ impl<'open> Window<'open> {
         let hover_interaction = resize_hover(ctx, possible, area_layer_id, last_frame_outer_rect);
 
         let mut area_content_ui = area.content_ui(ctx);
+        if right_to_left {
+            area_content_ui.style_mut().right_to_left = true;
+        }
 
         let content_inner = {
             // BEGIN FRAME --------------------------------
@@ -337,6 +498,7 @@ impl<'open> Window<'open> {
                     show_close_button,
                     &mut collapsing,
                     collapsible,
+                    right_to_left,
                 );
                 resize.min_size.x = resize.min_size.x.at_least(title_bar.rect.width()); // Prevent making window smaller than title bar width
                 Some(title_bar)
@@ -351,8 +513,17 @@ impl<'open> Window<'open> {
                             ui.add_space(title_content_spacing);
                         }
 
-                        if scroll.has_any_bar() {
-                            scroll.show(ui, add_contents).inner
+                        let add_contents = |ui: &mut Ui| {
+                            if scroll.has_any_bar() {
+                                scroll.show(ui, add_contents).inner
+                            } else {
+                                add_contents(ui)
+                            }
+                        };
+
+                        if right_to_left {
+                            ui.with_layout(Layout::top_down(Align::Max), add_contents)
+                                .inner
                         } else {
                             add_contents(ui)
                         }
@@ -361,23 +532,50 @@ impl<'open> Window<'open> {
                 .map_or((None, None), |ir| (Some(ir.inner), Some(ir.response)));
 
             let outer_rect = frame.end(&mut area_content_ui).rect;
-            paint_resize_corner(&mut area_content_ui, &possible, outer_rect, frame_stroke);
+            paint_resize_corner(
+                &mut area_content_ui,
+                &possible,
+                outer_rect,
+                frame_stroke,
+                right_to_left,
+            );
 
             // END FRAME --------------------------------
 
             if let Some(title_bar) = title_bar {
-                title_bar.ui(
+                let title_response = title_bar.ui(
                     &mut area_content_ui,
                     outer_rect,
                     &content_response,
                     open,
                     &mut collapsing,
                     collapsible,
+                    right_to_left,
                 );
+
+                if possible.movable || possible.resizable() {
+                    keyboard_interaction(
+                        ctx,
+                        &title_response,
+                        possible,
+                        area_layer_id,
+                        &mut area,
+                        area_id.with("keyboard_interaction"),
+                        outer_rect,
+                        margins,
+                        resize_id,
+                    );
+                }
             }
 
             collapsing.store(ctx);
 
+            if !persist {
+                let mut data = ctx.data();
+                data.make_temp::<crate::collapsing_header::InnerState>(collapsing.id());
+                data.make_temp::<crate::resize::State>(resize_id);
+            }
+
             if let Some(interaction) = interaction {
                 paint_frame_interaction(
                     &mut area_content_ui,
@@ -398,9 +596,7 @@ impl<'open> Window<'open> {
             content_inner
         };
 
-        area.state_mut().pos = ctx
-            .constrain_window_rect_to_area(area.state().rect(), area.drag_bounds())
-            .min;
+        area.state_mut().pos = area.constrain(ctx, area.state().rect()).min;
 
         let full_response = area.end(ctx, area_content_ui);
 
@@ -417,8 +613,11 @@ fn paint_resize_corner(
     possible: &PossibleInteractions,
     outer_rect: Rect,
     stroke: Stroke,
+    right_to_left: bool,
 ) {
-    let corner = if possible.resize_right && possible.resize_bottom {
+    let corner = if right_to_left && possible.resize_left && possible.resize_bottom {
+        Align2::LEFT_BOTTOM
+    } else if possible.resize_right && possible.resize_bottom {
         Align2::RIGHT_BOTTOM
     } else if possible.resize_left && possible.resize_bottom {
         Align2::LEFT_BOTTOM
@@ -505,13 +704,23 @@ fn interact(
     resize_id: Id,
 ) -> Option<WindowInteraction> {
     let new_rect = move_and_resize_window(ctx, &window_interaction)?;
-    let new_rect = ctx.round_rect_to_pixels(new_rect);
+    let mut new_rect = ctx.round_rect_to_pixels(new_rect);
 
-    let new_rect = ctx.constrain_window_rect_to_area(new_rect, area.drag_bounds());
+    if !window_interaction.is_resize() {
+        new_rect = ctx.snap_window_rect(area_layer_id, new_rect, area.drag_bounds());
+    }
+    let new_rect = match area.constrain_rect() {
+        Some(bounds) if window_interaction.is_resize() => new_rect.intersect(bounds),
+        _ => area.constrain(ctx, new_rect),
+    };
 
     // TODO(emilk): add this to a Window state instead as a command "move here next frame"
     area.state_mut().pos = new_rect.min;
 
+    if !window_interaction.is_resize() {
+        ctx.frame_state().dragged_area = Some(area_layer_id);
+    }
+
     if window_interaction.is_resize() {
         if let Some(mut state) = resize::State::load(ctx, resize_id) {
             state.requested_size = Some(new_rect.size() - margins);
@@ -523,6 +732,92 @@ fn interact(
     Some(window_interaction)
 }
 
+/// Lets the user move and resize the window without a pointer.
+///
+/// Pressing enter on the focused title bar toggles a mode where the arrow keys move the window,
+/// and shift + arrow keys resize it. The mode ends when the title bar loses focus (e.g. with escape or tab).
+#[allow(clippy::too_many_arguments)]
+fn keyboard_interaction(
+    ctx: &Context,
+    title_response: &Response,
+    possible: PossibleInteractions,
+    area_layer_id: LayerId,
+    area: &mut area::Prepared,
+    mode_id: Id,
+    outer_rect: Rect,
+    margins: Vec2,
+    resize_id: Id,
+) {
+    let was_active = ctx.data().get_temp::<bool>(mode_id).unwrap_or(false);
+    let mut active = was_active;
+    if !title_response.has_focus() {
+        active = false;
+    } else if ctx.input().key_pressed(Key::Enter) {
+        active = !active;
+    }
+
+    if active != was_active {
+        if active {
+            ctx.data().insert_temp(mode_id, true);
+        } else {
+            ctx.data().remove::<bool>(mode_id);
+        }
+
+        if title_response.has_focus() {
+            let announcement = if active {
+                "Arrow keys move the window, shift + arrow keys resize it. Press enter when done."
+            } else {
+                "Done moving the window."
+            };
+            ctx.output()
+                .events
+                .push(output::OutputEvent::ValueChanged(WidgetInfo::labeled(
+                    WidgetType::Other,
+                    announcement,
+                )));
+        }
+    }
+
+    if !active {
+        return;
+    }
+
+    let step = ctx.style().spacing.interact_size.y;
+    let mut delta = Vec2::ZERO;
+    let shift = {
+        let input = ctx.input();
+        for (key, dir) in [
+            (Key::ArrowLeft, -Vec2::X),
+            (Key::ArrowRight, Vec2::X),
+            (Key::ArrowUp, -Vec2::Y),
+            (Key::ArrowDown, Vec2::Y),
+        ] {
+            if input.key_pressed(key) {
+                delta += step * dir;
+            }
+        }
+        input.modifiers.shift
+    };
+
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    if shift {
+        if possible.resizable() {
+            if let Some(mut state) = resize::State::load(ctx, resize_id) {
+                state.requested_size = Some(outer_rect.size() + delta - margins);
+                state.store(ctx, resize_id);
+            }
+        }
+    } else if possible.movable {
+        area.state_mut().pos += delta;
+    }
+
+    ctx.memory().areas.move_to_top(area_layer_id);
+    ctx.request_repaint();
+}
+
 fn move_and_resize_window(ctx: &Context, window_interaction: &WindowInteraction) -> Option<Rect> {
     window_interaction.set_cursor(ctx);
 
@@ -782,6 +1077,7 @@ fn show_title_bar(
     show_close_button: bool,
     collapsing: &mut CollapsingState,
     collapsible: bool,
+    right_to_left: bool,
 ) -> TitleBar {
     let inner_response = ui.horizontal(|ui| {
         let height = title
@@ -794,7 +1090,8 @@ fn show_title_bar(
 
         let pad = (height - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
 
-        if collapsible {
+        // For right-to-left the collapse button is painted in `TitleBar::ui`, once we know our full width.
+        if collapsible && !right_to_left {
             ui.add_space(pad);
             collapsing.show_default_button_with_size(ui, button_size);
         }
@@ -839,6 +1136,11 @@ impl TitleBar {
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
     ///   of `collapsing` state
+    /// - `right_to_left`: if `true`, the "Close" button is put on the left side and the
+    ///   collapse button on the right side
+    ///
+    /// Returns the response of the title bar itself, which can be focused to move the window with the keyboard.
+    #[allow(clippy::too_many_arguments)]
     fn ui(
         mut self,
         ui: &mut Ui,
@@ -847,7 +1149,8 @@ impl TitleBar {
         open: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
-    ) {
+        right_to_left: bool,
+    ) -> Response {
         if let Some(content_response) = &content_response {
             // Now we know how large we got to be:
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
@@ -855,16 +1158,27 @@ impl TitleBar {
 
         if let Some(open) = open {
             // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
+            let side = if right_to_left {
+                Align::LEFT
+            } else {
+                Align::RIGHT
+            };
+            if self.close_button_ui(ui, side).clicked() {
                 *open = false;
             }
         }
 
+        if collapsible && right_to_left {
+            let button_rect = self.title_button_rect(ui, Align::RIGHT);
+            collapsing.show_default_button_at(ui, button_rect);
+        }
+
         let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
         let text_pos =
             emath::align::center_size_in_rect(self.title_galley.size(), full_top_rect).left_top();
         let text_pos = text_pos - self.title_galley.galley().rect.min.to_vec2();
         let text_pos = text_pos - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
+        let title_text = self.title_galley.text().to_owned();
         self.title_galley.paint_with_fallback_color(
             ui.painter(),
             text_pos,
@@ -879,32 +1193,37 @@ impl TitleBar {
             ui.painter().hline(outer_rect.x_range(), y, stroke);
         }
 
-        if ui
-            .interact(self.rect, self.id, Sense::click())
-            .double_clicked()
-            && collapsible
-        {
+        let response = ui.interact(self.rect, self.id, Sense::click());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Other, &title_text));
+        if response.double_clicked() && collapsible {
             collapsing.toggle(ui);
         }
+        response
     }
 
-    /// Paints the "Close" button at the right side of the title bar
+    /// Paints the "Close" button at the given side of the title bar
     /// and processes clicks on it.
     ///
     /// The button is square and its size is determined by the
     /// [`crate::style::Spacing::icon_width`] setting.
-    fn close_button_ui(&self, ui: &mut Ui) -> Response {
+    fn close_button_ui(&self, ui: &mut Ui, side: Align) -> Response {
+        let button_rect = self.title_button_rect(ui, side);
+        close_button(ui, button_rect)
+    }
+
+    /// Where to put a title bar button on the left or right side of the title bar.
+    fn title_button_rect(&self, ui: &Ui, side: Align) -> Rect {
         let button_size = Vec2::splat(ui.spacing().icon_width);
         let pad = (self.rect.height() - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
-        let button_rect = Rect::from_min_size(
-            pos2(
-                self.rect.right() - pad - button_size.x,
-                self.rect.center().y - 0.5 * button_size.y,
-            ),
+        let x = match side {
+            Align::Min => self.rect.left() + pad,
+            Align::Center => self.rect.center().x - 0.5 * button_size.x,
+            Align::Max => self.rect.right() - pad - button_size.x,
+        };
+        Rect::from_min_size(
+            pos2(x, self.rect.center().y - 0.5 * button_size.y),
             button_size,
-        );
-
-        close_button(ui, button_rect)
+        )
     }
 }
 
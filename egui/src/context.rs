@@ -5,7 +5,7 @@ use crate::{
     animation_manager::AnimationManager, data::output::PlatformOutput, frame_state::FrameState,
     input_state::*, layers::GraphicLayers, memory::Options, output::FullOutput, TextureHandle, *,
 };
-use epaint::{mutex::*, stats::*, text::Fonts, textures::TextureFilter, TessellationOptions, *};
+use epaint::{mutex::*, stats::*, text::Fonts, textures::TextureOptions, TessellationOptions, *};
 
 // ----------------------------------------------------------------------------
 
@@ -46,6 +46,9 @@ struct ContextImpl {
     output: PlatformOutput,
 
     paint_stats: PaintStats,
+    tessellation_cache: crate::tessellation_cache::TessellationCache,
+    frame_timing: FrameTiming,
+    gamepad_navigation: crate::gamepad_navigation::GamepadNavigation,
     /// the duration backend will poll for new events, before forcing another egui update
     /// even if there's no new events.
     repaint_after: std::time::Duration,
@@ -53,20 +56,72 @@ struct ContextImpl {
     repaint_requests: u32,
     request_repaint_callback: Option<Box<dyn Fn() + Send + Sync>>,
     requested_repaint_last_frame: bool,
+
+    /// See [`Context::set_embed_viewports`].
+    embed_viewports: bool,
+    /// The viewport whose state is in the fields above. See [`Context::show_viewport`].
+    viewport_id: ViewportId,
+    /// The state of the other viewports.
+    viewports: ahash::AHashMap<ViewportId, crate::viewport::ViewportState>,
+    /// The input the backend gave us for the other viewports this frame.
+    viewport_inputs: ahash::AHashMap<ViewportId, RawInput>,
+    /// The output of the other viewports shown this frame.
+    viewport_outputs: Vec<crate::ViewportOutput>,
 }
 
 impl ContextImpl {
-    fn begin_frame_mut(&mut self, new_raw_input: RawInput) {
+    /// Swap in the state of the given viewport, and return the previous viewport.
+    fn set_viewport(&mut self, viewport_id: ViewportId) -> ViewportId {
+        let previous = self.viewport_id;
+        if previous != viewport_id {
+            let mut state = self.viewports.remove(&viewport_id).unwrap_or_default();
+            std::mem::swap(&mut self.input, &mut state.input);
+            std::mem::swap(&mut self.frame_state, &mut state.frame_state);
+            std::mem::swap(&mut self.graphics, &mut state.graphics);
+            std::mem::swap(&mut self.output, &mut state.output);
+            self.memory.swap_viewport(&mut state.memory);
+            self.viewports.insert(previous, state);
+            self.viewport_id = viewport_id;
+        }
+        previous
+    }
+
+    fn begin_frame_mut(&mut self, mut new_raw_input: RawInput) {
+        let is_root = self.viewport_id == ViewportId::ROOT;
+        if is_root {
+            self.viewport_inputs = std::mem::take(&mut new_raw_input.viewports)
+                .into_iter()
+                .collect();
+            self.viewport_outputs.clear();
+        }
+
+        if self.memory.options.gamepad_navigation {
+            self.gamepad_navigation.translate(&mut new_raw_input);
+        }
+
         self.memory.begin_frame(&self.input, &new_raw_input);
 
+        let prev_system_theme = self.input.raw.system_theme;
         self.input = std::mem::take(&mut self.input)
             .begin_frame(new_raw_input, self.requested_repaint_last_frame);
 
-        if let Some(new_pixels_per_point) = self.memory.new_pixels_per_point.take() {
-            self.input.pixels_per_point = new_pixels_per_point;
+        if self.memory.options.follow_system_theme && prev_system_theme.is_some() {
+            if let Some(theme) = self.input.raw.system_theme {
+                if Some(theme) != prev_system_theme {
+                    std::sync::Arc::make_mut(&mut self.memory.options.style).visuals =
+                        theme.egui_visuals();
+                }
+            }
+        }
+
+        if is_root {
+            if let Some(new_pixels_per_point) = self.memory.new_pixels_per_point.take() {
+                self.input.pixels_per_point = new_pixels_per_point;
+            }
         }
 
         self.frame_state.begin_frame(&self.input);
+        self.frame_state.collect_widget_info = self.memory.options.accessibility_tree;
 
         self.update_fonts_mut();
 
@@ -78,6 +133,7 @@ impl ContextImpl {
                 pos: screen_rect.min,
                 size: screen_rect.size(),
                 interactable: true,
+                temporary: false,
             },
         );
     }
@@ -162,6 +218,7 @@ impl Default for Context {
             // Start with painting an extra frame to compensate for some widgets
             // that take two frames before they "settle":
             repaint_requests: 1,
+            embed_viewports: true,
             ..ContextImpl::default()
         })))
     }
@@ -265,6 +322,8 @@ impl Context {
 
             let id_str = id.short_debug_format();
 
+            self.warn(id, format!("ID clash: {} ID {}", what, id_str));
+
             if prev_rect.min.distance(new_rect.min) < 4.0 {
                 show_error(
                     new_rect.min,
@@ -283,6 +342,210 @@ impl Context {
         }
     }
 
+    /// Report a problem that the developer of the app should know about,
+    /// e.g. an [`Id`] clash, a full font atlas, characters missing from the fonts,
+    /// a [`Ui`] without room or a NaN value in a [`crate::Slider`] or [`crate::DragValue`].
+    ///
+    /// Warnings are collected during the frame, with at most one warning per [`Id`]
+    /// (the first one reported wins).
+    /// If [`crate::style::DebugOptions::show_warnings`] is set they are shown as a small badge
+    /// in the corner of the screen, which can be hovered to see all warnings.
+    ///
+    /// See also [`Self::warnings`].
+    pub fn warn(&self, id: Id, message: impl Into<String>) {
+        let mut frame_state = self.frame_state();
+        if !frame_state.warnings.iter().any(|(other, _)| *other == id) {
+            frame_state.warnings.push((id, message.into()));
+        }
+    }
+
+    /// The warnings reported with [`Self::warn`] this frame,
+    /// or the previous frame if called between frames.
+    ///
+    /// Useful for asserting that there are no warnings in tests.
+    pub fn warnings(&self) -> Vec<(Id, String)> {
+        self.frame_state().warnings.clone()
+    }
+
+    /// Tell egui about a keyboard shortcut that is active this frame, so it can be listed
+    /// in the shortcut overlay (toggled by pressing `?`, see [`crate::Options::shortcut_overlay`]).
+    ///
+    /// Call this every frame the shortcut is active, e.g. where you call
+    /// [`crate::InputState::consume_shortcut`].
+    /// Shortcuts are grouped by `category` in the overlay.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// const SAVE: egui::KeyboardShortcut =
+    ///     egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+    /// ctx.register_shortcut("File", SAVE, "Save the document");
+    /// if ctx.input_mut().consume_shortcut(&SAVE) {
+    ///     // save…
+    /// }
+    /// ```
+    pub fn register_shortcut(
+        &self,
+        category: impl Into<String>,
+        shortcut: KeyboardShortcut,
+        description: impl Into<String>,
+    ) {
+        let category = category.into();
+        let mut frame_state = self.frame_state();
+        let already_registered = frame_state
+            .shortcuts
+            .iter()
+            .any(|registered| registered.category == category && registered.shortcut == shortcut);
+        if !already_registered {
+            frame_state
+                .shortcuts
+                .push(crate::frame_state::RegisteredShortcut {
+                    category,
+                    shortcut,
+                    description: description.into(),
+                });
+        }
+    }
+
+    /// Human-readable shortcut for the current platform, e.g. `Ctrl+S`, or `⌘S` on Mac.
+    pub fn format_shortcut(&self, shortcut: &KeyboardShortcut) -> String {
+        shortcut.format(cfg!(target_os = "macos"))
+    }
+
+    fn shortcut_overlay(&self) {
+        let shortcuts = std::mem::take(&mut self.frame_state().shortcuts);
+        if shortcuts.is_empty() || !self.memory().options.shortcut_overlay {
+            return;
+        }
+
+        let id = Id::new("egui_shortcut_overlay");
+        let mut open = self.data().get_temp::<bool>(id).unwrap_or(false);
+
+        let nothing_has_focus = self.memory().focus().is_none();
+        let question_mark_pressed = self
+            .input()
+            .events
+            .iter()
+            .any(|event| matches!(event, Event::Text(text) if text == "?"));
+        if nothing_has_focus && question_mark_pressed {
+            open = !open;
+        }
+        if open && self.input().key_pressed(Key::Escape) {
+            open = false;
+        }
+        self.data().insert_temp(id, open);
+
+        if !open {
+            return;
+        }
+
+        // Categories in the order they were first registered:
+        let mut categories: Vec<&str> = vec![];
+        for registered in &shortcuts {
+            if !categories.contains(&registered.category.as_str()) {
+                categories.push(&registered.category);
+            }
+        }
+
+        Area::new(id)
+            .order(Order::Foreground)
+            .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+            .show(self, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.heading("Keyboard shortcuts");
+                    for category in categories {
+                        ui.separator();
+                        ui.strong(category);
+                        Grid::new(id.with(category))
+                            .num_columns(2)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for registered in &shortcuts {
+                                    if registered.category == category {
+                                        ui.label(self.format_shortcut(&registered.shortcut));
+                                        ui.label(&registered.description);
+                                        ui.end_row();
+                                    }
+                                }
+                            });
+                    }
+                    ui.separator();
+                    ui.weak("Press ? or Escape to close");
+                });
+            });
+    }
+
+    /// Toggled with [`Options::inspection_shortcut`].
+    fn inspection_window(&self) {
+        let shortcut = match self.memory().options.inspection_shortcut {
+            Some(shortcut) => shortcut,
+            None => return,
+        };
+
+        let id = Id::new("egui_inspection_window");
+        let mut open = self.data().get_temp::<bool>(id).unwrap_or(false);
+        if self.input_mut().consume_shortcut(&shortcut) {
+            open = !open;
+        }
+
+        Window::new("🔍 Inspection")
+            .id(id)
+            .open(&mut open)
+            .vscroll(true)
+            .show(self, |ui| {
+                self.inspection_ui(ui);
+            });
+
+        self.data().insert_temp(id, open);
+    }
+
+    fn paint_focus_ring(&self) {
+        let focus_ring = self.frame_state().focus_ring;
+        if let Some(focus_ring) = focus_ring {
+            let visuals = &self.style().visuals;
+            if visuals.focus_ring.width > 0.0 {
+                let rect = focus_ring.rect.expand(visuals.focus_ring.width + 1.0);
+                self.layer_painter(focus_ring.layer_id)
+                    .with_clip_rect(focus_ring.clip_rect)
+                    .rect_stroke(rect, visuals.widgets.inactive.rounding, visuals.focus_ring);
+            }
+        }
+    }
+
+    fn paint_warnings(&self) {
+        let warnings = self.warnings();
+        if warnings.is_empty() || !self.style().debug.show_warnings {
+            return;
+        }
+
+        let painter = self.debug_painter();
+        let color = self.style().visuals.warn_fg_color;
+        let corner = self.input().screen_rect().right_bottom() - vec2(8.0, 8.0);
+
+        let badge_text = if warnings.len() == 1 {
+            "⚠ 1 warning".to_owned()
+        } else {
+            format!("⚠ {} warnings", warnings.len())
+        };
+        let badge_rect = painter.debug_text(corner, Align2::RIGHT_BOTTOM, color, badge_text);
+
+        let is_hovered = self
+            .pointer_hover_pos()
+            .map_or(false, |pos| badge_rect.contains(pos));
+        if is_hovered {
+            let text = warnings
+                .iter()
+                .map(|(_, message)| message.as_str())
+                .collect::<Vec<_>>()
+                .join("\n");
+            painter.debug_text(
+                badge_rect.right_top() - vec2(0.0, 4.0),
+                Align2::RIGHT_BOTTOM,
+                color,
+                text,
+            );
+        }
+    }
+
     // ---------------------------------------------------------------------
 
     /// Use `ui.interact` instead
@@ -306,7 +569,13 @@ impl Context {
                 .at_most(Vec2::splat(5.0)),
         ); // make it easier to click
         let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
-        self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
+        let response = self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered);
+        if let Some(focus_ring) = &mut self.frame_state().focus_ring {
+            if focus_ring.id == id {
+                focus_ring.clip_rect = clip_rect;
+            }
+        }
+        response
     }
 
     /// You specify if a thing is hovered, and the function gives a [`Response`].
@@ -339,6 +608,10 @@ impl Context {
             changed: false, // must be set by the widget itself
         };
 
+        if hovered {
+            self.frame_state().hovered_widget = Some((id, rect));
+        }
+
         if !enabled || !sense.focusable || !layer_id.allow_interaction() {
             // Not interested or allowed input:
             self.memory().surrender_focus(id);
@@ -368,6 +641,15 @@ impl Context {
             response.clicked[PointerButton::Primary as usize] = true;
         }
 
+        if memory.has_visible_focus(id) {
+            ctx_impl.frame_state.focus_ring = Some(crate::frame_state::FocusRing {
+                id,
+                layer_id,
+                clip_rect: Rect::EVERYTHING, // narrowed down by `interact`, if we know it
+                rect,
+            });
+        }
+
         if sense.click || sense.drag {
             memory.interaction.click_interest |= hovered && sense.click;
             memory.interaction.drag_interest |= hovered && sense.drag;
@@ -425,7 +707,11 @@ impl Context {
         }
 
         if response.is_pointer_button_down_on {
-            response.interact_pointer_pos = input.pointer.interact_pos();
+            let (_, transform) = ctx_impl.frame_state.layer_transform(layer_id);
+            response.interact_pointer_pos = input
+                .pointer
+                .interact_pos()
+                .map(|pos| transform.from_screen(pos));
         }
 
         if input.pointer.any_down() {
@@ -558,6 +844,23 @@ impl Context {
 }
 
 impl Context {
+    /// Tell egui how long the last frame took to produce, in seconds of CPU time.
+    ///
+    /// This is meant to be called by the egui integration (`eframe` does this),
+    /// and is used to lower the painting quality when `ctx.options().frame_budget` is exceeded.
+    pub fn report_frame_time(&self, frame_time: f32) {
+        let ctx = &mut *self.write();
+        let frame_budget = ctx.memory.options.frame_budget;
+        ctx.frame_timing.on_frame_time(frame_time, frame_budget);
+    }
+
+    /// How long frames take to produce, and whether the painting quality is lowered because of it.
+    ///
+    /// See [`FrameTiming`] and [`Self::report_frame_time`].
+    pub fn frame_timing(&self) -> FrameTiming {
+        self.read().frame_timing
+    }
+
     /// Call this if there is need to repaint the UI, i.e. if you are showing an animation.
     ///
     /// If this is called at least once in a frame, then there will be another frame right after this.
@@ -668,6 +971,13 @@ impl Context {
         std::sync::Arc::make_mut(&mut self.options().style).visuals = visuals;
     }
 
+    /// The dark or light mode of the operating system or browser, if known.
+    ///
+    /// See [`crate::RawInput::system_theme`] and [`crate::Options::follow_system_theme`].
+    pub fn system_theme(&self) -> Option<crate::Theme> {
+        self.input().raw.system_theme
+    }
+
     /// The number of physical pixels for each logical point.
     #[inline(always)]
     pub fn pixels_per_point(&self) -> f32 {
@@ -748,7 +1058,7 @@ impl Context {
         &self,
         name: impl Into<String>,
         image: impl Into<ImageData>,
-        filter: TextureFilter,
+        options: impl Into<TextureOptions>,
     ) -> TextureHandle {
         let name = name.into();
         let image = image.into();
@@ -762,7 +1072,7 @@ impl Context {
             max_texture_side
         );
         let tex_mngr = self.tex_manager();
-        let tex_id = tex_mngr.write().alloc(name, image, filter);
+        let tex_id = tex_mngr.write().alloc(name, image, options.into());
         TextureHandle::new(tex_mngr, tex_id)
     }
 
@@ -775,6 +1085,16 @@ impl Context {
         self.read().tex_manager.0.clone()
     }
 
+    /// Send the whole font texture again in the next [`FullOutput::textures_delta`].
+    ///
+    /// Call this if the integration lost all its textures, e.g. after a lost WebGL context.
+    /// egui does not keep a copy of the user textures, so the integration must keep those itself.
+    pub fn invalidate_font_texture(&self) {
+        if let Some(fonts) = &self.read().fonts {
+            fonts.texture_atlas().lock().invalidate();
+        }
+    }
+
     // ---------------------------------------------------------------------
 
     /// Constrain the position of a window/area so it fits within the provided boundary.
@@ -810,6 +1130,65 @@ impl Context {
 
         Rect::from_min_size(pos, window.size())
     }
+
+    /// Move a window that is being dragged so that its edges line up with the edges of `area`
+    /// (the available screen area by default) or of other windows close to it,
+    /// see [`crate::style::Interaction::window_snap_distance`].
+    pub(crate) fn snap_window_rect(
+        &self,
+        layer_id: LayerId,
+        window: Rect,
+        area: Option<Rect>,
+    ) -> Rect {
+        let snap_distance = self.style().interaction.window_snap_distance;
+        if snap_distance <= 0.0 {
+            return window;
+        }
+
+        let area = area.unwrap_or_else(|| self.available_rect());
+        let mut x_targets = vec![area.left(), area.right()];
+        let mut y_targets = vec![area.top(), area.bottom()];
+
+        {
+            let memory = self.memory();
+            for other in memory.areas.visible_layer_ids() {
+                if other == layer_id || other.order != Order::Middle {
+                    continue;
+                }
+                if let Some(other) = memory.areas.get(other.id) {
+                    let other = other.rect();
+                    // Only snap to windows we are next to:
+                    if window.expand(snap_distance).intersects(other) {
+                        x_targets.extend([other.left(), other.right()]);
+                        y_targets.extend([other.top(), other.bottom()]);
+                    }
+                }
+            }
+        }
+
+        let offset = vec2(
+            snap_offset([window.left(), window.right()], &x_targets, snap_distance),
+            snap_offset([window.top(), window.bottom()], &y_targets, snap_distance),
+        );
+        window.translate(offset)
+    }
+}
+
+/// How far to move so that the one of `edges` that is closest to one of `targets` lines up with it,
+/// or zero if none are within `snap_distance`.
+fn snap_offset(edges: [f32; 2], targets: &[f32], snap_distance: f32) -> f32 {
+    let mut best = 0.0;
+    let mut best_distance = snap_distance;
+    for edge in edges {
+        for &target in targets {
+            let distance = (target - edge).abs();
+            if distance <= best_distance {
+                best = target - edge;
+                best_distance = distance;
+            }
+        }
+    }
+    best
 }
 
 impl Context {
@@ -820,26 +1199,86 @@ impl Context {
             self.request_repaint();
         }
 
-        let textures_delta;
+        if self.fonts().font_atlas_fill_ratio() >= 1.0 {
+            self.warn(
+                Id::new("egui_font_atlas_full"),
+                "The font texture atlas is full. Are you using a lot of different font sizes?",
+            );
+        }
+
+        let missing_chars = self.fonts().missing_chars();
+        if !missing_chars.is_empty() {
+            let missing_chars: String = missing_chars.into_iter().collect();
+            self.warn(
+                Id::new("egui_missing_glyphs"),
+                format!(
+                    "No font has a glyph for {:?}. Add a font that has them to the FontDefinitions.",
+                    missing_chars
+                ),
+            );
+        }
+
+        self.paint_focus_ring();
+        self.shortcut_overlay();
+        self.inspection_window();
+        self.paint_warnings();
+
+        let is_root = self.viewport_id() == ViewportId::ROOT;
+
+        let mut textures_delta = TexturesDelta::default();
         {
             let ctx_impl = &mut *self.write();
             ctx_impl
                 .memory
                 .end_frame(&ctx_impl.input, &ctx_impl.frame_state.used_ids);
 
-            let font_image_delta = ctx_impl.fonts.as_ref().unwrap().font_image_delta();
-            if let Some(font_image_delta) = font_image_delta {
-                ctx_impl
-                    .tex_manager
-                    .0
-                    .write()
-                    .set(TextureId::default(), font_image_delta);
-            }
+            // The textures of all viewports are sent with the root viewport, which ends last:
+            if is_root {
+                let font_image_delta = ctx_impl.fonts.as_ref().unwrap().font_image_delta();
+                if let Some(font_image_delta) = font_image_delta {
+                    ctx_impl
+                        .tex_manager
+                        .0
+                        .write()
+                        .set(TextureId::default(), font_image_delta);
+                }
 
-            textures_delta = ctx_impl.tex_manager.0.write().take_delta();
+                textures_delta = ctx_impl.tex_manager.0.write().take_delta();
+            }
         };
 
-        let platform_output: PlatformOutput = std::mem::take(&mut self.output());
+        let mut platform_output: PlatformOutput = std::mem::take(&mut self.output());
+        if self.frame_state().collect_widget_info {
+            let mut nodes = std::mem::take(&mut self.frame_state().access_nodes);
+            for i in 0..nodes.len() {
+                if nodes[i].info.label.is_none() {
+                    if let Some(labelled_by) = nodes[i].labelled_by {
+                        nodes[i].info.label = nodes
+                            .iter()
+                            .find(|node| node.id == labelled_by)
+                            .and_then(|node| node.info.label.clone());
+                    }
+                }
+            }
+            platform_output.accessibility_tree = Some(crate::output::AccessibilityTree {
+                nodes,
+                focus: self.memory().focus(),
+            });
+        }
+
+        let (shapes, shape_layers) = self.drain_paint_lists();
+
+        if !is_root {
+            // Repaints and the other viewports are handled by the root viewport:
+            return FullOutput {
+                platform_output,
+                repaint_after: std::time::Duration::MAX,
+                textures_delta,
+                shapes,
+                shape_layers,
+                viewports: Vec::new(),
+            };
+        }
 
         // if repaint_requests is greater than zero. just set the duration to zero for immediate
         // repaint. if there's no repaint requests, then we can use the actual repaint_after instead.
@@ -856,32 +1295,187 @@ impl Context {
         // will fail to overwrite the previous lower value. and thus, repaints will never
         // go back to higher values.
         self.write().repaint_after = std::time::Duration::MAX;
-        let shapes = self.drain_paint_lists();
+
+        let viewports = {
+            let ctx_impl = &mut *self.write();
+            let viewports = std::mem::take(&mut ctx_impl.viewport_outputs);
+            // Forget the viewports that were not shown this frame:
+            ctx_impl
+                .viewports
+                .retain(|id, _| viewports.iter().any(|viewport| viewport.id == *id));
+            ctx_impl.viewport_inputs.clear();
+            viewports
+        };
 
         FullOutput {
             platform_output,
             repaint_after,
             textures_delta,
             shapes,
+            shape_layers,
+            viewports,
         }
     }
 
-    fn drain_paint_lists(&self) -> Vec<ClippedShape> {
-        let ctx_impl = &mut *self.write();
-        ctx_impl
-            .graphics
-            .drain(ctx_impl.memory.areas.order())
+    fn drain_paint_lists(&self) -> (Vec<ClippedShape>, Vec<(LayerId, usize)>) {
+        self.paint_embedded_layers();
+        self.compensate_drag_latency();
+
+        let mut layers = {
+            let ctx_impl = &mut *self.write();
+            ctx_impl
+                .graphics
+                .drain_layers(ctx_impl.memory.areas.order())
+        };
+
+        let layer_effects = std::mem::take(&mut self.frame_state().layer_effects);
+        for (layer_id, shapes) in &mut layers {
+            if let Some(effect) = layer_effects.get(layer_id) {
+                *shapes = self.apply_layer_effect(std::mem::take(shapes), *effect);
+            }
+        }
+
+        let shape_layers = layers
+            .iter()
+            .map(|(layer_id, shapes)| (*layer_id, shapes.len()))
+            .collect();
+
+        let mut all_shapes =
+            Vec::with_capacity(layers.iter().map(|(_, shapes)| shapes.len()).sum());
+        for (_, mut shapes) in layers {
+            all_shapes.append(&mut shapes);
+        }
+        (all_shapes, shape_layers)
+    }
+
+    /// Paint the layers of each [`Canvas`] into the layer the canvas is in, with the transform of the canvas.
+    fn paint_embedded_layers(&self) {
+        let embedded_layers = std::mem::take(&mut self.frame_state().embedded_layers);
+
+        // Nested layers and later canvases in the same layer first:
+        for embedded in embedded_layers.iter().rev() {
+            let shapes = self.graphics().list(embedded.layer_id).take();
+            let transform = embedded.transform;
+            let shapes = if transform.zoom() == 1.0 {
+                let delta = transform.to_screen(Pos2::ZERO).to_vec2();
+                shapes
+                    .into_iter()
+                    .map(|ClippedShape(clip_rect, mut shape)| {
+                        shape.translate(delta);
+                        ClippedShape(clip_rect.translate(delta), shape)
+                    })
+                    .collect()
+            } else {
+                self.transform_layer(shapes, |pos| transform.to_screen(pos))
+            };
+            self.graphics()
+                .list(embedded.parent)
+                .replace_with(embedded.idx, shapes);
+        }
+    }
+
+    fn apply_layer_effect(
+        &self,
+        mut shapes: Vec<ClippedShape>,
+        effect: crate::frame_state::LayerEffect,
+    ) -> Vec<ClippedShape> {
+        let crate::frame_state::LayerEffect {
+            origin,
+            scale,
+            opacity,
+        } = effect;
+
+        if scale != 1.0 {
+            shapes = self.transform_layer(shapes, |pos| origin + scale * (pos - origin));
+        }
+
+        if opacity < 1.0 {
+            for ClippedShape(_, shape) in &mut shapes {
+                epaint::shape_transform::adjust_colors(shape, &|color| {
+                    *color = color.linear_multiply(opacity);
+                });
+            }
+        }
+
+        shapes
+    }
+
+    /// Move and scale some shapes with the given transform.
+    fn transform_layer(
+        &self,
+        shapes: Vec<ClippedShape>,
+        transform: impl Fn(Pos2) -> Pos2,
+    ) -> Vec<ClippedShape> {
+        // Text can't be scaled as a shape, so we tessellate the layer and transform the meshes:
+        let primitives = self.tessellate_layer(shapes);
+        primitives
+            .into_iter()
+            .map(|clipped_primitive| {
+                let clip_rect = clipped_primitive.clip_rect;
+                let clip_rect =
+                    Rect::from_min_max(transform(clip_rect.min), transform(clip_rect.max));
+                let shape = match clipped_primitive.primitive {
+                    epaint::Primitive::Mesh(mut mesh) => {
+                        for vertex in &mut mesh.vertices {
+                            vertex.pos = transform(vertex.pos);
+                        }
+                        Shape::Mesh(mesh)
+                    }
+                    epaint::Primitive::Callback(mut callback) => {
+                        let rect = callback.rect;
+                        callback.rect =
+                            Rect::from_min_max(transform(rect.min), transform(rect.max));
+                        Shape::Callback(callback)
+                    }
+                };
+                ClippedShape(clip_rect, shape)
+            })
             .collect()
     }
 
+    /// Tessellate some shapes right away, without updating the paint stats.
+    fn tessellate_layer(&self, shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
+        let tessellation_options = self
+            .frame_timing()
+            .tessellation_options(*self.tessellation_options());
+        let texture_atlas = self.fonts().texture_atlas();
+        let font_tex_size = texture_atlas.lock().size();
+        let prepared_discs = texture_atlas.lock().prepared_discs();
+        tessellator::tessellate_shapes(
+            self.pixels_per_point(),
+            tessellation_options,
+            font_tex_size,
+            prepared_discs,
+            shapes,
+        )
+    }
+
+    /// Paint the area being dragged where the pointer is predicted to be,
+    /// see [`crate::style::Interaction::drag_latency_compensation`].
+    fn compensate_drag_latency(&self) {
+        let latency = self.style().interaction.drag_latency_compensation;
+        if latency <= 0.0 {
+            return;
+        }
+        let dragged_area = self.frame_state().dragged_area;
+        if let Some(layer_id) = dragged_area {
+            let delta = latency * self.input().pointer.velocity();
+            let delta = self.round_vec_to_pixels(delta);
+            self.translate_layer(layer_id, delta);
+        }
+    }
+
     /// Tessellate the given shapes into triangle meshes.
     pub fn tessellate(&self, shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
         // A tempting optimization is to reuse the tessellation from last frame if the
         // shapes are the same, but just comparing the shapes takes about 50% of the time
         // it takes to tessellate them, so it is not a worth optimization.
+        // It is opt-in with `Options::tessellation_cache`, see `Self::tessellate_layers`.
 
         let pixels_per_point = self.pixels_per_point();
-        let tessellation_options = *self.tessellation_options();
+        let tessellation_options = self
+            .frame_timing()
+            .tessellation_options(*self.tessellation_options());
         let texture_atlas = self.fonts().texture_atlas();
         let font_tex_size = texture_atlas.lock().size();
         let prepared_discs = texture_atlas.lock().prepared_discs();
@@ -898,6 +1492,51 @@ impl Context {
         clipped_primitives
     }
 
+    /// Tessellate the [`FullOutput::shapes`] into triangle meshes,
+    /// using [`FullOutput::shape_layers`] to tell which layer each shape belongs to.
+    ///
+    /// If [`crate::Options::tessellation_cache`] is on, the tessellation of the layers
+    /// whose shapes haven't changed since the last call is reused.
+    /// Otherwise this is the same as [`Self::tessellate`].
+    pub fn tessellate_layers(
+        &self,
+        shapes: Vec<ClippedShape>,
+        shape_layers: &[(LayerId, usize)],
+    ) -> Vec<ClippedPrimitive> {
+        let num_layer_shapes: usize = shape_layers.iter().map(|(_, count)| count).sum();
+        crate::egui_assert!(
+            num_layer_shapes == shapes.len(),
+            "shape_layers does not match the shapes"
+        );
+        if !self.options().tessellation_cache || num_layer_shapes != shapes.len() {
+            return self.tessellate(shapes);
+        }
+
+        let pixels_per_point = self.pixels_per_point();
+        let tessellation_options = self
+            .frame_timing()
+            .tessellation_options(*self.tessellation_options());
+        let texture_atlas = self.fonts().texture_atlas();
+        let font_tex_size = texture_atlas.lock().size();
+        let prepared_discs = texture_atlas.lock().prepared_discs();
+
+        let mut paint_stats = PaintStats::from_shapes(&shapes);
+        let mut ctx_impl = self.write();
+        let cache = &mut ctx_impl.tessellation_cache;
+        let clipped_primitives = cache.tessellate(
+            pixels_per_point,
+            tessellation_options,
+            font_tex_size,
+            prepared_discs,
+            shapes,
+            shape_layers,
+        );
+        paint_stats.num_layers_tessellated = cache.num_tessellated;
+        paint_stats.num_layers_reused = cache.num_reused;
+        ctx_impl.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+        clipped_primitives
+    }
+
     // ---------------------------------------------------------------------
 
     /// How much space is used by panels and windows.
@@ -940,6 +1579,23 @@ impl Context {
     /// If `false`, the pointer is outside of any egui area and so
     /// you may be interested in what it is doing (e.g. controlling your game).
     /// Returns `false` if a drag started outside of egui and then moved over an egui area.
+    ///
+    /// Check this after running your ui code for the frame,
+    /// so that e.g. dragging a [`Window`] doesn't also rotate the camera of your game:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # struct Camera { yaw: f32 }
+    /// # let mut camera = Camera { yaw: 0.0 };
+    /// egui::Window::new("Settings").show(ctx, |ui| {
+    ///     ui.label("Drag me around");
+    /// });
+    ///
+    /// if !ctx.wants_pointer_input() && ctx.input().pointer.primary_down() {
+    ///     camera.yaw += ctx.input().pointer.delta().x;
+    /// }
+    /// # });
+    /// ```
     pub fn wants_pointer_input(&self) -> bool {
         self.is_using_pointer() || (self.is_pointer_over_area() && !self.input().pointer.any_down())
     }
@@ -951,6 +1607,8 @@ impl Context {
     }
 
     /// If `true`, egui is currently listening on text input (e.g. typing text in a [`TextEdit`]).
+    ///
+    /// If `false`, you can use the keyboard for your own purposes (e.g. moving around in your game).
     pub fn wants_keyboard_input(&self) -> bool {
         self.memory().interaction.focus.focused().is_some()
     }
@@ -996,6 +1654,56 @@ impl Context {
         }
     }
 
+    /// Scale all the graphics of the given layer about `origin`, and multiply their opacity,
+    /// at the end of the frame.
+    ///
+    /// Unlike [`Self::translate_layer`] this affects everything painted to the layer this frame,
+    /// including what is painted after this call.
+    /// This can be used for window animations, e.g. to make a window "pop" and fade in:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let t = ctx.animate_bool(egui::Id::new("my_window_animation"), true);
+    /// if let Some(response) = egui::Window::new("My window").show(ctx, |ui| ui.label("Hello!")) {
+    ///     let response = response.response;
+    ///     let scale = egui::lerp(0.9..=1.0, t);
+    ///     ctx.set_layer_scale_and_opacity(response.layer_id, response.rect.center(), scale, t);
+    /// }
+    /// # });
+    /// ```
+    ///
+    /// The scaling only affects the painting, not the interaction.
+    pub fn set_layer_scale_and_opacity(
+        &self,
+        layer_id: LayerId,
+        origin: Pos2,
+        scale: f32,
+        opacity: f32,
+    ) {
+        self.frame_state().layer_effects.insert(
+            layer_id,
+            crate::frame_state::LayerEffect {
+                origin,
+                scale,
+                opacity,
+            },
+        );
+    }
+
+    /// A copy of everything painted to the given layer so far this frame.
+    ///
+    /// Call this after all your ui code has run (e.g. at the end of your frame, before [`Self::end_frame`]),
+    /// to get a clean image of a single window or [`Area`] without anything behind or above it.
+    /// Tessellate the shapes with [`Self::tessellate`] and paint them on a cleared background,
+    /// then read back the pixels (e.g. with `egui_glow::Painter::read_screen_rgba`)
+    /// and crop it with [`crate::ColorImage::region`] to the rectangle of the window
+    /// (multiplied by [`Self::pixels_per_point`], since the image is in pixels).
+    ///
+    /// For a [`Window`], use the [`Response::layer_id`] and [`Response::rect`] returned by [`Window::show`].
+    pub fn layer_shapes(&self, layer_id: LayerId) -> Vec<ClippedShape> {
+        self.graphics().list(layer_id).shapes().to_vec()
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         let resize_grab_radius_side = self.style().interaction.resize_grab_radius_side;
@@ -1008,10 +1716,69 @@ impl Context {
         self.memory().areas.move_to_top(layer_id);
     }
 
+    /// The top-most visible layer of the given [`Order`].
+    ///
+    /// For [`Order::Middle`] this is the window that was last interacted with (or moved to the top).
+    pub fn top_layer_id(&self, order: Order) -> Option<LayerId> {
+        self.memory().areas.top_layer_id(order)
+    }
+
+    /// Was the given [`Area`] or [`Window`] shown this frame or the previous one?
+    ///
+    /// The [`LayerId`] of a window is `LayerId::new(Order::Middle, id)`,
+    /// where `id` is `Id::new(title)` unless you set it with [`Window::id`].
+    pub fn is_area_visible(&self, layer_id: LayerId) -> bool {
+        self.memory().areas.is_visible(&layer_id)
+    }
+
+    /// Where the given [`Area`] or [`Window`] was last shown, including any frame and title bar.
+    ///
+    /// `None` if it has never been shown.
+    pub fn area_rect(&self, layer_id: LayerId) -> Option<Rect> {
+        self.memory()
+            .areas
+            .get(layer_id.id)
+            .map(|state| state.rect())
+    }
+
+    /// Move an [`Area`] or [`Window`] so that its left top corner is at `pos`.
+    ///
+    /// If it has not been shown yet, this is where it will appear (instead of at its default position).
+    /// Calling this every frame will prevent the user from moving it.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let id = egui::Id::new("Tools");
+    /// if ctx.input().key_pressed(egui::Key::Home) {
+    ///     ctx.set_area_pos(id, egui::pos2(10.0, 10.0));
+    ///     ctx.move_to_top(egui::LayerId::new(egui::Order::Middle, id));
+    /// }
+    /// egui::Window::new("Tools").show(ctx, |ui| {
+    ///     ui.label("Press Home to bring this window back");
+    /// });
+    /// # });
+    /// ```
+    pub fn set_area_pos(&self, id: Id, pos: Pos2) {
+        let pos = self.round_pos_to_pixels(pos);
+        self.memory().areas.set_pos(id, pos);
+    }
+
+    /// Resize a [`Window`], just as if the user had dragged its resize handles.
+    ///
+    /// Like [`Window::default_size`], this is the size of the contents,
+    /// excluding the frame and title bar.
+    /// The window may still be larger if its contents need more space.
+    pub fn set_window_size(&self, window_id: Id, size: Vec2) {
+        crate::containers::resize::State::set_size(self, window_id.with("resize"), size);
+    }
+
     pub(crate) fn rect_contains_pointer(&self, layer_id: LayerId, rect: Rect) -> bool {
         let pointer_pos = self.input().pointer.interact_pos();
         if let Some(pointer_pos) = pointer_pos {
-            rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
+            // `rect` is in the coordinates of the layer, which may be inside a `Canvas`:
+            let (area_layer_id, transform) = self.frame_state().layer_transform(layer_id);
+            rect.contains(transform.from_screen(pointer_pos))
+                && self.layer_id_at(pointer_pos) == Some(area_layer_id)
         } else {
             false
         }
@@ -1048,8 +1815,32 @@ impl Context {
         self.animate_bool_with_time(id, value, animation_time)
     }
 
+    /// Like [`Self::animate_bool`], but with an easing function applied,
+    /// e.g. one from [`emath::easing`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let is_open = true;
+    /// let how_open = ui.ctx().animate_bool_with_easing(
+    ///     egui::Id::new("my_panel"),
+    ///     is_open,
+    ///     egui::emath::easing::cubic_out,
+    /// );
+    /// # });
+    /// ```
+    pub fn animate_bool_with_easing(&self, id: Id, value: bool, easing: fn(f32) -> f32) -> f32 {
+        easing(self.animate_bool(id, value))
+    }
+
     /// Like [`Self::animate_bool`] but allows you to control the animation time.
+    ///
+    /// With [`Style::reduce_motion`] the animation is skipped.
     pub fn animate_bool_with_time(&self, id: Id, value: bool, animation_time: f32) -> f32 {
+        let animation_time = if self.style().reduce_motion {
+            0.0
+        } else {
+            animation_time
+        };
         let animated_value = {
             let ctx_impl = &mut *self.write();
             ctx_impl
@@ -1066,7 +1857,14 @@ impl Context {
     /// Allows you to smoothly change the f32 value.
     /// At the first call the value is written to memory.
     /// When it is called with a new value, it linearly interpolates to it in the given time.
+    ///
+    /// With [`Style::reduce_motion`] the animation is skipped.
     pub fn animate_value_with_time(&self, id: Id, value: f32, animation_time: f32) -> f32 {
+        let animation_time = if self.style().reduce_motion {
+            0.0
+        } else {
+            animation_time
+        };
         let animated_value = {
             let ctx_impl = &mut *self.write();
             ctx_impl
@@ -1087,6 +1885,160 @@ impl Context {
     }
 }
 
+/// ## Viewports
+impl Context {
+    /// Show the contents of another viewport, i.e. another native window.
+    ///
+    /// Call this every frame the viewport should be open,
+    /// and stop calling it to close it.
+    /// The contents are put in a [`CentralPanel`] that fills the window.
+    ///
+    /// The viewport gets its own input from the backend, its own windows, popups and keyboard focus,
+    /// and its own paint output in [`FullOutput::viewports`].
+    /// All viewports use the [`Context::pixels_per_point`] of the root viewport.
+    ///
+    /// The backend must call [`Self::set_embed_viewports`] with `false` to say it supports this.
+    /// Otherwise (e.g. on the web) the contents are shown in a [`Window`] instead.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let id = egui::ViewportId::from_hash_of("tools");
+    /// let response = ctx.show_viewport(id, egui::ViewportBuilder::new("Tools"), |ui| {
+    ///     ui.label("Hello from another window!");
+    /// });
+    /// if response.close_requested {
+    ///     // stop showing the viewport
+    /// }
+    /// # });
+    /// ```
+    pub fn show_viewport<R>(
+        &self,
+        id: ViewportId,
+        builder: ViewportBuilder,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ViewportResponse<R> {
+        if self.embed_viewports() {
+            return self.show_embedded_viewport(id, builder, add_contents);
+        }
+
+        let (mut raw_input, already_shown) = {
+            let mut ctx_impl = self.write();
+            let already_shown = ctx_impl
+                .viewport_outputs
+                .iter()
+                .any(|output| output.id == id);
+            let raw_input = ctx_impl.viewport_inputs.remove(&id).unwrap_or_default();
+            (raw_input, already_shown)
+        };
+        if already_shown || id == ViewportId::ROOT {
+            let message = if already_shown {
+                format!("The viewport {:?} was shown twice", builder.title)
+            } else {
+                "ViewportId::ROOT can't be shown with show_viewport".to_owned()
+            };
+            self.warn(id.0, message);
+            return ViewportResponse {
+                inner: None,
+                close_requested: false,
+            };
+        }
+
+        // The backend has not created the window yet, so it has given us no input:
+        if raw_input.screen_rect.is_none() {
+            if let Some(inner_size) = builder.inner_size {
+                raw_input.screen_rect = Some(Rect::from_min_size(Pos2::ZERO, inner_size));
+            }
+        }
+        {
+            let parent_input = self.input();
+            raw_input.pixels_per_point = Some(parent_input.pixels_per_point());
+            raw_input.max_texture_side = Some(parent_input.max_texture_side);
+            raw_input.time = Some(parent_input.time);
+        }
+        let close_requested = raw_input.close_requested;
+
+        let parent_id = self.write().set_viewport(id);
+        self.begin_frame(raw_input);
+        let inner = CentralPanel::default().show(self, add_contents).inner;
+        let FullOutput {
+            platform_output,
+            repaint_after: _,  // repaints are requested for all viewports at once
+            textures_delta: _, // sent with the root viewport
+            shapes,
+            shape_layers,
+            viewports: _, // added to `viewport_outputs` directly
+        } = self.end_frame();
+        let mut ctx_impl = self.write();
+        ctx_impl.set_viewport(parent_id);
+        ctx_impl.viewport_outputs.push(ViewportOutput {
+            id,
+            parent: parent_id,
+            builder,
+            platform_output,
+            shapes,
+            shape_layers,
+        });
+
+        ViewportResponse {
+            inner: Some(inner),
+            close_requested,
+        }
+    }
+
+    fn show_embedded_viewport<R>(
+        &self,
+        id: ViewportId,
+        builder: ViewportBuilder,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ViewportResponse<R> {
+        let ViewportBuilder {
+            title,
+            inner_size,
+            position: _, // a position on the screen means nothing inside another viewport
+            resizable,
+        } = builder;
+
+        let mut open = true;
+        let mut window = Window::new(title)
+            .id(id.0)
+            .open(&mut open)
+            .resizable(resizable);
+        if let Some(inner_size) = inner_size {
+            window = window.default_size(inner_size);
+        }
+        let inner = window
+            .show(self, add_contents)
+            .and_then(|response| response.inner);
+
+        ViewportResponse {
+            inner,
+            close_requested: !open,
+        }
+    }
+
+    /// Are viewports shown as [`Window`]s inside the current viewport?
+    ///
+    /// See [`Self::set_embed_viewports`].
+    pub fn embed_viewports(&self) -> bool {
+        self.read().embed_viewports
+    }
+
+    /// Should [`Self::show_viewport`] show its contents in a [`Window`]
+    /// inside the current viewport, instead of in a native window of its own?
+    ///
+    /// Default: `true`. Backends that can open more native windows set this to `false`.
+    pub fn set_embed_viewports(&self, embed_viewports: bool) {
+        self.write().embed_viewports = embed_viewports;
+    }
+
+    /// The viewport whose contents are being run right now.
+    ///
+    /// This is [`ViewportId::ROOT`] except inside of [`Self::show_viewport`].
+    pub fn viewport_id(&self) -> ViewportId {
+        self.read().viewport_id
+    }
+}
+
 impl Context {
     pub fn settings_ui(&self, ui: &mut Ui) {
         use crate::containers::*;
@@ -1104,6 +2056,34 @@ impl Context {
                 tessellation_options.ui(ui);
                 ui.vertical_centered(|ui| reset_button(ui, &mut tessellation_options));
                 *self.tessellation_options() = tessellation_options;
+
+                let mut frame_budget = self.options().frame_budget;
+                ui.horizontal(|ui| {
+                    let mut enabled = frame_budget.is_some();
+                    ui.checkbox(&mut enabled, "Frame budget").on_hover_text(
+                        "Lower the painting quality while frames take longer than this",
+                    );
+                    let mut budget_ms = 1e3 * frame_budget.unwrap_or(1.0 / 60.0);
+                    ui.add_enabled(
+                        enabled,
+                        DragValue::new(&mut budget_ms)
+                            .clamp_range(1.0..=100.0)
+                            .suffix(" ms"),
+                    );
+                    frame_budget = enabled.then(|| 1e-3 * budget_ms);
+                });
+                if self.frame_timing().reduced_quality {
+                    ui.label("Painting quality is reduced to stay within the frame budget");
+                }
+                self.options().frame_budget = frame_budget;
+
+                let mut tessellation_cache = self.options().tessellation_cache;
+                ui.checkbox(
+                    &mut tessellation_cache,
+                    "Reuse the tessellation of unchanged layers",
+                )
+                .on_hover_text("Only worth it for mostly-static UIs");
+                self.options().tessellation_cache = tessellation_cache;
             });
     }
 
@@ -1145,6 +2125,12 @@ impl Context {
             .map_or_else(String::new, |layer| layer.short_debug_format());
         ui.label(format!("Top layer under mouse: {}", top_layer));
 
+        let hovered_widget = self.frame_state().hovered_widget_last_frame;
+        let hovered_widget = hovered_widget.map_or_else(String::new, |(id, rect)| {
+            format!("{} {:?}", id.short_debug_format(), rect)
+        });
+        ui.label(format!("Widget under mouse: {}", hovered_widget));
+
         ui.add_space(16.0);
 
         ui.label(format!(
@@ -1161,6 +2147,12 @@ impl Context {
                 input.ui(ui);
             });
 
+        CollapsingHeader::new("🌲 Ui stack")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.ui_stack_ui(ui);
+            });
+
         CollapsingHeader::new("📊 Paint stats")
             .default_open(false)
             .show(ui, |ui| {
@@ -1178,10 +2170,95 @@ impl Context {
             .default_open(false)
             .show(ui, |ui| {
                 let font_image_size = self.fonts().font_image_size();
-                crate::introspection::font_texture_ui(ui, font_image_size);
+                let fill_ratio = self.fonts().font_atlas_fill_ratio();
+                crate::introspection::font_texture_ui(ui, font_image_size, fill_ratio);
             });
     }
 
+    /// Show the tree of [`Ui`]s created last frame, with their [`Id`]s, layouts and rectangles.
+    ///
+    /// Hover a [`Ui`] to highlight it.
+    ///
+    /// The [`Ui`]s are only recorded while this is shown,
+    /// so the first time it is shown it will be empty for a frame.
+    pub fn ui_stack_ui(&self, ui: &mut Ui) {
+        let (is_recording, ui_stack) = {
+            let mut frame_state = self.frame_state();
+            frame_state.ui_stack_shown = true;
+            (
+                frame_state.record_ui_stack,
+                frame_state.ui_stack_last_frame.clone(),
+            )
+        };
+        if !is_recording {
+            self.request_repaint(); // so we get to show what we record next frame
+        }
+
+        let mut children = vec![vec![]; ui_stack.len()];
+        let mut roots = vec![];
+        for (index, ui_info) in ui_stack.iter().enumerate() {
+            match ui_info.parent {
+                Some(parent) => children[parent].push(index),
+                None => roots.push(index),
+            }
+        }
+
+        ui.label(format!(
+            "{} Ui:s were created last frame, {} of them at the root of a layer.",
+            ui_stack.len(),
+            roots.len()
+        ));
+        ui.label("Hover to highlight");
+
+        for index in roots {
+            self.ui_stack_node_ui(ui, &ui_stack, &children, index);
+        }
+    }
+
+    fn ui_stack_node_ui(
+        &self,
+        ui: &mut Ui,
+        ui_stack: &[crate::frame_state::UiInfo],
+        children: &[Vec<usize>],
+        index: usize,
+    ) {
+        let ui_info = &ui_stack[index];
+        let mut text = format!(
+            "{} {:?}",
+            ui_info.id.short_debug_format(),
+            ui_info.layout.main_dir()
+        );
+        if ui_info.layout.main_wrap() {
+            text += " wrapping";
+        }
+        if ui_info.parent.is_none() {
+            text += &format!(" in {}", ui_info.layer_id.short_debug_format());
+        }
+        let text = RichText::new(text).monospace();
+
+        let hovered = if children[index].is_empty() {
+            ui.add(Label::new(text).sense(Sense::hover())).hovered()
+        } else {
+            CollapsingHeader::new(text)
+                .id_source(("egui_ui_stack", index))
+                .show(ui, |ui| {
+                    for &child in &children[index] {
+                        self.ui_stack_node_ui(ui, ui_stack, children, child);
+                    }
+                })
+                .header_response
+                .hovered()
+        };
+
+        if hovered {
+            let painter = self.debug_painter();
+            painter.debug_rect(ui_info.max_rect, Color32::LIGHT_BLUE, "max_rect");
+            if ui_info.min_rect.is_finite() {
+                painter.debug_rect(ui_info.min_rect, Color32::RED, "min_rect");
+            }
+        }
+    }
+
     /// Show stats about the allocated textures.
     pub fn texture_ui(&self, ui: &mut crate::Ui) {
         let tex_mngr = self.tex_manager();
@@ -1355,3 +2432,74 @@ fn context_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Context>();
 }
+
+#[test]
+fn warnings_are_deduplicated_per_id() {
+    let ctx = Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        ctx.warn(Id::new("a"), "first");
+        ctx.warn(Id::new("a"), "second");
+        ctx.warn(Id::new("b"), "third");
+    });
+    let messages: Vec<String> = ctx.warnings().into_iter().map(|(_, m)| m).collect();
+    assert_eq!(messages, vec!["first".to_owned(), "third".to_owned()]);
+
+    let _ = ctx.run(Default::default(), |_ctx| {});
+    assert!(ctx.warnings().is_empty());
+}
+
+#[test]
+fn viewports_have_their_own_input_and_output() {
+    let ctx = Context::default();
+    ctx.set_embed_viewports(false);
+    let id = ViewportId::from_hash_of("tools");
+    let root_rect = Rect::from_min_size(Pos2::ZERO, vec2(800.0, 600.0));
+    let tools_rect = Rect::from_min_size(Pos2::ZERO, vec2(200.0, 100.0));
+
+    let mut raw_input = RawInput {
+        screen_rect: Some(root_rect),
+        ..Default::default()
+    };
+    raw_input.viewports.insert(
+        id,
+        RawInput {
+            screen_rect: Some(tools_rect),
+            close_requested: true,
+            ..Default::default()
+        },
+    );
+
+    let output = ctx.run(raw_input, |ctx| {
+        let response = ctx.show_viewport(id, ViewportBuilder::new("Tools"), |ui| {
+            assert_eq!(ui.ctx().viewport_id(), id);
+            assert_eq!(ui.ctx().input().screen_rect(), tools_rect);
+            ui.label("Hello");
+        });
+        assert!(response.close_requested);
+        assert_eq!(response.inner, Some(()));
+        assert_eq!(ctx.viewport_id(), ViewportId::ROOT);
+        assert_eq!(ctx.input().screen_rect(), root_rect);
+    });
+    assert_eq!(output.viewports.len(), 1);
+    assert_eq!(output.viewports[0].id, id);
+    assert_eq!(output.viewports[0].parent, ViewportId::ROOT);
+    assert!(!output.viewports[0].shapes.is_empty());
+
+    // Not showing it closes it:
+    let output = ctx.run(Default::default(), |_ctx| {});
+    assert!(output.viewports.is_empty());
+}
+
+#[test]
+fn embedded_viewports_are_windows() {
+    let ctx = Context::default();
+    let output = ctx.run(Default::default(), |ctx| {
+        let id = ViewportId::from_hash_of("tools");
+        let response = ctx.show_viewport(id, ViewportBuilder::new("Tools"), |ui| {
+            assert_eq!(ui.ctx().viewport_id(), ViewportId::ROOT);
+        });
+        assert_eq!(response.inner, Some(()));
+        assert!(!response.close_requested);
+    });
+    assert!(output.viewports.is_empty());
+}
@@ -55,6 +55,8 @@ pub struct RawInput {
     pub events: Vec<Event>,
 
     /// Dragged files hovering over egui.
+    ///
+    /// See the `file_dialog` example for how to show a drop target while these are hovering.
     pub hovered_files: Vec<HoveredFile>,
 
     /// Dragged files dropped into egui.
@@ -65,6 +67,22 @@ pub struct RawInput {
 
     /// The window has the keyboard focus (i.e. is receiving key presses).
     pub has_focus: bool,
+
+    /// The theme (dark or light mode) the user has picked for the operating system or browser,
+    /// if the integration knows it.
+    ///
+    /// See [`crate::Options::follow_system_theme`].
+    pub system_theme: Option<crate::Theme>,
+
+    /// The user asked to close the window, e.g. with its close button.
+    ///
+    /// For viewports other than the root one, this is reported by [`crate::Context::show_viewport`].
+    pub close_requested: bool,
+
+    /// The input for the other viewports, i.e. the other native windows.
+    ///
+    /// Only read for the root viewport. See [`crate::Context::show_viewport`].
+    pub viewports: std::collections::HashMap<crate::ViewportId, RawInput>,
 }
 
 impl Default for RawInput {
@@ -80,6 +98,9 @@ impl Default for RawInput {
             hovered_files: Default::default(),
             dropped_files: Default::default(),
             has_focus: true, // integrations opt into global focus tracking
+            system_theme: None,
+            close_requested: false,
+            viewports: Default::default(),
         }
     }
 }
@@ -101,6 +122,9 @@ impl RawInput {
             hovered_files: self.hovered_files.clone(),
             dropped_files: std::mem::take(&mut self.dropped_files),
             has_focus: self.has_focus,
+            system_theme: self.system_theme,
+            close_requested: std::mem::take(&mut self.close_requested),
+            viewports: std::mem::take(&mut self.viewports),
         }
     }
 
@@ -117,6 +141,9 @@ impl RawInput {
             mut hovered_files,
             mut dropped_files,
             has_focus,
+            system_theme,
+            close_requested,
+            viewports,
         } = newer;
 
         self.screen_rect = screen_rect.or(self.screen_rect);
@@ -129,6 +156,11 @@ impl RawInput {
         self.hovered_files.append(&mut hovered_files);
         self.dropped_files.append(&mut dropped_files);
         self.has_focus = has_focus;
+        self.system_theme = system_theme.or(self.system_theme);
+        self.close_requested |= close_requested;
+        for (id, newer) in viewports {
+            self.viewports.entry(id).or_default().append(newer);
+        }
     }
 }
 
@@ -194,6 +226,13 @@ pub enum Event {
     /// The mouse or touch moved to a new place.
     PointerMoved(Pos2),
 
+    /// The raw, unaccelerated movement of the mouse, in points, since the last event.
+    ///
+    /// Unlike [`Self::PointerMoved`], this keeps being reported when the pointer is at the edge
+    /// of the screen, or locked (see [`crate::PlatformOutput::lock_pointer`]).
+    /// Useful for e.g. first-person camera controls.
+    MouseMoved(Vec2),
+
     /// A mouse button was pressed or released (or a touch started or stopped).
     PointerButton {
         /// Where is the pointer?
@@ -209,6 +248,22 @@ pub enum Event {
         modifiers: Modifiers,
     },
 
+    /// The pressure and tilt of a pen (stylus) or touch, reported together with
+    /// [`Self::PointerMoved`] and [`Self::PointerButton`].
+    ///
+    /// Don't send this for a mouse.
+    PointerPressure {
+        /// How hard the pen is pressed against the surface, in the range `0.0..=1.0`.
+        pressure: f32,
+
+        /// The angle (in radians) between the pen and the normal of the surface,
+        /// along the x and y axes respectively.
+        /// Positive values tilt the pen towards the right and down.
+        ///
+        /// `None` if the device doesn't report it.
+        tilt: Option<Vec2>,
+    },
+
     /// The mouse left the screen, or the last/primary touch input disappeared.
     ///
     /// This means there is no longer a cursor on the screen for hovering etc.
@@ -246,6 +301,28 @@ pub enum Event {
     /// IME composition ended with this final result.
     CompositionEnd(String),
 
+    /// A button on a gamepad (game controller) was pressed or released.
+    ///
+    /// egui doesn't read gamepads itself, so it is up to the integration (or app) to send this,
+    /// e.g. using the `gilrs` crate.
+    /// See [`crate::Options::gamepad_navigation`].
+    GamepadButton {
+        button: GamepadButton,
+
+        /// Was it pressed or released?
+        pressed: bool,
+    },
+
+    /// A stick on a gamepad (game controller) moved.
+    ///
+    /// See [`Self::GamepadButton`].
+    GamepadAxis {
+        axis: GamepadAxis,
+
+        /// In the range `-1.0..=1.0`, where positive values are right (for x) or down (for y).
+        value: f32,
+    },
+
     /// On touch screens, report this *in addition to*
     /// [`Self::PointerMoved`], [`Self::PointerButton`], [`Self::PointerGone`]
     Touch {
@@ -464,6 +541,67 @@ impl std::ops::BitOr for Modifiers {
     }
 }
 
+impl Modifiers {
+    /// Human-readable modifiers, as a prefix for a key name.
+    ///
+    /// ```
+    /// # use egui::Modifiers;
+    /// let modifiers = Modifiers::COMMAND | Modifiers::SHIFT;
+    /// assert_eq!(modifiers.format(false), "Ctrl+Shift+");
+    /// assert_eq!(modifiers.format(true), "⇧⌘");
+    /// ```
+    pub fn format(&self, is_mac: bool) -> String {
+        let mut s = String::new();
+        if is_mac {
+            if self.ctrl {
+                s += "⌃";
+            }
+            if self.alt {
+                s += "⌥";
+            }
+            if self.shift {
+                s += "⇧";
+            }
+            if self.mac_cmd || self.command {
+                s += "⌘";
+            }
+        } else {
+            if self.ctrl || self.command {
+                s += "Ctrl+";
+            }
+            if self.alt {
+                s += "Alt+";
+            }
+            if self.shift {
+                s += "Shift+";
+            }
+        }
+        s
+    }
+}
+
+/// A keyboard shortcut, e.g. `Ctrl+Alt+W`.
+///
+/// Can be used with [`crate::InputState::consume_shortcut`]
+/// and [`crate::Context::register_shortcut`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KeyboardShortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl KeyboardShortcut {
+    pub const fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Human-readable shortcut, e.g. `Ctrl+Shift+O`, or `⇧⌘O` on Mac.
+    pub fn format(&self, is_mac: bool) -> String {
+        format!("{}{}", self.modifiers.format(is_mac), self.key.name())
+    }
+}
+
 /// Keyboard keys.
 ///
 /// Includes all keys egui is interested in (such as `Home` and `End`)
@@ -492,6 +630,11 @@ pub enum Key {
     PageUp,
     PageDown,
 
+    /// The minus key, either from the main row or from the numpad. Used for cmd+minus (zoom out).
+    Minus,
+    /// The `=`/`+` key, or plus from the numpad. Used for cmd+plus (zoom in).
+    PlusEquals,
+
     /// Either from the main row or from the numpad.
     Num0,
     /// Either from the main row or from the numpad.
@@ -563,6 +706,87 @@ pub enum Key {
     F20,
 }
 
+impl Key {
+    /// Human-readable name of the key, e.g. `"A"`, `"F5"` or `"PageUp"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Key::ArrowDown => "⏷",
+            Key::ArrowLeft => "⏴",
+            Key::ArrowRight => "⏵",
+            Key::ArrowUp => "⏶",
+            Key::Escape => "Escape",
+            Key::Tab => "Tab",
+            Key::Backspace => "Backspace",
+            Key::Enter => "Enter",
+            Key::Space => "Space",
+            Key::Insert => "Insert",
+            Key::Delete => "Delete",
+            Key::Home => "Home",
+            Key::End => "End",
+            Key::PageUp => "PageUp",
+            Key::PageDown => "PageDown",
+            Key::Minus => "-",
+            Key::PlusEquals => "+",
+            Key::Num0 => "0",
+            Key::Num1 => "1",
+            Key::Num2 => "2",
+            Key::Num3 => "3",
+            Key::Num4 => "4",
+            Key::Num5 => "5",
+            Key::Num6 => "6",
+            Key::Num7 => "7",
+            Key::Num8 => "8",
+            Key::Num9 => "9",
+            Key::A => "A",
+            Key::B => "B",
+            Key::C => "C",
+            Key::D => "D",
+            Key::E => "E",
+            Key::F => "F",
+            Key::G => "G",
+            Key::H => "H",
+            Key::I => "I",
+            Key::J => "J",
+            Key::K => "K",
+            Key::L => "L",
+            Key::M => "M",
+            Key::N => "N",
+            Key::O => "O",
+            Key::P => "P",
+            Key::Q => "Q",
+            Key::R => "R",
+            Key::S => "S",
+            Key::T => "T",
+            Key::U => "U",
+            Key::V => "V",
+            Key::W => "W",
+            Key::X => "X",
+            Key::Y => "Y",
+            Key::Z => "Z",
+            Key::F1 => "F1",
+            Key::F2 => "F2",
+            Key::F3 => "F3",
+            Key::F4 => "F4",
+            Key::F5 => "F5",
+            Key::F6 => "F6",
+            Key::F7 => "F7",
+            Key::F8 => "F8",
+            Key::F9 => "F9",
+            Key::F10 => "F10",
+            Key::F11 => "F11",
+            Key::F12 => "F12",
+            Key::F13 => "F13",
+            Key::F14 => "F14",
+            Key::F15 => "F15",
+            Key::F16 => "F16",
+            Key::F17 => "F17",
+            Key::F18 => "F18",
+            Key::F19 => "F19",
+            Key::F20 => "F20",
+        }
+    }
+}
+
 impl RawInput {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
@@ -576,6 +800,9 @@ impl RawInput {
             hovered_files,
             dropped_files,
             has_focus,
+            system_theme,
+            close_requested,
+            viewports,
         } = self;
 
         ui.label(format!("screen_rect: {:?} points", screen_rect));
@@ -594,6 +821,9 @@ impl RawInput {
         ui.label(format!("hovered_files: {}", hovered_files.len()));
         ui.label(format!("dropped_files: {}", dropped_files.len()));
         ui.label(format!("has_focus: {}", has_focus));
+        ui.label(format!("system_theme: {:?}", system_theme));
+        ui.label(format!("close_requested: {}", close_requested));
+        ui.label(format!("viewports: {}", viewports.len()));
         ui.scope(|ui| {
             ui.set_min_height(150.0);
             ui.label(format!("events: {:#?}", events))
@@ -635,6 +865,38 @@ pub enum TouchPhase {
     Cancel,
 }
 
+/// A button on a gamepad, named by its position so it works for all layouts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GamepadButton {
+    /// The bottom action button (A on an Xbox controller, ✕ on a PlayStation controller).
+    South,
+
+    /// The right action button (B on an Xbox controller, ○ on a PlayStation controller).
+    East,
+
+    /// The top action button (Y on an Xbox controller, △ on a PlayStation controller).
+    North,
+
+    /// The left action button (X on an Xbox controller, □ on a PlayStation controller).
+    West,
+
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+}
+
+/// A stick axis on a gamepad.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+}
+
 impl From<u64> for TouchId {
     fn from(id: u64) -> Self {
         Self(id)
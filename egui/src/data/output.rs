@@ -30,6 +30,20 @@ pub struct FullOutput {
     ///
     /// You can use [`crate::Context::tessellate`] to turn this into triangles.
     pub shapes: Vec<epaint::ClippedShape>,
+
+    /// Which layer the [`Self::shapes`] belong to:
+    /// the number of consecutive shapes of each layer, in paint order.
+    ///
+    /// Pass this to [`crate::Context::tessellate_layers`] to reuse the tessellation
+    /// of unchanged layers (see [`crate::Options::tessellation_cache`]).
+    pub shape_layers: Vec<(crate::LayerId, usize)>,
+
+    /// The output of the other viewports that were shown this frame,
+    /// with [`crate::Context::show_viewport`].
+    ///
+    /// The backend should open a native window for each new viewport,
+    /// and close the windows of the viewports that are no longer here.
+    pub viewports: Vec<ViewportOutput>,
 }
 
 impl FullOutput {
@@ -40,15 +54,44 @@ impl FullOutput {
             repaint_after,
             textures_delta,
             shapes,
+            shape_layers,
+            viewports,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.repaint_after = repaint_after; // if the last frame doesn't need a repaint, then we don't need to repaint
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
+        self.shape_layers = shape_layers;
+        self.viewports = viewports; // Only the latest viewports are open
     }
 }
 
+/// The output of a viewport other than the root one, see [`crate::Context::show_viewport`].
+///
+/// The textures and the repaint delay of all viewports are in [`FullOutput`].
+#[derive(Clone, PartialEq)]
+pub struct ViewportOutput {
+    pub id: crate::ViewportId,
+
+    /// The viewport [`crate::Context::show_viewport`] was called from.
+    pub parent: crate::ViewportId,
+
+    /// How the window should look.
+    pub builder: crate::ViewportBuilder,
+
+    /// Non-rendering related output, e.g. the cursor icon to show in the window.
+    pub platform_output: PlatformOutput,
+
+    /// What to paint, in the same [`crate::Context::pixels_per_point`] as the root viewport.
+    ///
+    /// Use [`crate::Context::tessellate`] to turn this into triangles.
+    pub shapes: Vec<epaint::ClippedShape>,
+
+    /// See [`FullOutput::shape_layers`].
+    pub shape_layers: Vec<(crate::LayerId, usize)>,
+}
+
 /// The non-rendering part of what egui emits each frame.
 ///
 /// You can access (and modify) this with [`crate::Context::output`].
@@ -60,6 +103,12 @@ pub struct PlatformOutput {
     /// Set the cursor to this icon.
     pub cursor_icon: CursorIcon,
 
+    /// If set, show this image as the mouse cursor instead of [`Self::cursor_icon`].
+    ///
+    /// Integrations that can't show custom cursors fall back to [`Self::cursor_icon`],
+    /// so set both. `eframe` shows it on web, but `egui-winit` can't show it yet.
+    pub custom_cursor: Option<CustomCursor>,
+
     /// If set, open this url.
     pub open_url: Option<OpenUrl>,
 
@@ -85,6 +134,41 @@ pub struct PlatformOutput {
 
     /// Screen-space position of text edit cursor (used for IME).
     pub text_cursor_pos: Option<crate::Pos2>,
+
+    /// A widget wants to keep receiving pointer events while the pointer is down,
+    /// even if the pointer leaves the window (or canvas).
+    ///
+    /// The integration should capture the pointer if it isn't already,
+    /// e.g. with `setPointerCapture` on web. Most native platforms do this automatically while a button is held.
+    ///
+    /// Set with [`crate::Response::request_pointer_capture`].
+    pub capture_pointer: bool,
+
+    /// Lock the pointer in place and hide it, e.g. while rotating a 3D camera.
+    ///
+    /// While locked, [`crate::PointerState::motion`] keeps reporting how the mouse moves.
+    /// Set this every frame for as long as you want the lock; it is released the first frame it isn't set.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.allocate_response(ui.available_size(), egui::Sense::drag());
+    /// if response.dragged_by(egui::PointerButton::Secondary) {
+    ///     ui.output().lock_pointer = true;
+    ///     let look_delta = ui.input().pointer.motion().unwrap_or_default();
+    /// }
+    /// # });
+    /// ```
+    pub lock_pointer: bool,
+
+    /// Things the integration should do with the native window (or browser tab), in order.
+    ///
+    /// Add to this with [`Self::window_command`].
+    pub window_commands: Vec<WindowCommand>,
+
+    /// All the widgets of this frame, for screen readers and other assistive technology.
+    ///
+    /// Only set if [`crate::Options::accessibility_tree`] is on.
+    pub accessibility_tree: Option<AccessibilityTree>,
 }
 
 impl PlatformOutput {
@@ -94,6 +178,19 @@ impl PlatformOutput {
         self.open_url = Some(OpenUrl::same_tab(url));
     }
 
+    /// Ask the integration to do something with the window, e.g. change its title.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("Maximize").clicked() {
+    ///     ui.output().window_command(egui::WindowCommand::Maximize(true));
+    /// }
+    /// # });
+    /// ```
+    pub fn window_command(&mut self, command: WindowCommand) {
+        self.window_commands.push(command);
+    }
+
     /// This can be used by a text-to-speech system to describe the events (if any).
     pub fn events_description(&self) -> String {
         // only describe last event:
@@ -116,14 +213,20 @@ impl PlatformOutput {
     pub fn append(&mut self, newer: Self) {
         let Self {
             cursor_icon,
+            custom_cursor,
             open_url,
             copied_text,
             mut events,
             mutable_text_under_cursor,
             text_cursor_pos,
+            capture_pointer,
+            lock_pointer,
+            mut window_commands,
+            accessibility_tree,
         } = newer;
 
         self.cursor_icon = cursor_icon;
+        self.custom_cursor = custom_cursor;
         if open_url.is_some() {
             self.open_url = open_url;
         }
@@ -133,12 +236,19 @@ impl PlatformOutput {
         self.events.append(&mut events);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.text_cursor_pos = text_cursor_pos.or(self.text_cursor_pos);
+        self.capture_pointer |= capture_pointer;
+        self.lock_pointer |= lock_pointer;
+        self.window_commands.append(&mut window_commands);
+        if accessibility_tree.is_some() {
+            self.accessibility_tree = accessibility_tree;
+        }
     }
 
-    /// Take everything ephemeral (everything except `cursor_icon` currently)
+    /// Take everything ephemeral (everything except `cursor_icon` and `custom_cursor` currently)
     pub fn take(&mut self) -> Self {
         let taken = std::mem::take(self);
         self.cursor_icon = taken.cursor_icon; // eveything else is ephemeral
+        self.custom_cursor = taken.custom_cursor.clone();
         taken
     }
 }
@@ -173,6 +283,55 @@ impl OpenUrl {
     }
 }
 
+/// Something for the integration to do with the native window (or browser tab).
+///
+/// See [`PlatformOutput::window_command`].
+/// Integrations ignore the commands that make no sense for them, e.g. [`Self::Minimize`] on web.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WindowCommand {
+    /// Set the title of the window (or the browser tab).
+    SetTitle(String),
+
+    /// Resize the inner area of the window to this size, in points.
+    SetInnerSize(crate::Vec2),
+
+    /// Minimize the window.
+    Minimize,
+
+    /// Maximize (`true`) or restore (`false`) the window.
+    Maximize(bool),
+
+    /// Close the window, which usually quits the app.
+    ///
+    /// Integrations that don't own the event loop (e.g. `egui-winit`) leave this to the user.
+    Close,
+}
+
+/// A mouse cursor made from an image, see [`PlatformOutput::custom_cursor`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let cursor_image = std::sync::Arc::new(egui::ColorImage::new([16, 16], egui::Color32::WHITE));
+/// if ui.button("Paint").hovered() {
+///     ui.output().cursor_icon = egui::CursorIcon::Crosshair; // fallback
+///     ui.output().custom_cursor = Some(egui::CustomCursor {
+///         image: cursor_image.clone(),
+///         hotspot: [8, 8],
+///     });
+/// }
+/// # });
+/// ```
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct CustomCursor {
+    /// The cursor image, e.g. from [`epaint::ColorImage::from_rgba_unmultiplied`].
+    pub image: std::sync::Arc<epaint::ColorImage>,
+
+    /// The pixel in the image that does the pointing, e.g. the tip of an arrow.
+    pub hotspot: [usize; 2],
+}
+
 /// A mouse cursor icon.
 ///
 /// egui emits a [`CursorIcon`] in [`PlatformOutput`] each frame as a request to the integration.
@@ -372,6 +531,19 @@ pub enum OutputEvent {
     ValueChanged(WidgetInfo),
 }
 
+impl OutputEvent {
+    pub(crate) fn widget_info_mut(&mut self) -> &mut WidgetInfo {
+        match self {
+            Self::Clicked(info)
+            | Self::DoubleClicked(info)
+            | Self::TripleClicked(info)
+            | Self::FocusGained(info)
+            | Self::TextSelectionChanged(info)
+            | Self::ValueChanged(info) => info,
+        }
+    }
+}
+
 impl std::fmt::Debug for OutputEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -385,6 +557,62 @@ impl std::fmt::Debug for OutputEvent {
     }
 }
 
+/// A description of the widgets of a frame, so that the integration can expose them
+/// to screen readers through the accessibility API of the platform.
+///
+/// Unlike [`PlatformOutput::events`], this describes every widget, not just the ones that changed,
+/// so it can be used to navigate the ui.
+///
+/// See [`PlatformOutput::accessibility_tree`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccessibilityTree {
+    /// All widgets of the frame, in the order they were added (which is usually the reading order).
+    ///
+    /// Widgets with the same [`AccessNode::layer_id`] are in the same window (or other area).
+    pub nodes: Vec<AccessNode>,
+
+    /// The widget with keyboard focus, if any.
+    pub focus: Option<crate::Id>,
+}
+
+impl AccessibilityTree {
+    /// The windows (and other areas) with their widgets, in the order they were first added to.
+    pub fn layers(&self) -> Vec<(crate::LayerId, Vec<&AccessNode>)> {
+        let mut layers: Vec<(crate::LayerId, Vec<&AccessNode>)> = vec![];
+        for node in &self.nodes {
+            if let Some((_, nodes)) = layers
+                .iter_mut()
+                .find(|(layer_id, _)| *layer_id == node.layer_id)
+            {
+                nodes.push(node);
+            } else {
+                layers.push((node.layer_id, vec![node]));
+            }
+        }
+        layers
+    }
+}
+
+/// A widget in an [`AccessibilityTree`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccessNode {
+    pub id: crate::Id,
+
+    /// The window (or other area) the widget is in.
+    pub layer_id: crate::LayerId,
+
+    /// Where the widget is, in points.
+    pub rect: crate::Rect,
+
+    /// What kind of widget this is (its role), its label, value and state.
+    pub info: WidgetInfo,
+
+    /// The widget that names this one, set with [`crate::Response::labelled_by`].
+    pub labelled_by: Option<crate::Id>,
+}
+
 /// Describes a widget such as a [`crate::Button`] or a [`crate::TextEdit`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -9,6 +9,57 @@ pub(crate) struct TooltipRect {
     pub count: usize,
 }
 
+/// The widget that should get a [`crate::style::Visuals::focus_ring`] this frame.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct FocusRing {
+    pub id: Id,
+    pub layer_id: LayerId,
+    pub clip_rect: Rect,
+    pub rect: Rect,
+}
+
+/// A shortcut registered with [`Context::register_shortcut`].
+#[derive(Clone, Debug)]
+pub(crate) struct RegisteredShortcut {
+    pub category: String,
+    pub shortcut: KeyboardShortcut,
+    pub description: String,
+}
+
+/// A scale and opacity applied to all the graphics of a layer at the end of the frame.
+/// Set with [`Context::set_layer_scale_and_opacity`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LayerEffect {
+    pub origin: Pos2,
+    pub scale: f32,
+    pub opacity: f32,
+}
+
+/// A layer that is painted inside another layer, with a transform. Used by [`crate::Canvas`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct EmbeddedLayer {
+    pub layer_id: LayerId,
+    /// The layer it is painted in.
+    pub parent: LayerId,
+    /// Where in the [`crate::layers::PaintList`] of the parent it is painted.
+    pub idx: crate::layers::ShapeIdx,
+    /// From the coordinates of this layer to those of the parent.
+    pub transform: CanvasTransform,
+}
+
+/// A [`Ui`] created during a frame, shown in [`Context::inspection_ui`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct UiInfo {
+    pub id: Id,
+    /// Index of the parent [`Ui`] in [`FrameState::ui_stack`].
+    pub parent: Option<usize>,
+    pub layer_id: LayerId,
+    pub layout: Layout,
+    pub max_rect: Rect,
+    /// Set when the [`Ui`] is dropped.
+    pub min_rect: Rect,
+}
+
 /// State that is collected during a frame and then cleared.
 /// Short-term (single frame) memory.
 #[derive(Clone)]
@@ -41,6 +92,67 @@ pub(crate) struct FrameState {
 
     /// horizontal, vertical
     pub(crate) scroll_target: [Option<(RangeInclusive<f32>, Option<Align>)>; 2],
+
+    /// Reported with [`Context::warn`]. At most one per [`Id`].
+    pub(crate) warnings: Vec<(Id, String)>,
+
+    /// The [`Area`] (or [`Window`]) being moved by dragging this frame, if any.
+    /// Used for [`crate::style::Interaction::drag_latency_compensation`].
+    pub(crate) dragged_area: Option<LayerId>,
+
+    /// Registered with [`Context::register_shortcut`], shown with `?`.
+    pub(crate) shortcuts: Vec<RegisteredShortcut>,
+
+    /// The widget with keyboard focus, if the focus ring should be shown this frame.
+    pub(crate) focus_ring: Option<FocusRing>,
+
+    /// If true, all [`Ui`]s created this frame are recorded in [`Self::ui_stack`].
+    /// Set at the start of each frame if [`Context::ui_stack_ui`] was shown the previous frame,
+    /// so that we only pay for it while someone is looking.
+    pub(crate) record_ui_stack: bool,
+
+    /// Set by [`Context::ui_stack_ui`].
+    pub(crate) ui_stack_shown: bool,
+
+    /// All [`Ui`]s created this frame, in the order they were created,
+    /// if [`Self::record_ui_stack`] is set.
+    pub(crate) ui_stack: Vec<UiInfo>,
+
+    /// The [`Self::ui_stack`] of the previous frame, shown in [`Context::inspection_ui`].
+    pub(crate) ui_stack_last_frame: Vec<UiInfo>,
+
+    /// The last widget that was hovered this frame, and its rectangle.
+    pub(crate) hovered_widget: Option<(Id, Rect)>,
+
+    /// The [`Self::hovered_widget`] of the previous frame.
+    pub(crate) hovered_widget_last_frame: Option<(Id, Rect)>,
+
+    /// When the pointer started hovering the widgets that want to show a tooltip this frame.
+    /// Used for [`crate::style::Interaction::tooltip_delay`].
+    pub(crate) tooltip_hover_start: crate::IdMap<f64>,
+
+    /// The [`Self::tooltip_hover_start`] of the previous frame.
+    pub(crate) tooltip_hover_start_last_frame: crate::IdMap<f64>,
+
+    /// If true, [`Response::widget_info`] records all widgets in [`Self::access_nodes`].
+    /// Set from [`crate::Options::accessibility_tree`] at the start of each frame.
+    pub(crate) collect_widget_info: bool,
+
+    /// The widgets shown this frame, if [`Self::collect_widget_info`] is set.
+    pub(crate) access_nodes: Vec<crate::output::AccessNode>,
+
+    /// The output events [`Response::widget_info`] emitted this frame,
+    /// as the id of the widget and the index in [`crate::PlatformOutput::events`].
+    ///
+    /// Used by [`Response::accessibility_label`] to rename them.
+    pub(crate) widget_events: Vec<(Id, usize)>,
+
+    /// Applied to the layers when the paint lists are drained at the end of the frame.
+    pub(crate) layer_effects: ahash::AHashMap<LayerId, LayerEffect>,
+
+    /// Painted into their parent layers when the paint lists are drained at the end of the frame,
+    /// in reverse order so that nested layers are done first.
+    pub(crate) embedded_layers: Vec<EmbeddedLayer>,
 }
 
 impl Default for FrameState {
@@ -53,6 +165,23 @@ impl Default for FrameState {
             tooltip_rect: None,
             scroll_delta: Vec2::ZERO,
             scroll_target: [None, None],
+            warnings: Vec::new(),
+            dragged_area: None,
+            shortcuts: Vec::new(),
+            focus_ring: None,
+            record_ui_stack: false,
+            ui_stack_shown: false,
+            ui_stack: Vec::new(),
+            ui_stack_last_frame: Vec::new(),
+            hovered_widget: None,
+            hovered_widget_last_frame: None,
+            tooltip_hover_start: Default::default(),
+            tooltip_hover_start_last_frame: Default::default(),
+            collect_widget_info: false,
+            access_nodes: Vec::new(),
+            widget_events: Vec::new(),
+            layer_effects: Default::default(),
+            embedded_layers: Vec::new(),
         }
     }
 }
@@ -67,6 +196,23 @@ impl FrameState {
             tooltip_rect,
             scroll_delta,
             scroll_target,
+            warnings,
+            dragged_area,
+            shortcuts,
+            focus_ring,
+            record_ui_stack,
+            ui_stack_shown,
+            ui_stack,
+            ui_stack_last_frame,
+            hovered_widget,
+            hovered_widget_last_frame,
+            tooltip_hover_start,
+            tooltip_hover_start_last_frame,
+            collect_widget_info: _,
+            access_nodes,
+            widget_events,
+            layer_effects,
+            embedded_layers,
         } = self;
 
         used_ids.clear();
@@ -76,6 +222,44 @@ impl FrameState {
         *tooltip_rect = None;
         *scroll_delta = input.scroll_delta;
         *scroll_target = [None, None];
+        warnings.clear();
+        *dragged_area = None;
+        shortcuts.clear();
+        *focus_ring = None;
+        *record_ui_stack = std::mem::take(ui_stack_shown);
+        *ui_stack_last_frame = std::mem::take(ui_stack);
+        *hovered_widget_last_frame = hovered_widget.take();
+        *tooltip_hover_start_last_frame = std::mem::take(tooltip_hover_start);
+        access_nodes.clear();
+        widget_events.clear();
+        layer_effects.clear();
+        embedded_layers.clear();
+    }
+
+    /// The layer that `layer_id` is painted in, following any [`EmbeddedLayer`]s,
+    /// and the transform from the coordinates of `layer_id` to the screen.
+    pub(crate) fn layer_transform(&self, mut layer_id: LayerId) -> (LayerId, CanvasTransform) {
+        let mut transform = CanvasTransform::IDENTITY;
+        while let Some(embedded) = self
+            .embedded_layers
+            .iter()
+            .find(|embedded| embedded.layer_id == layer_id)
+        {
+            transform = embedded.transform.after(transform);
+            layer_id = embedded.parent;
+        }
+        (layer_id, transform)
+    }
+
+    /// Returns the index of the new [`Ui`] in [`Self::ui_stack`],
+    /// or `None` if we are not recording it this frame.
+    pub(crate) fn push_ui(&mut self, ui_info: UiInfo) -> Option<usize> {
+        if self.record_ui_stack {
+            self.ui_stack.push(ui_info);
+            Some(self.ui_stack.len() - 1)
+        } else {
+            None
+        }
     }
 
     /// How much space is still available after panels has been added.
@@ -0,0 +1,86 @@
+/// How long it takes to produce a frame, as reported by the integration
+/// with [`crate::Context::report_frame_time`].
+///
+/// If `ctx.options().frame_budget` is set, this is also used to lower the quality of the painting
+/// while frames take longer than the budget, and to restore it once the load drops.
+/// While the quality is reduced, anti-aliasing (feathering) and shadows are turned off,
+/// and curves are tessellated with fewer segments.
+///
+/// Get it with [`crate::Context::frame_timing`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTiming {
+    /// The CPU time of the last reported frame, in seconds.
+    pub last_frame_time: Option<f32>,
+
+    /// The CPU time per frame, smoothed over the last few frames, in seconds.
+    pub mean_frame_time: Option<f32>,
+
+    /// Is the painting quality currently lowered because frames are over budget?
+    pub reduced_quality: bool,
+}
+
+impl FrameTiming {
+    /// How much of the last frame time goes into [`Self::mean_frame_time`].
+    const SMOOTHING: f32 = 0.1;
+
+    /// Only restore the quality once frames take less than this fraction of the budget,
+    /// so that we don't flip back and forth each frame.
+    const RESTORE_FRACTION: f32 = 0.5;
+
+    pub(crate) fn on_frame_time(&mut self, frame_time: f32, frame_budget: Option<f32>) {
+        self.last_frame_time = Some(frame_time);
+        let mean = match self.mean_frame_time {
+            Some(mean) => crate::lerp(mean..=frame_time, Self::SMOOTHING),
+            None => frame_time,
+        };
+        self.mean_frame_time = Some(mean);
+
+        self.reduced_quality = match frame_budget {
+            Some(budget) if self.reduced_quality => mean > Self::RESTORE_FRACTION * budget,
+            Some(budget) => mean > budget,
+            None => false,
+        };
+    }
+
+    /// The tessellation options to use, given the ones the user asked for.
+    pub(crate) fn tessellation_options(
+        &self,
+        mut options: epaint::TessellationOptions,
+    ) -> epaint::TessellationOptions {
+        if self.reduced_quality {
+            options.feathering = false;
+            options.bezier_tolerance *= 4.0;
+            options.coarse_tessellation_culling = true;
+        }
+        options
+    }
+}
+
+#[test]
+fn test_frame_timing_hysteresis() {
+    let budget = Some(1.0 / 60.0);
+    let mut timing = FrameTiming::default();
+
+    timing.on_frame_time(0.010, budget);
+    assert!(!timing.reduced_quality);
+
+    for _ in 0..50 {
+        timing.on_frame_time(0.030, budget);
+    }
+    assert!(timing.reduced_quality);
+
+    // Just under budget is not enough to restore the quality:
+    for _ in 0..50 {
+        timing.on_frame_time(0.015, budget);
+    }
+    assert!(timing.reduced_quality);
+
+    for _ in 0..50 {
+        timing.on_frame_time(0.002, budget);
+    }
+    assert!(!timing.reduced_quality);
+
+    // Without a budget, the quality is never reduced:
+    timing.on_frame_time(1.0, None);
+    assert!(!timing.reduced_quality);
+}
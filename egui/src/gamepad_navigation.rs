@@ -0,0 +1,134 @@
+use crate::{Event, GamepadAxis, GamepadButton, Key, Modifiers, RawInput, Vec2};
+
+/// Translates gamepad events into the keyboard events egui already uses for navigation,
+/// so that a gamepad can move the focus between widgets and activate them.
+///
+/// * D-pad up/down (or the left stick): previous/next widget, like Shift+Tab/Tab.
+/// * D-pad left/right (or the left stick): like the arrow keys, e.g. to change a slider.
+/// * [`GamepadButton::South`] (A): activate the focused widget, like Enter.
+/// * [`GamepadButton::East`] (B): remove the focus, like Escape.
+///
+/// See [`crate::Options::gamepad_navigation`].
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct GamepadNavigation {
+    /// Latest reported position of the left stick.
+    left_stick: Vec2,
+}
+
+impl GamepadNavigation {
+    /// How far the stick needs to be pushed to count as a press of the d-pad.
+    const STICK_THRESHOLD: f32 = 0.5;
+
+    /// Add the key events corresponding to the gamepad events in `raw`.
+    pub(crate) fn translate(&mut self, raw: &mut RawInput) {
+        let mut keys = vec![];
+
+        for event in &raw.events {
+            match *event {
+                Event::GamepadButton {
+                    button,
+                    pressed: true,
+                } => {
+                    keys.extend(key_for_button(button));
+                }
+                Event::GamepadAxis {
+                    axis: GamepadAxis::LeftStickX,
+                    value,
+                } => {
+                    let button = match stick_push(self.left_stick.x, value) {
+                        Some(true) => Some(GamepadButton::DPadRight),
+                        Some(false) => Some(GamepadButton::DPadLeft),
+                        None => None,
+                    };
+                    keys.extend(button.and_then(key_for_button));
+                    self.left_stick.x = value;
+                }
+                Event::GamepadAxis {
+                    axis: GamepadAxis::LeftStickY,
+                    value,
+                } => {
+                    let button = match stick_push(self.left_stick.y, value) {
+                        Some(true) => Some(GamepadButton::DPadDown),
+                        Some(false) => Some(GamepadButton::DPadUp),
+                        None => None,
+                    };
+                    keys.extend(button.and_then(key_for_button));
+                    self.left_stick.y = value;
+                }
+                _ => {}
+            }
+        }
+
+        for (key, modifiers) in keys {
+            for pressed in [true, false] {
+                raw.events.push(Event::Key {
+                    key,
+                    pressed,
+                    modifiers,
+                });
+            }
+        }
+    }
+}
+
+fn key_for_button(button: GamepadButton) -> Option<(Key, Modifiers)> {
+    match button {
+        GamepadButton::DPadUp => Some((Key::Tab, Modifiers::SHIFT)),
+        GamepadButton::DPadDown => Some((Key::Tab, Modifiers::NONE)),
+        GamepadButton::DPadLeft => Some((Key::ArrowLeft, Modifiers::NONE)),
+        GamepadButton::DPadRight => Some((Key::ArrowRight, Modifiers::NONE)),
+        GamepadButton::South => Some((Key::Enter, Modifiers::NONE)),
+        GamepadButton::East => Some((Key::Escape, Modifiers::NONE)),
+        GamepadButton::North | GamepadButton::West => None,
+    }
+}
+
+/// Was the stick just pushed past the threshold in the positive (`true`) or negative (`false`) direction?
+fn stick_push(old: f32, new: f32) -> Option<bool> {
+    let threshold = GamepadNavigation::STICK_THRESHOLD;
+    if old < threshold && threshold <= new {
+        Some(true)
+    } else if -threshold < old && new <= -threshold {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[test]
+fn test_gamepad_navigation() {
+    let mut navigation = GamepadNavigation::default();
+    let mut raw = RawInput::default();
+    raw.events.push(Event::GamepadButton {
+        button: GamepadButton::South,
+        pressed: true,
+    });
+    raw.events.push(Event::GamepadAxis {
+        axis: GamepadAxis::LeftStickY,
+        value: 0.8,
+    });
+    navigation.translate(&mut raw);
+
+    let pressed_keys: Vec<_> = raw
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } => Some((*key, modifiers.shift)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(pressed_keys, vec![(Key::Enter, false), (Key::Tab, false)]);
+
+    // Holding the stick doesn't repeat:
+    let mut raw = RawInput::default();
+    raw.events.push(Event::GamepadAxis {
+        axis: GamepadAxis::LeftStickY,
+        value: 0.9,
+    });
+    navigation.translate(&mut raw);
+    assert_eq!(raw.events.len(), 1);
+}
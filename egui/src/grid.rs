@@ -68,6 +68,13 @@ pub(crate) struct GridLayout {
     // Cursor:
     col: usize,
     row: usize,
+
+    /// How many columns and rows the next cell spans, set by [`Ui::grid_span`].
+    col_span: usize,
+    row_span: usize,
+
+    /// For each column, the first row that is not covered by a cell spanning several rows.
+    free_from_row: Vec<usize>,
 }
 
 impl GridLayout {
@@ -100,6 +107,10 @@ impl GridLayout {
 
             col: 0,
             row: 0,
+
+            col_span: 1,
+            row_span: 1,
+            free_from_row: vec![],
         }
     }
 }
@@ -117,18 +128,54 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    /// The width of the columns the next cell spans, except the first one, including spacing.
+    fn extra_span_width(&self) -> f32 {
+        (1..self.col_span)
+            .map(|i| self.spacing.x + self.prev_col_width(self.col + i))
+            .sum()
+    }
+
+    /// The height of the rows the next cell spans, except the first one, including spacing.
+    fn extra_span_height(&self) -> f32 {
+        (1..self.row_span)
+            .map(|i| self.spacing.y + self.prev_row_height(self.row + i))
+            .sum()
+    }
+
+    pub(crate) fn set_span(&mut self, columns: usize, rows: usize) {
+        self.col_span = columns.at_least(1);
+        self.row_span = rows.at_least(1);
+    }
+
+    fn is_covered(&self, col: usize) -> bool {
+        self.free_from_row
+            .get(col)
+            .map_or(false, |&free_from_row| self.row < free_from_row)
+    }
+
+    /// Move past the cells covered by cells above that span several rows.
+    fn skip_covered_cells(&mut self, cursor: &mut Rect) {
+        while self.is_covered(self.col) {
+            cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
+            self.col += 1;
+        }
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
         self.max_cell_size.x.is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = self
+            .num_columns
+            .map_or(false, |num_columns| num_columns <= self.col + self.col_span);
+        let extra_width = self.extra_span_width();
 
         let width = if is_last_column {
             (self.initial_available.right() - region.cursor.left()).at_most(self.max_cell_size.x)
         } else if self.max_cell_size.x.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
-            self.max_cell_size.x
+            self.max_cell_size.x + extra_width
         } else {
             // If we want to allow width-filling widgets like [`Separator`] in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
@@ -136,10 +183,11 @@ impl GridLayout {
                 .col_width(self.col)
                 .or_else(|| self.curr_state.col_width(self.col))
                 .unwrap_or(self.min_cell_size.x)
+                + extra_width
         };
 
         // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0) + extra_width);
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -152,8 +200,8 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
-        let height = self.prev_row_height(self.row);
+        let width = self.prev_state.col_width(self.col).unwrap_or(0.0) + self.extra_span_width();
+        let height = self.prev_row_height(self.row) + self.extra_span_height();
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
@@ -169,12 +217,15 @@ impl GridLayout {
     }
 
     pub(crate) fn advance(&mut self, cursor: &mut Rect, _frame_rect: Rect, widget_rect: Rect) {
+        let width = self.prev_col_width(self.col) + self.extra_span_width();
+        let height = self.prev_row_height(self.row) + self.extra_span_height();
+
         let debug_expand_width = self.style.debug.show_expand_width;
         let debug_expand_height = self.style.debug.show_expand_height;
         if debug_expand_width || debug_expand_height {
             let rect = widget_rect;
-            let too_wide = rect.width() > self.prev_col_width(self.col);
-            let too_high = rect.height() > self.prev_row_height(self.row);
+            let too_wide = rect.width() > width;
+            let too_high = rect.height() > height;
 
             if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
                 let painter = self.ctx.debug_painter();
@@ -191,13 +242,34 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
-        self.curr_state
-            .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+        // A cell spanning several columns (or rows) only widens the last of them if it doesn't fit:
+        let last_col = self.col + self.col_span - 1;
+        let last_row = self.row + self.row_span - 1;
+        let leading_width = width - self.prev_col_width(last_col);
+        let leading_height = height - self.prev_row_height(last_row);
+        self.curr_state.set_min_col_width(
+            last_col,
+            (widget_rect.width() - leading_width).max(self.min_cell_size.x),
+        );
+        self.curr_state.set_min_row_height(
+            last_row,
+            (widget_rect.height() - leading_height).max(self.min_cell_size.y),
+        );
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        if self.row_span > 1 {
+            let free_from_row = self.row + self.row_span;
+            self.free_from_row
+                .resize(self.free_from_row.len().max(last_col + 1), 0);
+            for col in self.col..=last_col {
+                self.free_from_row[col] = self.free_from_row[col].max(free_from_row);
+            }
+        }
+
+        cursor.min.x += width + self.spacing.x;
+        self.col += self.col_span;
+        self.col_span = 1;
+        self.row_span = 1;
+        self.skip_covered_cells(cursor);
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
@@ -210,6 +282,8 @@ impl GridLayout {
 
         self.col = 0;
         self.row += 1;
+        self.col_span = 1;
+        self.row_span = 1;
 
         if self.striped && self.row % 2 == 1 {
             if let Some(height) = self.prev_state.row_height(self.row) {
@@ -222,6 +296,8 @@ impl GridLayout {
                 painter.rect_filled(rect, 2.0, self.style.visuals.faint_bg_color);
             }
         }
+
+        self.skip_covered_cells(cursor);
     }
 
     pub(crate) fn save(&self) {
@@ -242,6 +318,8 @@ impl GridLayout {
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// A cell can span several columns and/or rows with [`Ui::grid_span`].
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
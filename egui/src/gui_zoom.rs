@@ -0,0 +1,115 @@
+//! Helpers for zooming the whole GUI of an app (changing [`Context::pixels_per_point`]).
+
+use crate::*;
+
+/// The keyboard shortcuts for zooming, like in a web browser.
+pub mod kb_shortcuts {
+    use super::*;
+
+    pub const ZOOM_IN: KeyboardShortcut =
+        KeyboardShortcut::new(Modifiers::COMMAND, Key::PlusEquals);
+    pub const ZOOM_OUT: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Minus);
+    pub const ZOOM_RESET: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::Num0);
+}
+
+/// Smallest and largest [`Context::pixels_per_point`] you can zoom to.
+const PIXELS_PER_POINT_RANGE: std::ops::RangeInclusive<f32> = 0.2..=4.0;
+
+/// Let the user scale the GUI (change [`Context::pixels_per_point`]) by pressing
+/// Cmd+Plus, Cmd+Minus or Cmd+0, just like in a browser.
+///
+/// `native_pixels_per_point` is the scale of the window, which Cmd+0 goes back to.
+///
+/// Fonts are rebuilt at the new scale at the start of the next frame.
+/// All sizes in egui are in points, so the layout stays the same, just bigger or smaller.
+///
+/// This is called by `eframe` each frame on native, after [`App::update`](https://docs.rs/eframe/latest/eframe/trait.App.html#tymethod.update),
+/// so you can consume the shortcuts in your app if you want to use them for something else.
+/// On the web the browser zoom does the same thing.
+pub fn zoom_with_keyboard_shortcuts(ctx: &Context, native_pixels_per_point: Option<f32>) {
+    let (zoom_in, zoom_out, zoom_reset) = {
+        let mut input = ctx.input_mut();
+        let shift_plus = KeyboardShortcut::new(
+            kb_shortcuts::ZOOM_IN.modifiers | Modifiers::SHIFT,
+            kb_shortcuts::ZOOM_IN.key,
+        ); // Cmd+Shift+= is Cmd+Plus on many keyboards
+        (
+            input.consume_shortcut(&kb_shortcuts::ZOOM_IN) | input.consume_shortcut(&shift_plus),
+            input.consume_shortcut(&kb_shortcuts::ZOOM_OUT),
+            input.consume_shortcut(&kb_shortcuts::ZOOM_RESET),
+        )
+    };
+
+    if zoom_reset {
+        if let Some(native_pixels_per_point) = native_pixels_per_point {
+            ctx.set_pixels_per_point(native_pixels_per_point);
+        }
+    } else {
+        if zoom_in {
+            self::zoom_in(ctx);
+        }
+        if zoom_out {
+            self::zoom_out(ctx);
+        }
+    }
+}
+
+/// Make everything larger.
+pub fn zoom_in(ctx: &Context) {
+    zoom_by(ctx, 0.1);
+}
+
+/// Make everything smaller.
+pub fn zoom_out(ctx: &Context) {
+    zoom_by(ctx, -0.1);
+}
+
+fn zoom_by(ctx: &Context, delta: f32) {
+    let pixels_per_point = ctx.pixels_per_point() + delta;
+    let pixels_per_point = pixels_per_point.clamp(
+        *PIXELS_PER_POINT_RANGE.start(),
+        *PIXELS_PER_POINT_RANGE.end(),
+    );
+    let pixels_per_point = (pixels_per_point * 10.).round() / 10.;
+    ctx.set_pixels_per_point(pixels_per_point);
+}
+
+/// Show buttons for zooming in, out and back to the native scale, e.g. in a "View" menu.
+pub fn zoom_menu_buttons(ui: &mut Ui, native_pixels_per_point: Option<f32>) {
+    if ui
+        .add_enabled(
+            ui.ctx().pixels_per_point() < *PIXELS_PER_POINT_RANGE.end(),
+            Button::new("Zoom In").shortcut_text(ui.ctx().format_shortcut(&kb_shortcuts::ZOOM_IN)),
+        )
+        .clicked()
+    {
+        zoom_in(ui.ctx());
+        ui.close_menu();
+    }
+
+    if ui
+        .add_enabled(
+            ui.ctx().pixels_per_point() > *PIXELS_PER_POINT_RANGE.start(),
+            Button::new("Zoom Out")
+                .shortcut_text(ui.ctx().format_shortcut(&kb_shortcuts::ZOOM_OUT)),
+        )
+        .clicked()
+    {
+        zoom_out(ui.ctx());
+        ui.close_menu();
+    }
+
+    if let Some(native_pixels_per_point) = native_pixels_per_point {
+        if ui
+            .add_enabled(
+                ui.ctx().pixels_per_point() != native_pixels_per_point,
+                Button::new("Reset Zoom")
+                    .shortcut_text(ui.ctx().format_shortcut(&kb_shortcuts::ZOOM_RESET)),
+            )
+            .clicked()
+        {
+            ui.ctx().set_pixels_per_point(native_pixels_per_point);
+            ui.close_menu();
+        }
+    }
+}
@@ -35,7 +35,7 @@ impl Id {
     ///
     /// The null [`Id`] is still a valid id to use in all circumstances,
     /// though obviously it will lead to a lot of collisions if you do use it!
-    pub fn null() -> Self {
+    pub const fn null() -> Self {
         Self(0)
     }
 
@@ -60,6 +60,16 @@ impl Id {
         Id(hasher.finish())
     }
 
+    /// Generate a new [`Id`] by hashing the parent [`Id`] and where in the source code this is called from.
+    ///
+    /// This makes widgets with the same id source on different lines of code get different ids.
+    /// Note that the id changes if you move the line that calls this,
+    /// so any state stored under it (e.g. whether a header is open) is forgotten when you edit your code.
+    #[track_caller]
+    pub fn with_caller_location(self) -> Id {
+        self.with(std::panic::Location::caller())
+    }
+
     /// Short and readable summary
     pub fn short_debug_format(&self) -> String {
         format!("{:04X}", self.0 as u16)
@@ -150,3 +160,15 @@ impl std::hash::BuildHasher for BuilIdHasher {
 
 /// `IdMap<V>` is a `HashMap<Id, V>` optimized by knowing that [`Id`] has good entropy, and doesn't need more hashing.
 pub type IdMap<V> = std::collections::HashMap<Id, V, BuilIdHasher>;
+
+#[test]
+fn test_id_with_caller_location() {
+    let a = Id::new("header").with_caller_location();
+    let b = Id::new("header").with_caller_location();
+    assert_ne!(a, b);
+
+    let ids: Vec<Id> = (0..2)
+        .map(|_| Id::new("header").with_caller_location())
+        .collect();
+    assert_eq!(ids[0], ids[1], "same line gives the same id");
+}
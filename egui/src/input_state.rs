@@ -245,6 +245,14 @@ impl InputState {
         self.pointer.wants_repaint() || self.scroll_delta != Vec2::ZERO || !self.events.is_empty()
     }
 
+    /// Check for a key press of the given shortcut. If found, `true` is returned and the key pressed is consumed,
+    /// so that this will only return `true` once.
+    ///
+    /// See also [`crate::Context::register_shortcut`].
+    pub fn consume_shortcut(&mut self, shortcut: &KeyboardShortcut) -> bool {
+        self.consume_key(shortcut.modifiers, shortcut.key)
+    }
+
     /// Check for a key press. If found, `true` is returned and the key pressed is consumed, so that this will only return `true` once.
     pub fn consume_key(&mut self, modifiers: Modifiers, key: Key) -> bool {
         let mut match_found = false;
@@ -366,7 +374,7 @@ impl InputState {
 
     /// True if there currently are any fingers touching egui.
     pub fn any_touches(&self) -> bool {
-        !self.touch_states.is_empty()
+        self.touch_states.values().any(|t| t.any_touches())
     }
 
     /// Scans `events` for device IDs of touch devices we have not seen before,
@@ -453,6 +461,9 @@ pub struct PointerState {
     /// How much the pointer moved compared to last frame, in points.
     delta: Vec2,
 
+    /// Sum of all [`Event::MouseMoved`] this frame, or `None` if there were none.
+    motion: Option<Vec2>,
+
     /// Current velocity of pointer.
     velocity: Vec2,
 
@@ -484,6 +495,12 @@ pub struct PointerState {
 
     /// All button events that occurred this frame
     pub(crate) pointer_events: Vec<PointerEvent>,
+
+    /// Latest reported [`Event::PointerPressure`], if the pointer is a pen.
+    pressure: Option<f32>,
+
+    /// Latest reported [`Event::PointerPressure`] tilt, if the pointer is a pen that reports it.
+    tilt: Option<Vec2>,
 }
 
 impl Default for PointerState {
@@ -493,6 +510,7 @@ impl Default for PointerState {
             latest_pos: None,
             interact_pos: None,
             delta: Vec2::ZERO,
+            motion: None,
             velocity: Vec2::ZERO,
             pos_history: History::new(0..1000, 0.1),
             down: Default::default(),
@@ -502,6 +520,8 @@ impl Default for PointerState {
             last_click_time: std::f64::NEG_INFINITY,
             last_last_click_time: std::f64::NEG_INFINITY,
             pointer_events: vec![],
+            pressure: None,
+            tilt: None,
         }
     }
 }
@@ -516,10 +536,18 @@ impl PointerState {
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
 
+        // The integration may send the pressure before or after the movement,
+        // so we only forget it if the pointer changed without any new pressure this frame:
+        let mut pointer_changed = false;
+        let mut new_pressure = None;
+
+        self.motion = None;
+
         for event in &new.events {
             match event {
                 Event::PointerMoved(pos) => {
                     let pos = *pos;
+                    pointer_changed = true;
 
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
@@ -541,6 +569,7 @@ impl PointerState {
                     let button = *button;
                     let pressed = *pressed;
                     let modifiers = *modifiers;
+                    pointer_changed = true;
 
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
@@ -596,14 +625,29 @@ impl PointerState {
 
                     self.down[button as usize] = pressed; // must be done after the above call to `could_any_button_be_click`
                 }
+                Event::MouseMoved(delta) => {
+                    *self.motion.get_or_insert(Vec2::ZERO) += *delta;
+                }
+                Event::PointerPressure { pressure, tilt } => {
+                    new_pressure = Some((*pressure, *tilt));
+                }
                 Event::PointerGone => {
                     self.latest_pos = None;
                     // NOTE: we do NOT clear `self.interact_pos` here. It will be cleared next frame.
+                    pointer_changed = true;
                 }
                 _ => {}
             }
         }
 
+        if let Some((pressure, tilt)) = new_pressure {
+            self.pressure = Some(pressure);
+            self.tilt = tilt;
+        } else if pointer_changed {
+            self.pressure = None;
+            self.tilt = None;
+        }
+
         self.delta = if let (Some(old_pos), Some(new_pos)) = (old_pos, self.latest_pos) {
             new_pos - old_pos
         } else {
@@ -630,7 +674,7 @@ impl PointerState {
     }
 
     fn wants_repaint(&self) -> bool {
-        !self.pointer_events.is_empty() || self.delta != Vec2::ZERO
+        !self.pointer_events.is_empty() || self.delta != Vec2::ZERO || self.motion.is_some()
     }
 
     /// How much the pointer moved compared to last frame, in points.
@@ -639,6 +683,19 @@ impl PointerState {
         self.delta
     }
 
+    /// The raw movement of the mouse this frame, in points,
+    /// regardless of where the pointer is or if it is locked.
+    ///
+    /// Unlike [`Self::delta`], this is not limited by the edges of the screen,
+    /// so use this for e.g. first-person camera controls together with
+    /// [`crate::PlatformOutput::lock_pointer`].
+    ///
+    /// `None` if the mouse didn't move, or if the integration doesn't report [`Event::MouseMoved`].
+    #[inline(always)]
+    pub fn motion(&self) -> Option<Vec2> {
+        self.motion
+    }
+
     /// Current velocity of pointer.
     #[inline(always)]
     pub fn velocity(&self) -> Vec2 {
@@ -682,6 +739,30 @@ impl PointerState {
         self.interact_pos
     }
 
+    /// How hard the pen (stylus) is pressed against the surface, in the range `0.0..=1.0`.
+    ///
+    /// `None` for a mouse, or if the device doesn't report pressure.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let pressure = ui.input().pointer.pressure().unwrap_or(1.0);
+    /// let stroke_width = 4.0 * pressure;
+    /// # });
+    /// ```
+    #[inline(always)]
+    pub fn pressure(&self) -> Option<f32> {
+        self.pressure
+    }
+
+    /// The angle (in radians) between the pen (stylus) and the normal of the surface,
+    /// along the x and y axes respectively.
+    ///
+    /// `None` for a mouse, or if the device doesn't report tilt.
+    #[inline(always)]
+    pub fn tilt(&self) -> Option<Vec2> {
+        self.tilt
+    }
+
     /// Do we have a pointer?
     ///
     /// `false` if the mouse is not over the egui area, or if no touches are down on touch screens.
@@ -798,6 +879,18 @@ impl PointerState {
         true
     }
 
+    /// Has the pointer been held down in the same place for too long to be a click?
+    ///
+    /// This is how a long-press on a touch screen is detected.
+    #[inline(always)]
+    pub fn is_long_press(&self) -> bool {
+        self.any_down()
+            && !self.has_moved_too_much_for_a_click
+            && self.press_start_time.map_or(false, |press_start_time| {
+                self.time - press_start_time > MAX_CLICK_DURATION
+            })
+    }
+
     /// Is the primary button currently down?
     #[inline(always)]
     pub fn primary_down(&self) -> bool {
@@ -892,6 +985,7 @@ impl PointerState {
             latest_pos,
             interact_pos,
             delta,
+            motion,
             velocity,
             pos_history: _,
             down,
@@ -901,11 +995,14 @@ impl PointerState {
             last_click_time,
             last_last_click_time,
             pointer_events,
+            pressure,
+            tilt,
         } = self;
 
         ui.label(format!("latest_pos: {:?}", latest_pos));
         ui.label(format!("interact_pos: {:?}", interact_pos));
         ui.label(format!("delta: {:?}", delta));
+        ui.label(format!("motion: {:?}", motion));
         ui.label(format!(
             "velocity: [{:3.0} {:3.0}] points/sec",
             velocity.x, velocity.y
@@ -920,5 +1017,7 @@ impl PointerState {
         ui.label(format!("last_click_time: {:#?}", last_click_time));
         ui.label(format!("last_last_click_time: {:#?}", last_last_click_time));
         ui.label(format!("pointer_events: {:?}", pointer_events));
+        ui.label(format!("pressure: {:?}", pressure));
+        ui.label(format!("tilt: {:?}", tilt));
     }
 }
@@ -172,6 +172,11 @@ impl TouchState {
         self.gesture_state.is_some()
     }
 
+    /// Are there any fingers touching the surface of this device?
+    pub fn any_touches(&self) -> bool {
+        !self.active_touches.is_empty()
+    }
+
     pub fn info(&self) -> Option<MultiTouchInfo> {
         self.gesture_state.as_ref().map(|state| {
             // state.previous can be `None` when the number of simultaneous touches has just
@@ -23,7 +23,11 @@ pub fn font_id_ui(ui: &mut Ui, font_id: &mut FontId) {
 }
 
 // Show font texture in demo Ui
-pub(crate) fn font_texture_ui(ui: &mut Ui, [width, height]: [usize; 2]) -> Response {
+pub(crate) fn font_texture_ui(
+    ui: &mut Ui,
+    [width, height]: [usize; 2],
+    fill_ratio: f32,
+) -> Response {
     ui.vertical(|ui| {
         let color = if ui.visuals().dark_mode {
             Color32::WHITE
@@ -35,6 +39,10 @@ pub(crate) fn font_texture_ui(ui: &mut Ui, [width, height]: [usize; 2]) -> Respo
             "Texture size: {} x {} (hover to zoom)",
             width, height
         ));
+        ui.label(format!("Atlas occupancy: {:.0}%", 100.0 * fill_ratio))
+            .on_hover_text(
+                "The font atlas grows as needed. When it is full, it is cleared and all glyphs are rendered again.",
+            );
         if width <= 1 || height <= 1 {
             return;
         }
@@ -97,6 +105,8 @@ impl Widget for &epaint::stats::PaintStats {
                 clipped_primitives,
                 vertices,
                 indices,
+                num_layers_tessellated,
+                num_layers_reused,
             } = self;
 
             ui.label("Intermediate:");
@@ -122,6 +132,10 @@ impl Widget for &epaint::stats::PaintStats {
                 .on_hover_text("Number of separate clip rectangles");
             label(ui, vertices, "vertices");
             label(ui, indices, "indices").on_hover_text("Three 32-bit indices per triangles");
+            if num_layers_tessellated + num_layers_reused > 0 {
+                ui.label(format!("{:6} layers tessellated", num_layers_tessellated));
+                ui.label(format!("{:6} layers reused", num_layers_reused));
+            }
             ui.add_space(10.0);
 
             // ui.label("Total:");
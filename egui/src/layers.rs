@@ -109,7 +109,7 @@ impl LayerId {
 }
 
 /// A unique identifier of a specific [`Shape`] in a [`PaintList`].
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct ShapeIdx(usize);
 
 /// A list of [`Shape`]s paired with a clip rectangle.
@@ -147,6 +147,42 @@ impl PaintList {
         self.0[idx.0] = ClippedShape(clip_rect, shape);
     }
 
+    /// Add a [`Shape`] at the place of an existing one, after what is already there.
+    ///
+    /// This lets you use a `ShapeIdx` as a "sub-layer" that you can keep adding shapes to,
+    /// which will be painted behind everything added to the [`PaintList`] after it.
+    ///
+    /// All the shapes at `idx` share one clip rectangle, which is set to `clip_rect`.
+    pub fn add_at(&mut self, idx: ShapeIdx, clip_rect: Rect, shape: Shape) {
+        let ClippedShape(existing_clip_rect, existing) = &mut self.0[idx.0];
+        *existing_clip_rect = clip_rect;
+        match existing {
+            Shape::Noop => *existing = shape,
+            Shape::Vec(shapes) => shapes.push(shape),
+            _ => {
+                let previous = std::mem::replace(existing, Shape::Noop);
+                *existing = Shape::Vec(vec![previous, shape]);
+            }
+        }
+    }
+
+    /// Replace the [`Shape`] at `idx` with any number of shapes, each with its own clip rectangle.
+    ///
+    /// This moves the shapes after `idx`, so replace the last index first.
+    pub(crate) fn replace_with(&mut self, idx: ShapeIdx, shapes: Vec<ClippedShape>) {
+        self.0.splice(idx.0..=idx.0, shapes);
+    }
+
+    /// Remove all the shapes.
+    pub(crate) fn take(&mut self) -> Vec<ClippedShape> {
+        std::mem::take(&mut self.0)
+    }
+
+    /// All the shapes added so far.
+    pub fn shapes(&self) -> &[ClippedShape] {
+        &self.0
+    }
+
     /// Translate each [`Shape`] and clip rectangle by this much, in-place
     pub fn translate(&mut self, delta: Vec2) {
         for ClippedShape(clip_rect, shape) in &mut self.0 {
@@ -166,8 +202,9 @@ impl GraphicLayers {
             .or_default()
     }
 
-    pub fn drain(&mut self, area_order: &[LayerId]) -> impl ExactSizeIterator<Item = ClippedShape> {
-        let mut all_shapes: Vec<_> = Default::default();
+    /// The shapes of each layer, in paint order.
+    pub fn drain_layers(&mut self, area_order: &[LayerId]) -> Vec<(LayerId, Vec<ClippedShape>)> {
+        let mut all_layers: Vec<_> = Default::default();
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -181,17 +218,19 @@ impl GraphicLayers {
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        all_shapes.append(&mut list.0);
+                        all_layers.push((*layer_id, std::mem::take(&mut list.0)));
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
-            for shapes in order_map.values_mut() {
-                all_shapes.append(&mut shapes.0);
+            for (id, list) in order_map.iter_mut() {
+                if !list.is_empty() {
+                    all_layers.push((LayerId::new(order, *id), std::mem::take(&mut list.0)));
+                }
             }
         }
 
-        all_shapes.into_iter()
+        all_layers
     }
 }
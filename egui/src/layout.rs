@@ -104,6 +104,21 @@ impl Direction {
 
 // ----------------------------------------------------------------------------
 
+/// How to spread out widgets along the main direction, see [`crate::Ui::justify_content`].
+///
+/// Named after the `justify-content` of CSS.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum JustifyContent {
+    /// The first widget is at the start, the last at the end, with equal space between them.
+    SpaceBetween,
+
+    /// Equal space around each widget, so the space at the start and the end is half of that between two widgets.
+    SpaceAround,
+}
+
+// ----------------------------------------------------------------------------
+
 /// The layout of a [`Ui`][`crate::Ui`], e.g. "vertical & centered".
 ///
 /// ```
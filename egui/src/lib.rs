@@ -304,7 +304,10 @@ pub mod containers;
 mod context;
 mod data;
 mod frame_state;
+mod frame_timing;
+mod gamepad_navigation;
 pub(crate) mod grid;
+pub mod gui_zoom;
 mod id;
 mod input_state;
 pub mod introspection;
@@ -317,8 +320,10 @@ pub(crate) mod placer;
 mod response;
 mod sense;
 pub mod style;
+mod tessellation_cache;
 mod ui;
 pub mod util;
+mod viewport;
 pub mod widget_text;
 pub mod widgets;
 
@@ -331,7 +336,7 @@ pub use epaint::hex_color;
 pub use epaint::{
     color, mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
-    textures::{TextureFilter, TexturesDelta},
+    textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
     ClippedPrimitive, Color32, ColorImage, FontImage, ImageData, Mesh, PaintCallback,
     PaintCallbackInfo, Rgba, Rounding, Shape, Stroke, TextureHandle, TextureId,
 };
@@ -349,8 +354,12 @@ pub use {
     context::Context,
     data::{
         input::*,
-        output::{self, CursorIcon, FullOutput, PlatformOutput, WidgetInfo},
+        output::{
+            self, AccessNode, AccessibilityTree, CursorIcon, CustomCursor, FullOutput,
+            PlatformOutput, ViewportOutput, WidgetInfo, WindowCommand,
+        },
     },
+    frame_timing::FrameTiming,
     grid::Grid,
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
@@ -360,13 +369,17 @@ pub use {
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
-    style::{FontSelection, Style, TextStyle, Visuals},
+    style::{FontSelection, Style, TextStyle, Theme, Visuals},
     text::{Galley, TextFormat},
     ui::Ui,
+    viewport::{ViewportBuilder, ViewportId, ViewportResponse},
     widget_text::{RichText, WidgetText},
     widgets::*,
 };
 
+/// The version of egui, e.g. for showing in an about panel.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 // ----------------------------------------------------------------------------
 
 /// Helper function that adds a label when compiling with debug assertions enabled.
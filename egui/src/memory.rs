@@ -1,6 +1,6 @@
 use epaint::ahash::AHashSet;
 
-use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style};
+use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style, Vec2};
 
 // ----------------------------------------------------------------------------
 
@@ -116,6 +116,70 @@ pub struct Options {
     /// This can lead to fewer texture operations, but may use up the texture atlas quicker
     /// if you are changing [`Style::text_styles`], of have a lot of text styles.
     pub preload_font_glyphs: bool,
+
+    /// If true, pressing `?` (when no widget has keyboard focus) toggles an overlay
+    /// listing all shortcuts registered with [`crate::Context::register_shortcut`] this frame.
+    pub shortcut_overlay: bool,
+
+    /// If set, lower the painting quality while frames take more than this many seconds of CPU time,
+    /// and restore it when the load drops. This keeps egui responsive on weak hardware.
+    ///
+    /// This only works if the integration reports how long each frame takes,
+    /// using [`crate::Context::report_frame_time`] (`eframe` does this).
+    /// See [`crate::FrameTiming`].
+    ///
+    /// Default: `None` (off).
+    pub frame_budget: Option<f32>,
+
+    /// If true, [`crate::Event::GamepadButton`] and [`crate::Event::GamepadAxis`]
+    /// can be used to move the keyboard focus between widgets and to activate them,
+    /// e.g. for in-game menus.
+    ///
+    /// The d-pad (or left stick) up and down moves the focus, left and right works like the arrow keys
+    /// (e.g. for sliders), the south button (A) activates the focused widget,
+    /// and the east button (B) removes the focus.
+    ///
+    /// Default: `true`.
+    pub gamepad_navigation: bool,
+
+    /// If set, this shortcut toggles a window with [`crate::Context::inspection_ui`],
+    /// which includes the tree of [`crate::Ui`]s of the last frame and the widget under the mouse.
+    ///
+    /// For instance `KeyboardShortcut::new(Modifiers::NONE, Key::F12)`.
+    ///
+    /// Default: `None`.
+    pub inspection_shortcut: Option<crate::KeyboardShortcut>,
+
+    /// If true, egui describes all widgets each frame in [`crate::PlatformOutput::accessibility_tree`],
+    /// so that the integration can expose them through the accessibility API of the platform.
+    ///
+    /// On web, `eframe` exposes the tree to screen readers as invisible ARIA elements over the canvas.
+    /// On native, nothing reads the tree yet, except for what you do with [`crate::PlatformOutput::accessibility_tree`]
+    /// (also available as `egui_winit::State::accessibility_tree`).
+    ///
+    /// Default: `false`.
+    pub accessibility_tree: bool,
+
+    /// If true, the visuals are switched to [`crate::Theme::egui_visuals`] whenever
+    /// [`crate::RawInput::system_theme`] changes, i.e. when the user switches between dark and light mode
+    /// in the operating system or browser while the app is running.
+    /// Any other changes to the visuals are overwritten when that happens.
+    ///
+    /// The first theme reported by the integration is not applied, so that the visuals
+    /// the app starts with are kept (`eframe` starts with the system theme already).
+    ///
+    /// Default: `false`.
+    pub follow_system_theme: bool,
+
+    /// If true, [`crate::Context::tessellate_layers`] reuses the tessellation from last frame
+    /// of each layer whose shapes have not changed.
+    ///
+    /// Comparing the shapes of a layer takes about half the time of tessellating them,
+    /// so this is only a win for mostly-static UIs.
+    /// It also means meshes are never merged across layers, which can lead to a few more draw calls.
+    ///
+    /// Default: `false`.
+    pub tessellation_cache: bool,
 }
 
 impl Default for Options {
@@ -125,6 +189,13 @@ impl Default for Options {
             tessellation_options: Default::default(),
             screen_reader: false,
             preload_font_glyphs: true,
+            shortcut_overlay: true,
+            frame_budget: None,
+            gamepad_navigation: true,
+            inspection_shortcut: None,
+            accessibility_tree: false,
+            follow_system_theme: false,
+            tessellation_cache: false,
         }
     }
 }
@@ -191,6 +262,10 @@ pub(crate) struct Focus {
 
     /// Set at the beginning of the frame, set to `false` when "used".
     pressed_shift_tab: bool,
+
+    /// Was the focus last moved with the keyboard?
+    /// If so, we paint [`crate::style::Visuals::focus_ring`] around the focused widget.
+    keyboard_navigation: bool,
 }
 
 impl Interaction {
@@ -236,6 +311,10 @@ impl Focus {
         self.pressed_tab = false;
         self.pressed_shift_tab = false;
         for event in &new_input.events {
+            if matches!(event, crate::Event::PointerButton { pressed: true, .. }) {
+                self.keyboard_navigation = false;
+            }
+
             if matches!(
                 event,
                 crate::Event::Key {
@@ -267,6 +346,12 @@ impl Focus {
     }
 
     pub(crate) fn end_frame(&mut self, used_ids: &IdMap<Rect>) {
+        if self.pressed_shift_tab && self.id.is_none() {
+            // Nothing had focus and the user pressed shift-tab - give focus to the last widget that wants it:
+            self.id_next_frame = self.last_interested;
+            self.keyboard_navigation = true;
+        }
+
         if let Some(id) = self.id {
             // Allow calling `request_focus` one frame and not using it until next frame
             let recently_gained_focus = self.id_previous_frame != Some(id);
@@ -291,14 +376,17 @@ impl Focus {
                 self.id = None;
                 self.give_to_next = true;
                 self.pressed_tab = false;
+                self.keyboard_navigation = true;
             } else if self.pressed_shift_tab && !self.is_focus_locked {
                 self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
                 self.pressed_shift_tab = false;
+                self.keyboard_navigation = true;
             }
         } else if self.pressed_tab && self.id == None && !self.give_to_next {
             // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
             self.id = Some(id);
             self.pressed_tab = false;
+            self.keyboard_navigation = true;
         }
 
         self.last_interested = Some(id);
@@ -330,6 +418,30 @@ impl Memory {
         self.areas.layer_id_at(pos, resize_interact_radius_side)
     }
 
+    /// Is this the first frame the area is shown, either for the first time ever or after having been closed?
+    ///
+    /// Only meaningful after the area has been shown this frame.
+    /// Use this to e.g. play a sound or load data when a window opens.
+    pub fn area_just_opened(&self, layer_id: LayerId) -> bool {
+        self.areas.just_opened(&layer_id)
+    }
+
+    /// Was the last frame the area was shown (including its close animation) the previous frame?
+    ///
+    /// This is true for exactly one frame after the area stops being shown,
+    /// so you can e.g. persist data when a window actually closes.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let layer_id = egui::LayerId::new(egui::Order::Middle, egui::Id::new("My Window"));
+    /// if ctx.memory().area_just_closed(layer_id) {
+    ///     // save the contents of the window
+    /// }
+    /// ```
+    pub fn area_just_closed(&self, layer_id: LayerId) -> bool {
+        self.areas.just_closed(&layer_id)
+    }
+
     /// An iterator over all layers. Back-to-front. Top is last.
     pub fn layer_ids(&self) -> impl ExactSizeIterator<Item = LayerId> + '_ {
         self.areas.order().iter().copied()
@@ -400,6 +512,13 @@ impl Memory {
         }
     }
 
+    /// Should we paint a focus ring around this widget?
+    ///
+    /// True if it has keyboard focus, and the focus was moved there with the keyboard.
+    pub(crate) fn has_visible_focus(&self, id: Id) -> bool {
+        self.has_focus(id) && self.interaction.focus.keyboard_navigation
+    }
+
     /// Register this widget as being interested in getting keyboard focus.
     /// This will allow the user to select it with tab and shift-tab.
     #[inline(always)]
@@ -433,6 +552,32 @@ impl Memory {
     pub fn reset_areas(&mut self) {
         self.areas = Default::default();
     }
+
+    /// Forget the sizes that the user has picked for windows and other [`crate::Resize`] containers.
+    pub fn reset_sizes(&mut self) {
+        self.data.remove_by_type::<crate::resize::State>();
+    }
+
+    /// Forget which windows are collapsed and which
+    /// [`crate::CollapsingHeader`]s are open.
+    pub fn reset_collapsing_states(&mut self) {
+        self.data
+            .remove_by_type::<crate::collapsing_header::InnerState>();
+    }
+
+    /// Forget how far each [`crate::ScrollArea`] is scrolled.
+    pub fn reset_scroll_offsets(&mut self) {
+        self.data.remove_by_type::<crate::scroll_area::State>();
+    }
+
+    /// Forget window positions and sizes, what is collapsed and how far things are scrolled,
+    /// but keep everything else in [`Self::data`] (including your own state).
+    pub fn reset_layout(&mut self) {
+        self.reset_areas();
+        self.reset_sizes();
+        self.reset_collapsing_states();
+        self.reset_scroll_offsets();
+    }
 }
 
 /// ## Popups
@@ -479,6 +624,25 @@ impl Memory {
     }
 }
 
+/// The parts of [`Memory`] that belong to one viewport, see [`crate::Context::show_viewport`].
+#[derive(Default)]
+pub(crate) struct ViewportMemory {
+    interaction: Interaction,
+    window_interaction: Option<window::WindowInteraction>,
+    areas: Areas,
+    popup: Option<Id>,
+}
+
+impl Memory {
+    /// Swap the parts that belong to the current viewport with those of another viewport.
+    pub(crate) fn swap_viewport(&mut self, other: &mut ViewportMemory) {
+        std::mem::swap(&mut self.interaction, &mut other.interaction);
+        std::mem::swap(&mut self.window_interaction, &mut other.window_interaction);
+        std::mem::swap(&mut self.areas, &mut other.areas);
+        std::mem::swap(&mut self.popup, &mut other.popup);
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Keeps track of [`Area`](crate::containers::area::Area)s, which are free-floating [`Ui`](crate::Ui)s.
@@ -487,12 +651,26 @@ impl Memory {
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Areas {
+    #[cfg_attr(
+        feature = "serde",
+        serde(serialize_with = "serialize_persistent_areas")
+    )]
     areas: IdMap<area::State>,
     /// Back-to-front. Top is last.
     order: Vec<LayerId>,
     visible_last_frame: AHashSet<LayerId>,
     visible_current_frame: AHashSet<LayerId>,
 
+    /// Areas playing their close animation.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    closing_last_frame: AHashSet<LayerId>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    closing_current_frame: AHashSet<LayerId>,
+
+    /// Areas that were shown (or closing) in the frame before last, but not in the last frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    just_closed: AHashSet<LayerId>,
+
     /// When an area want to be on top, it is put in here.
     /// At the end of the frame, this is used to reorder the layers.
     /// This means if several layers want to be on top, they will keep their relative order.
@@ -501,6 +679,15 @@ pub struct Areas {
     wants_to_be_on_top: AHashSet<LayerId>,
 }
 
+/// Skip the areas that should not be persisted, see [`crate::Area::persist`].
+#[cfg(feature = "serde")]
+fn serialize_persistent_areas<S: serde::Serializer>(
+    areas: &IdMap<area::State>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(areas.iter().filter(|(_, state)| !state.temporary))
+}
+
 impl Areas {
     pub(crate) fn count(&self) -> usize {
         self.areas.len()
@@ -523,6 +710,28 @@ impl Areas {
         }
     }
 
+    /// Move an area, or decide where a new area will appear.
+    pub(crate) fn set_pos(&mut self, id: Id, pos: Pos2) {
+        self.areas
+            .entry(id)
+            .or_insert_with(|| area::State {
+                pos,
+                size: Vec2::ZERO,
+                interactable: true,
+                temporary: false,
+            })
+            .pos = pos;
+    }
+
+    /// The top-most visible layer of the given [`Order`](crate::Order), e.g. the window that was last interacted with.
+    pub fn top_layer_id(&self, order: crate::Order) -> Option<LayerId> {
+        self.order
+            .iter()
+            .rev()
+            .find(|layer| layer.order == order && self.is_visible(layer))
+            .copied()
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2, resize_interact_radius_side: f32) -> Option<LayerId> {
         for layer in self.order.iter().rev() {
@@ -550,6 +759,21 @@ impl Areas {
         self.visible_last_frame.contains(layer_id) || self.visible_current_frame.contains(layer_id)
     }
 
+    pub fn just_opened(&self, layer_id: &LayerId) -> bool {
+        self.visible_current_frame.contains(layer_id)
+            && !self.visible_last_frame.contains(layer_id)
+            && !self.closing_last_frame.contains(layer_id)
+    }
+
+    pub fn just_closed(&self, layer_id: &LayerId) -> bool {
+        self.just_closed.contains(layer_id)
+    }
+
+    /// Note that the area is playing its close animation, so it is not yet considered closed.
+    pub(crate) fn set_closing(&mut self, layer_id: LayerId) {
+        self.closing_current_frame.insert(layer_id);
+    }
+
     pub fn visible_layer_ids(&self) -> AHashSet<LayerId> {
         self.visible_last_frame
             .iter()
@@ -579,13 +803,26 @@ impl Areas {
         let Self {
             visible_last_frame,
             visible_current_frame,
+            closing_last_frame,
+            closing_current_frame,
+            just_closed,
             order,
             wants_to_be_on_top,
             ..
         } = self;
 
+        *just_closed = visible_last_frame
+            .union(closing_last_frame)
+            .filter(|layer| {
+                !visible_current_frame.contains(layer) && !closing_current_frame.contains(layer)
+            })
+            .copied()
+            .collect();
+
         std::mem::swap(visible_last_frame, visible_current_frame);
         visible_current_frame.clear();
+        std::mem::swap(closing_last_frame, closing_current_frame);
+        closing_current_frame.clear();
         order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
         wants_to_be_on_top.clear();
     }
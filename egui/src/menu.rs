@@ -124,7 +124,13 @@ pub(crate) fn menu_ui<'c, R>(
     let pos = {
         let mut menu_state = menu_state_arc.write();
         menu_state.entry_count = 0;
-        menu_state.rect.min
+        // Use the size from last frame to decide where to put it:
+        popup_position(
+            menu_state.anchor,
+            menu_state.rect.size(),
+            menu_state.side,
+            ctx.input().screen_rect(),
+        )
     };
 
     let area = Area::new(menu_id)
@@ -244,9 +250,10 @@ pub(crate) struct MenuRoot {
 }
 
 impl MenuRoot {
-    pub fn new(position: Pos2, id: Id) -> Self {
+    /// A menu below the `anchor`, e.g. the button that opened it, or a point for context menus.
+    pub fn new(anchor: Rect, id: Id) -> Self {
         Self {
-            menu_state: Arc::new(RwLock::new(MenuState::new(position))),
+            menu_state: Arc::new(RwLock::new(MenuState::new(anchor, PopupSide::Below))),
             id,
         }
     }
@@ -288,8 +295,7 @@ impl MenuRoot {
         {
             // menu not open and button clicked
             // or button hovered while other menu is open
-            let pos = response.rect.left_bottom();
-            return MenuResponse::Create(pos, id);
+            return MenuResponse::Create(response.rect, id);
         } else if input.pointer.any_pressed() && input.pointer.primary_down() {
             if let Some(pos) = input.pointer.interact_pos() {
                 if let Some(root) = root.inner.as_mut() {
@@ -314,7 +320,14 @@ impl MenuRoot {
         id: Id,
     ) -> MenuResponse {
         let response = response.interact(Sense::click());
-        let pointer = &response.ctx.input().pointer;
+        let input = response.ctx.input();
+
+        let is_this_menu_open = root.as_ref().map_or(false, |root| root.id == id);
+        if is_this_menu_open && input.key_pressed(Key::Escape) {
+            return MenuResponse::Close;
+        }
+
+        let pointer = &input.pointer;
         if pointer.any_pressed() {
             if let Some(pos) = pointer.interact_pos() {
                 let mut destroy = false;
@@ -326,7 +339,7 @@ impl MenuRoot {
                 }
                 if !in_old_menu {
                     if response.hovered() && pointer.secondary_down() {
-                        return MenuResponse::Create(pos, id);
+                        return MenuResponse::Create(Rect::from_min_size(pos, Vec2::ZERO), id);
                     } else if (response.hovered() && pointer.primary_down()) || destroy {
                         return MenuResponse::Close;
                     }
@@ -338,8 +351,8 @@ impl MenuRoot {
 
     fn handle_menu_response(root: &mut MenuRootManager, menu_response: MenuResponse) {
         match menu_response {
-            MenuResponse::Create(pos, id) => {
-                root.inner = Some(MenuRoot::new(pos, id));
+            MenuResponse::Create(anchor, id) => {
+                root.inner = Some(MenuRoot::new(anchor, id));
             }
             MenuResponse::Close => root.inner = None,
             MenuResponse::Stay => {}
@@ -363,7 +376,8 @@ impl MenuRoot {
 pub(crate) enum MenuResponse {
     Close,
     Stay,
-    Create(Pos2, Id),
+    /// Open a menu below the given rectangle.
+    Create(Rect, Id),
 }
 
 impl MenuResponse {
@@ -495,6 +509,12 @@ pub(crate) struct MenuState {
     /// Bounding box of this menu (without the sub-menu)
     pub rect: Rect,
 
+    /// What the menu is shown next to, e.g. the button that opened it.
+    anchor: Rect,
+
+    /// Which side of the [`Self::anchor`] we prefer to show the menu on.
+    side: PopupSide,
+
     /// Used to check if any menu in the tree wants to close
     pub response: MenuResponse,
 
@@ -503,9 +523,12 @@ pub(crate) struct MenuState {
 }
 
 impl MenuState {
-    pub fn new(position: Pos2) -> Self {
+    /// A menu on the given `side` of the `anchor`, or the opposite side if there is more room there.
+    pub fn new(anchor: Rect, side: PopupSide) -> Self {
         Self {
-            rect: Rect::from_min_size(position, Vec2::ZERO),
+            rect: Rect::from_min_size(anchor.min, Vec2::ZERO),
+            anchor,
+            side,
             sub_menu: None,
             response: MenuResponse::Stay,
             entry_count: 0,
@@ -562,8 +585,7 @@ impl MenuState {
             // ensure to repaint once even when pointer is not moving
             ui.ctx().request_repaint();
         } else if !open && button.hovered() {
-            let pos = button.rect.right_top();
-            self.open_submenu(sub_id, pos);
+            self.open_submenu(sub_id, button.rect);
         } else if open && !button.hovered() && !self.hovering_current_submenu(pointer) {
             self.close_submenu();
         }
@@ -625,10 +647,11 @@ impl MenuState {
             .and_then(|(k, sub)| if id == *k { Some(sub) } else { None })
     }
 
-    /// Open submenu at position, if not already open.
-    fn open_submenu(&mut self, id: Id, pos: Pos2) {
+    /// Open submenu next to the button, if not already open.
+    fn open_submenu(&mut self, id: Id, button_rect: Rect) {
         if !self.is_open(id) {
-            self.sub_menu = Some((id, Arc::new(RwLock::new(MenuState::new(pos)))));
+            let menu_state = MenuState::new(button_rect, PopupSide::Right);
+            self.sub_menu = Some((id, Arc::new(RwLock::new(menu_state))));
         }
     }
 
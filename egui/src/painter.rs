@@ -202,6 +202,40 @@ impl Painter {
         self.transform_shape(&mut shape);
         self.paint_list().set(idx, self.clip_rect, shape);
     }
+
+    /// Reserve a place in the paint order, a "sub-layer", that you can add any number of shapes to
+    /// later with [`Self::add_to_sub_layer`].
+    ///
+    /// Those shapes are painted behind everything added after this call,
+    /// so this can be used to paint e.g. a selection highlight behind content
+    /// that has already been painted by the time you know where the highlight goes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let background = ui.painter().add_sub_layer();
+    /// let response = ui.label("Some content");
+    /// if response.hovered() {
+    ///     let highlight = egui::Shape::rect_filled(response.rect, 2.0, ui.visuals().selection.bg_fill);
+    ///     ui.painter().add_to_sub_layer(background, highlight);
+    /// }
+    /// # });
+    /// ```
+    pub fn add_sub_layer(&self) -> ShapeIdx {
+        self.paint_list().add(self.clip_rect, Shape::Noop)
+    }
+
+    /// Add a shape to a sub-layer created with [`Self::add_sub_layer`],
+    /// after the shapes already added to it.
+    ///
+    /// All the shapes in a sub-layer use the clip rectangle of the last painter that added to it.
+    pub fn add_to_sub_layer(&self, sub_layer: ShapeIdx, shape: impl Into<Shape>) {
+        if self.fade_to_color == Some(Color32::TRANSPARENT) {
+            return;
+        }
+        let mut shape = shape.into();
+        self.transform_shape(&mut shape);
+        self.paint_list().add_at(sub_layer, self.clip_rect, shape);
+    }
 }
 
 /// ## Debug painting
@@ -265,6 +299,48 @@ impl Painter {
         self.add(Shape::vline(x, y, stroke));
     }
 
+    /// Paints a dashed line through the given points.
+    ///
+    /// Useful for guides and grid lines.
+    pub fn dashed_line(
+        &self,
+        points: &[Pos2],
+        stroke: impl Into<Stroke>,
+        dash_length: f32,
+        gap_length: f32,
+    ) {
+        self.add(Shape::dashed_line(points, stroke, dash_length, gap_length));
+    }
+
+    /// Paints a line of equally spaced dots through the given points.
+    pub fn dotted_line(
+        &self,
+        points: &[Pos2],
+        color: impl Into<Color32>,
+        spacing: f32,
+        radius: f32,
+    ) {
+        self.add(Shape::dotted_line(points, color, spacing, radius));
+    }
+
+    /// Paints the outline of a rectangle with dashes, e.g. for a selection rectangle.
+    pub fn dashed_rect_stroke(
+        &self,
+        rect: Rect,
+        stroke: impl Into<Stroke>,
+        dash_length: f32,
+        gap_length: f32,
+    ) {
+        let points = [
+            rect.left_top(),
+            rect.right_top(),
+            rect.right_bottom(),
+            rect.left_bottom(),
+            rect.left_top(),
+        ];
+        self.dashed_line(&points, stroke, dash_length, gap_length);
+    }
+
     pub fn circle(
         &self,
         center: Pos2,
@@ -5,6 +5,12 @@ pub(crate) struct Placer {
     grid: Option<grid::GridLayout>,
     layout: Layout,
     region: Region,
+
+    /// Added to the item spacing along the main direction, see [`Ui::justify_content`].
+    extra_item_spacing: f32,
+
+    /// How many widgets have been added so far.
+    num_items: usize,
 }
 
 impl Placer {
@@ -14,6 +20,8 @@ impl Placer {
             grid: None,
             layout,
             region,
+            extra_item_spacing: 0.0,
+            num_items: 0,
         }
     }
 
@@ -33,6 +41,11 @@ impl Placer {
         self.grid.as_ref()
     }
 
+    #[inline(always)]
+    pub(crate) fn grid_mut(&mut self) -> Option<&mut grid::GridLayout> {
+        self.grid.as_mut()
+    }
+
     #[inline(always)]
     pub(crate) fn is_grid(&self) -> bool {
         self.grid.is_some()
@@ -72,6 +85,16 @@ impl Placer {
     pub(crate) fn set_cursor(&mut self, cursor: Rect) {
         self.region.cursor = cursor;
     }
+
+    #[inline(always)]
+    pub(crate) fn set_extra_item_spacing(&mut self, extra_item_spacing: f32) {
+        self.extra_item_spacing = extra_item_spacing;
+    }
+
+    #[inline(always)]
+    pub(crate) fn num_items(&self) -> usize {
+        self.num_items
+    }
 }
 
 impl Placer {
@@ -162,9 +185,16 @@ impl Placer {
         egui_assert!(!widget_rect.any_nan());
         self.region.sanity_check();
 
+        self.num_items += 1;
+
         if let Some(grid) = &mut self.grid {
             grid.advance(&mut self.region.cursor, frame_rect, widget_rect);
         } else {
+            let item_spacing = if self.layout.main_dir().is_horizontal() {
+                item_spacing + vec2(self.extra_item_spacing, 0.0)
+            } else {
+                item_spacing + vec2(0.0, self.extra_item_spacing)
+            };
             self.layout.advance_after_rects(
                 &mut self.region.cursor,
                 frame_rect,
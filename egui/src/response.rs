@@ -286,7 +286,8 @@ impl Response {
     /// If dragged, how many points were we dragged and in what direction?
     pub fn drag_delta(&self) -> Vec2 {
         if self.dragged() {
-            self.ctx.input().pointer.delta()
+            let (_, transform) = self.ctx.frame_state().layer_transform(self.layer_id);
+            transform.vec_from_screen(self.ctx.input().pointer.delta())
         } else {
             Vec2::ZERO
         }
@@ -302,7 +303,9 @@ impl Response {
     /// None if the pointer is outside the response area.
     pub fn hover_pos(&self) -> Option<Pos2> {
         if self.hovered() {
-            self.ctx.input().pointer.hover_pos()
+            let (_, transform) = self.ctx.frame_state().layer_transform(self.layer_id);
+            let hover_pos = self.ctx.input().pointer.hover_pos();
+            hover_pos.map(|pos| transform.from_screen(pos))
         } else {
             None
         }
@@ -403,6 +406,27 @@ impl Response {
             return false;
         }
 
+        let tooltip_delay = self.ctx.style().interaction.tooltip_delay;
+        if tooltip_delay > 0.0 {
+            let now = self.ctx.input().time;
+            let hover_start = {
+                let mut frame_state = self.ctx.frame_state();
+                let hover_start = frame_state
+                    .tooltip_hover_start_last_frame
+                    .get(&self.id)
+                    .copied()
+                    .unwrap_or(now);
+                frame_state.tooltip_hover_start.insert(self.id, hover_start);
+                hover_start
+            };
+            let time_left = tooltip_delay - (now - hover_start) as f32;
+            if time_left > 0.0 {
+                self.ctx
+                    .request_repaint_after(std::time::Duration::from_secs_f32(time_left));
+                return false;
+            }
+        }
+
         // We don't want tooltips of things while we are dragging them,
         // but we do want tooltips while holding down on an item on a touch screen.
         if self.ctx.input().pointer.any_down()
@@ -450,6 +474,84 @@ impl Response {
         self
     }
 
+    /// Keep receiving pointer events while this widget is being pressed or dragged,
+    /// even if the pointer leaves the window.
+    ///
+    /// This is useful for e.g. color pickers and [`crate::DragValue`], which should keep
+    /// following the pointer wherever it goes. Call this every frame while dragging.
+    /// It is up to the integration to capture the pointer, see [`crate::PlatformOutput::capture_pointer`].
+    pub fn request_pointer_capture(&self) {
+        if self.dragged() || self.is_pointer_button_down_on() {
+            self.ctx.output().capture_pointer = true;
+        }
+    }
+
+    /// Give the widget a name for screen readers, e.g. for a button that only shows an icon.
+    ///
+    /// This replaces the label of the [`crate::WidgetInfo`] of the widget,
+    /// in [`crate::PlatformOutput::events`] and [`crate::PlatformOutput::accessibility_tree`].
+    /// Call this every frame: the name is only used for the current frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("🗙").accessibility_label("Close").clicked() {
+    ///     /* … */
+    /// }
+    /// # });
+    /// ```
+    pub fn accessibility_label(self, label: impl Into<String>) -> Self {
+        let label = label.into();
+        let event_indices: Vec<usize> = {
+            let mut frame_state = self.ctx.frame_state();
+            for node in &mut frame_state.access_nodes {
+                if node.id == self.id {
+                    node.info.label = Some(label.clone());
+                }
+            }
+            frame_state
+                .widget_events
+                .iter()
+                .filter(|(id, _)| *id == self.id)
+                .map(|(_, index)| *index)
+                .collect()
+        };
+
+        // Rename the events the widget has already emitted this frame:
+        let mut output = self.ctx.output();
+        for index in event_indices {
+            if let Some(event) = output.events.get_mut(index) {
+                event.widget_info_mut().label = Some(label.clone());
+            }
+        }
+        drop(output);
+        self
+    }
+
+    /// Tell screen readers that this widget is named by another widget,
+    /// e.g. a [`crate::Label`] in front of a [`crate::TextEdit`].
+    ///
+    /// This sets [`crate::AccessNode::labelled_by`], and the label of the other widget is used
+    /// for this widget in [`crate::PlatformOutput::accessibility_tree`] if it has no label of its own.
+    /// Call this every frame: the relation is only used for the current frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut name = String::new();
+    /// ui.horizontal(|ui| {
+    ///     let label = ui.label("Name:");
+    ///     ui.text_edit_singleline(&mut name).labelled_by(label.id);
+    /// });
+    /// # });
+    /// ```
+    pub fn labelled_by(self, id: Id) -> Self {
+        for node in &mut self.ctx.frame_state().access_nodes {
+            if node.id == self.id {
+                node.labelled_by = Some(id);
+            }
+        }
+        self
+    }
+
     /// Check for more interactions (e.g. sense clicks on a [`Response`] returned from a label).
     ///
     /// Note that this call will not add any hover-effects to the widget, so when possible
@@ -503,6 +605,20 @@ impl Response {
     /// Call after interacting and potential calls to [`Self::mark_changed`].
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
+
+        if self.ctx.frame_state().collect_widget_info {
+            // The tree is in screen coordinates, also for widgets in a `Canvas`:
+            let (layer_id, transform) = self.ctx.frame_state().layer_transform(self.layer_id);
+            let node = crate::output::AccessNode {
+                id: self.id,
+                layer_id,
+                rect: transform.to_screen_rect(self.rect),
+                info: make_info(),
+                labelled_by: None, // set by `Self::labelled_by`
+            };
+            self.ctx.frame_state().access_nodes.push(node);
+        }
+
         let event = if self.clicked() {
             Some(OutputEvent::Clicked(make_info()))
         } else if self.double_clicked() {
@@ -517,7 +633,12 @@ impl Response {
             None
         };
         if let Some(event) = event {
-            self.ctx.output().events.push(event);
+            let index = {
+                let mut output = self.ctx.output();
+                output.events.push(event);
+                output.events.len() - 1
+            };
+            self.ctx.frame_state().widget_events.push((self.id, index));
         }
     }
 
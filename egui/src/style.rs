@@ -182,6 +182,16 @@ pub struct Style {
     /// * `Some(false)`: default off
     pub wrap: Option<bool>,
 
+    /// Mirror widgets for right-to-left languages.
+    ///
+    /// Collapsing headers put their arrow on the right, indentation and the indentation line
+    /// are on the right, horizontal sliders fill from the right
+    /// and the vertical scroll bar of a [`crate::ScrollArea`] is on the left.
+    ///
+    /// This is independent of [`crate::Layout::right_to_left`], which only controls
+    /// in what order widgets are placed. Default: `false`.
+    pub right_to_left: bool,
+
     /// Sizes and distances between widgets
     pub spacing: Spacing,
 
@@ -194,6 +204,12 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// Turn off animations (e.g. when collapsing headers and windows open and close)
+    /// and kinetic scrolling, for users who are sensitive to motion.
+    ///
+    /// This affects everything animated with [`crate::Context::animate_bool`] and similar.
+    pub reduce_motion: bool,
+
     /// Options to help debug why egui behaves strangely.
     pub debug: DebugOptions,
 
@@ -222,6 +238,22 @@ impl Style {
         visuals
     }
 
+    /// A style with [`Visuals::high_contrast`], e.g. for users with low vision.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.set_style(egui::Style {
+    ///     reduce_motion: true,
+    ///     ..egui::Style::high_contrast()
+    /// });
+    /// ```
+    pub fn high_contrast() -> Self {
+        Self {
+            visuals: Visuals::high_contrast(),
+            ..Default::default()
+        }
+    }
+
     /// Style to use for non-interactive widgets.
     pub fn noninteractive(&self) -> &WidgetVisuals {
         &self.visuals.widgets.noninteractive
@@ -393,6 +425,34 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// How many seconds the pointer must hover a widget before its tooltip
+    /// (e.g. [`Response::on_hover_text`]) is shown.
+    ///
+    /// Default: `0.0` (show at once).
+    pub tooltip_delay: f32,
+
+    /// When dragging a window (or movable [`crate::Area`]), paint it where the pointer
+    /// is predicted to be this many seconds into the future, based on the pointer velocity.
+    ///
+    /// This makes dragging feel locked to the cursor at low frame rates.
+    /// A good value is the latency of your integration, e.g. one or two frames (`1.0 / 60.0`).
+    ///
+    /// Default: `0.0` (off).
+    pub drag_latency_compensation: f32,
+
+    /// When using touch, a scroll bar can be grabbed this far outside of it.
+    pub scroll_bar_touch_grab_margin: f32,
+
+    /// If `true`, clicking a scroll bar outside of its handle scrolls one page towards the click.
+    /// If `false`, the handle jumps to where you click, and can be dragged from there.
+    pub scroll_bar_click_to_page: bool,
+
+    /// When dragging a window (or movable [`crate::Area`]) this close to the edge of the screen
+    /// or of another window, it snaps to that edge. This makes it easy to tile windows neatly.
+    ///
+    /// Default: `0.0` (off).
+    pub window_snap_distance: f32,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -472,6 +532,11 @@ pub struct Visuals {
 
     /// Show a background behind collapsing headers.
     pub collapsing_header_frame: bool,
+
+    /// Painted around the widget with keyboard focus, after the user has moved focus with Tab.
+    ///
+    /// Use [`Stroke::none`] to turn it off.
+    pub focus_ring: Stroke,
 }
 
 impl Visuals {
@@ -583,7 +648,7 @@ impl WidgetVisuals {
 }
 
 /// Options for help debug egui by adding extra visualization
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct DebugOptions {
     /// However over widgets to see their rectangles
@@ -593,6 +658,23 @@ pub struct DebugOptions {
     /// Show which widgets make their parent higher
     pub show_expand_height: bool,
     pub show_resize: bool,
+
+    /// Show a badge with the warnings reported with [`crate::Context::warn`] this frame.
+    ///
+    /// On by default in debug builds.
+    pub show_warnings: bool,
+}
+
+impl Default for DebugOptions {
+    fn default() -> Self {
+        Self {
+            debug_on_hover: false,
+            show_expand_width: false,
+            show_expand_height: false,
+            show_resize: false,
+            show_warnings: cfg!(debug_assertions),
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -627,10 +709,12 @@ impl Default for Style {
             override_text_style: None,
             text_styles: default_text_styles(),
             wrap: None,
+            right_to_left: false,
             spacing: Spacing::default(),
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            reduce_motion: false,
             debug: Default::default(),
             explanation_tooltips: false,
         }
@@ -664,6 +748,11 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            tooltip_delay: 0.0,
+            drag_latency_compensation: 0.0,
+            scroll_bar_touch_grab_margin: 8.0,
+            scroll_bar_click_to_page: false,
+            window_snap_distance: 0.0,
         }
     }
 }
@@ -691,6 +780,7 @@ impl Visuals {
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
+            focus_ring: Stroke::new(1.5, Color32::from_rgb(90, 170, 255)),
         }
     }
 
@@ -708,6 +798,24 @@ impl Visuals {
             error_fg_color: Color32::from_rgb(255, 0, 0), // red
             window_shadow: Shadow::big_light(),
             popup_shadow: Shadow::small_light(),
+            focus_ring: Stroke::new(1.5, Color32::from_rgb(0, 155, 255)),
+            ..Self::dark()
+        }
+    }
+
+    /// Dark theme with white text on black, and clearly outlined widgets.
+    pub fn high_contrast() -> Self {
+        Self {
+            widgets: Widgets::high_contrast(),
+            selection: Selection::high_contrast(),
+            hyperlink_color: Color32::from_rgb(0, 255, 255),
+            faint_bg_color: Color32::from_gray(20),
+            extreme_bg_color: Color32::BLACK, // e.g. TextEdit background
+            code_bg_color: Color32::from_gray(40),
+            warn_fg_color: Color32::YELLOW,
+            error_fg_color: Color32::from_rgb(255, 90, 90), // a lighter red, readable on black
+            window_shadow: Shadow::small_dark(),
+            focus_ring: Stroke::new(2.5, Color32::YELLOW),
             ..Self::dark()
         }
     }
@@ -719,6 +827,29 @@ impl Default for Visuals {
     }
 }
 
+/// Dark or Light theme.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Theme {
+    /// Dark mode: light text on a dark background.
+    Dark,
+
+    /// Light mode: dark text on a light background.
+    Light,
+}
+
+impl Theme {
+    /// Get the egui visuals corresponding to this theme.
+    ///
+    /// Use with [`crate::Context::set_visuals`].
+    pub fn egui_visuals(self) -> Visuals {
+        match self {
+            Self::Dark => Visuals::dark(),
+            Self::Light => Visuals::light(),
+        }
+    }
+}
+
 impl Selection {
     fn dark() -> Self {
         Self {
@@ -733,6 +864,13 @@ impl Selection {
             stroke: Stroke::new(1.0, Color32::from_rgb(0, 83, 125)),
         }
     }
+
+    fn high_contrast() -> Self {
+        Self {
+            bg_fill: Color32::from_rgb(0, 60, 160),
+            stroke: Stroke::new(1.0, Color32::WHITE),
+        }
+    }
 }
 
 impl Default for Selection {
@@ -823,6 +961,48 @@ impl Widgets {
     }
 }
 
+impl Widgets {
+    pub fn high_contrast() -> Self {
+        Self {
+            noninteractive: WidgetVisuals {
+                bg_fill: Color32::BLACK,                              // window background
+                bg_stroke: Stroke::new(1.0, Color32::from_gray(200)), // separators, indentation lines, windows outlines
+                fg_stroke: Stroke::new(1.0, Color32::WHITE),          // normal text color
+                rounding: Rounding::same(2.0),
+                expansion: 0.0,
+            },
+            inactive: WidgetVisuals {
+                bg_fill: Color32::from_gray(25),             // button background
+                bg_stroke: Stroke::new(1.0, Color32::WHITE), // outline, so buttons stand out from the background
+                fg_stroke: Stroke::new(1.0, Color32::WHITE), // button text
+                rounding: Rounding::same(2.0),
+                expansion: 0.0,
+            },
+            hovered: WidgetVisuals {
+                bg_fill: Color32::from_gray(50),
+                bg_stroke: Stroke::new(2.0, Color32::YELLOW),
+                fg_stroke: Stroke::new(1.5, Color32::WHITE),
+                rounding: Rounding::same(3.0),
+                expansion: 1.0,
+            },
+            active: WidgetVisuals {
+                bg_fill: Color32::from_gray(70),
+                bg_stroke: Stroke::new(2.0, Color32::WHITE),
+                fg_stroke: Stroke::new(2.0, Color32::YELLOW),
+                rounding: Rounding::same(2.0),
+                expansion: 1.0,
+            },
+            open: WidgetVisuals {
+                bg_fill: Color32::BLACK,
+                bg_stroke: Stroke::new(1.0, Color32::WHITE),
+                fg_stroke: Stroke::new(1.0, Color32::WHITE),
+                rounding: Rounding::same(2.0),
+                expansion: 0.0,
+            },
+        }
+    }
+}
+
 impl Default for Widgets {
     fn default() -> Self {
         Self::dark()
@@ -840,10 +1020,12 @@ impl Style {
             override_text_style,
             text_styles,
             wrap: _,
+            right_to_left,
             spacing,
             interaction,
             visuals,
             animation_time,
+            reduce_motion,
             debug,
             explanation_tooltips,
         } = self;
@@ -887,6 +1069,16 @@ impl Style {
                     .suffix(" s"),
             );
             ui.end_row();
+
+            ui.label("Reduce motion:");
+            ui.checkbox(reduce_motion, "")
+                .on_hover_text("Turn off animations and kinetic scrolling");
+            ui.end_row();
+
+            ui.label("Right-to-left:");
+            ui.checkbox(right_to_left, "")
+                .on_hover_text("Mirror collapsing headers, indentation, sliders and scroll bars");
+            ui.end_row();
         });
 
         ui.collapsing("🔠 Text Styles", |ui| text_styles_ui(ui, text_styles));
@@ -1033,6 +1225,11 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            tooltip_delay,
+            drag_latency_compensation,
+            scroll_bar_touch_grab_margin,
+            scroll_bar_click_to_page,
+            window_snap_distance,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -1042,6 +1239,25 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(
+            Slider::new(tooltip_delay, 0.0..=2.0)
+                .text("tooltip_delay")
+                .suffix(" s"),
+        );
+        ui.add(
+            Slider::new(drag_latency_compensation, 0.0..=0.1)
+                .text("drag_latency_compensation")
+                .suffix(" s"),
+        );
+        ui.add(
+            Slider::new(scroll_bar_touch_grab_margin, 0.0..=20.0)
+                .text("scroll_bar_touch_grab_margin"),
+        );
+        ui.checkbox(
+            scroll_bar_click_to_page,
+            "Click scroll bar outside handle to scroll a page",
+        );
+        ui.add(Slider::new(window_snap_distance, 0.0..=20.0).text("window_snap_distance"));
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -1119,6 +1335,7 @@ impl Visuals {
         ui.horizontal(|ui| {
             ui.selectable_value(self, Self::light(), "☀ Light");
             ui.selectable_value(self, Self::dark(), "🌙 Dark");
+            ui.selectable_value(self, Self::high_contrast(), "◑ High contrast");
         });
     }
 
@@ -1167,6 +1384,7 @@ impl Visuals {
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
+            focus_ring,
         } = self;
 
         ui.collapsing("Background Colors", |ui| {
@@ -1220,6 +1438,7 @@ impl Visuals {
 
         ui.checkbox(button_frame, "Button has a frame");
         ui.checkbox(collapsing_header_frame, "Collapsing header has a frame");
+        stroke_ui(ui, focus_ring, "Keyboard focus ring");
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -1232,6 +1451,7 @@ impl DebugOptions {
             show_expand_width: debug_expand_width,
             show_expand_height: debug_expand_height,
             show_resize: debug_resize,
+            show_warnings,
         } = self;
 
         ui.checkbox(debug_on_hover, "Show debug info on hover");
@@ -1244,6 +1464,7 @@ impl DebugOptions {
             "Show which widgets make their parent higher",
         );
         ui.checkbox(debug_resize, "Debug Resize");
+        ui.checkbox(show_warnings, "Show warnings");
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -0,0 +1,90 @@
+//! Reusing the tessellation of unchanged layers from one frame to the next.
+//!
+//! See [`crate::Options::tessellation_cache`].
+
+use epaint::{tessellator, ClippedPrimitive, ClippedShape, PreparedDisc, TessellationOptions};
+
+use crate::LayerId;
+
+/// Everything except the shapes that affects the tessellation.
+#[derive(Clone, Copy, PartialEq)]
+struct Settings {
+    pixels_per_point: f32,
+    options: TessellationOptions,
+    font_tex_size: [usize; 2],
+}
+
+struct CachedLayer {
+    shapes: Vec<ClippedShape>,
+    primitives: Vec<ClippedPrimitive>,
+}
+
+#[derive(Default)]
+pub(crate) struct TessellationCache {
+    settings: Option<Settings>,
+    layers: ahash::AHashMap<LayerId, CachedLayer>,
+
+    /// The number of layers that had to be tessellated in the last call to [`Self::tessellate`].
+    pub num_tessellated: usize,
+
+    /// The number of layers that could be reused in the last call to [`Self::tessellate`].
+    pub num_reused: usize,
+}
+
+impl TessellationCache {
+    /// Tessellate each layer, reusing the results from last time for the layers whose shapes haven't changed.
+    pub fn tessellate(
+        &mut self,
+        pixels_per_point: f32,
+        options: TessellationOptions,
+        font_tex_size: [usize; 2],
+        prepared_discs: Vec<PreparedDisc>,
+        shapes: Vec<ClippedShape>,
+        shape_layers: &[(LayerId, usize)],
+    ) -> Vec<ClippedPrimitive> {
+        let settings = Settings {
+            pixels_per_point,
+            options,
+            font_tex_size,
+        };
+        if self.settings != Some(settings) {
+            self.settings = Some(settings);
+            self.layers.clear();
+        }
+
+        self.num_tessellated = 0;
+        self.num_reused = 0;
+
+        let mut new_layers = ahash::AHashMap::default();
+        let mut clipped_primitives = Vec::new();
+
+        let mut shapes = shapes.into_iter();
+        for &(layer_id, num_shapes) in shape_layers {
+            let shapes: Vec<ClippedShape> = shapes.by_ref().take(num_shapes).collect();
+            let layer = match self.layers.remove(&layer_id) {
+                Some(cached) if cached.shapes == shapes => {
+                    self.num_reused += 1;
+                    cached
+                }
+                _ => {
+                    self.num_tessellated += 1;
+                    let primitives = tessellator::tessellate_shapes_ref(
+                        pixels_per_point,
+                        options,
+                        font_tex_size,
+                        prepared_discs.clone(),
+                        &shapes,
+                    );
+                    CachedLayer { shapes, primitives }
+                }
+            };
+            clipped_primitives.extend(layer.primitives.iter().cloned());
+            new_layers.insert(layer_id, layer);
+        }
+
+        // Layers that were not painted this time are dropped.
+        self.layers = new_layers;
+
+        clipped_primitives
+    }
+}
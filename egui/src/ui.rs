@@ -6,8 +6,8 @@ use std::sync::Arc;
 use epaint::mutex::{RwLock, RwLockReadGuard, RwLockWriteGuard};
 
 use crate::{
-    color::*, containers::*, epaint::text::Fonts, layout::*, menu::MenuState, placer::Placer,
-    widgets::*, *,
+    color::*, containers::*, epaint::text::Fonts, frame_state::UiInfo, layout::*, menu::MenuState,
+    placer::Placer, widgets::*, *,
 };
 
 // ----------------------------------------------------------------------------
@@ -61,6 +61,105 @@ pub struct Ui {
 
     /// Indicates whether this Ui belongs to a Menu.
     menu_state: Option<Arc<RwLock<MenuState>>>,
+
+    /// Set by [`Self::add_filling`], so we can measure the space taken by the widgets after it
+    /// when this [`Ui`] is done.
+    filling: Option<Filling>,
+
+    /// Set by [`Self::justify_content`], so we can measure the widgets
+    /// when this [`Ui`] is done.
+    justified: Option<Justified>,
+
+    /// Where this [`Ui`] is in the stack of [`Ui`]s shown by [`Context::inspection_ui`],
+    /// if it is being recorded.
+    stack_index: Option<usize>,
+}
+
+/// Set by [`Ui::justify_content`].
+struct Justified {
+    id: Id,
+
+    /// The size of the widgets along the main direction and how many they were, last frame.
+    measured: (f32, usize),
+
+    /// [`Placer::num_items`] when [`Ui::justify_content`] was called.
+    first_item: usize,
+
+    /// Where the first widget was put along the main direction.
+    start: f32,
+
+    /// The spacing we used between the widgets this frame.
+    spacing: f32,
+}
+
+/// A widget added with [`Ui::add_filling`].
+struct Filling {
+    id: Id,
+
+    /// How much space the widgets after the filling widget needed last frame.
+    space_after: f32,
+
+    /// Where the filling widget ended.
+    rect: Rect,
+}
+
+impl Drop for Ui {
+    fn drop(&mut self) {
+        if let Some(stack_index) = self.stack_index {
+            let min_rect = self.min_rect();
+            if let Some(ui_info) = self.ctx().frame_state().ui_stack.get_mut(stack_index) {
+                ui_info.min_rect = min_rect;
+            }
+        }
+
+        if let Some(Filling {
+            id,
+            space_after,
+            rect,
+        }) = self.filling.take()
+        {
+            let min_rect = self.min_rect();
+            let new_space_after = match self.layout().main_dir() {
+                Direction::LeftToRight => min_rect.right() - rect.right(),
+                Direction::RightToLeft => rect.left() - min_rect.left(),
+                Direction::TopDown => min_rect.bottom() - rect.bottom(),
+                Direction::BottomUp => rect.top() - min_rect.top(),
+            }
+            .at_least(0.0);
+
+            if new_space_after != space_after {
+                self.ctx().data().insert_temp(id, new_space_after);
+                self.ctx().request_repaint(); // layout with the new size
+            }
+        }
+
+        if let Some(Justified {
+            id,
+            measured,
+            first_item,
+            start,
+            spacing,
+        }) = self.justified.take()
+        {
+            let min_rect = self.min_rect();
+            let end = match self.layout().main_dir() {
+                Direction::LeftToRight => min_rect.right(),
+                Direction::RightToLeft => min_rect.left(),
+                Direction::TopDown => min_rect.bottom(),
+                Direction::BottomUp => min_rect.top(),
+            };
+            let num_items = self.placer.num_items() - first_item;
+            let size = ((end - start).abs() - spacing * num_items.saturating_sub(1) as f32)
+                .at_least(0.0)
+                .round();
+            let new_measured = (size, num_items);
+
+            if new_measured != measured {
+                self.ctx().data().insert_temp(id, new_measured);
+                self.ctx().request_repaint(); // layout with the new size
+            }
+        }
+    }
 }
 
 impl Ui {
@@ -73,6 +172,14 @@ impl Ui {
     /// [`SidePanel`], [`TopBottomPanel`], [`CentralPanel`], [`Window`] or [`Area`].
     pub fn new(ctx: Context, layer_id: LayerId, id: Id, max_rect: Rect, clip_rect: Rect) -> Self {
         let style = ctx.style();
+        let stack_index = ctx.frame_state().push_ui(UiInfo {
+            id,
+            parent: None,
+            layer_id,
+            layout: Layout::default(),
+            max_rect,
+            min_rect: Rect::NOTHING,
+        });
         Ui {
             id,
             next_auto_id_source: id.with("auto").value(),
@@ -81,6 +188,9 @@ impl Ui {
             placer: Placer::new(max_rect, Layout::default()),
             enabled: true,
             menu_state: None,
+            filling: None,
+            justified: None,
+            stack_index,
         }
     }
 
@@ -100,14 +210,35 @@ impl Ui {
         let next_auto_id_source = Id::new(self.next_auto_id_source).with("child").value();
         self.next_auto_id_source = self.next_auto_id_source.wrapping_add(1);
         let menu_state = self.get_menu_state();
+        let id = self.id.with(id_source);
+        if max_rect.is_negative() {
+            self.ctx().warn(
+                id,
+                format!(
+                    "Ui {} has a negative size: its parent is too small for it.",
+                    id.short_debug_format()
+                ),
+            );
+        }
+        let stack_index = self.ctx().frame_state().push_ui(UiInfo {
+            id,
+            parent: self.stack_index,
+            layer_id: self.layer_id(),
+            layout,
+            max_rect,
+            min_rect: Rect::NOTHING,
+        });
         Ui {
-            id: self.id.with(id_source),
+            id,
             next_auto_id_source,
             painter: self.painter.clone(),
             style: self.style.clone(),
             placer: Placer::new(max_rect, layout),
             enabled: self.enabled,
             menu_state,
+            filling: None,
+            justified: None,
+            stack_index,
         }
     }
 
@@ -895,6 +1026,41 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Allocate exactly `desired_size` and only call `add_contents` if that space is visible.
+    ///
+    /// Use this to skip building expensive widgets (image thumbnails, plots, …)
+    /// that are scrolled out of view, or are outside a window that is too small to show them.
+    /// The contents should fit within `desired_size`, since that is all the space we reserve,
+    /// whether or not the contents are shown.
+    ///
+    /// Returns `None` if the contents were culled.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for i in 0..1000 {
+    ///         ui.allocate_ui_if_visible(egui::vec2(200.0, 50.0), |ui| {
+    ///             ui.label(format!("Expensive thing #{}", i));
+    ///         });
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn allocate_ui_if_visible<R>(
+        &mut self,
+        desired_size: Vec2,
+        add_contents: impl FnOnce(&mut Self) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let (rect, response) = self.allocate_exact_size(desired_size, Sense::hover());
+        let inner = if self.is_rect_visible(rect) {
+            let mut child_ui = self.child_ui(rect, *self.layout());
+            Some(add_contents(&mut child_ui))
+        } else {
+            None
+        };
+        InnerResponse::new(inner, response)
+    }
+
     /// Convenience function to get a region to paint on.
     ///
     /// Note that egui uses screen coordinates for everything.
@@ -1033,7 +1199,8 @@ impl Ui {
     /// Add a [`Widget`] to this [`Ui`] with a given size.
     /// The widget will attempt to fit within the given size, but some widgets may overflow.
     ///
-    /// To fill all remaining area, use `ui.add_sized(ui.available_size(), widget);`
+    /// To fill all remaining area, use `ui.add_sized(ui.available_size(), widget);`,
+    /// or [`Self::add_filling`] if there are more widgets to come after it.
     ///
     /// See also [`Self::add`] and [`Self::put`].
     ///
@@ -1054,6 +1221,174 @@ impl Ui {
             .inner
     }
 
+    /// Add a [`Widget`] that fills the space left over by the other widgets in this [`Ui`],
+    /// including the ones added after it.
+    ///
+    /// For instance, in a horizontal layout this can be used for a [`TextEdit`]
+    /// that takes up all the width not needed by the label before it and the button after it.
+    /// In a [`Grid`] the widget fills its cell.
+    ///
+    /// The space needed by the widgets after it is measured each frame and used the next frame,
+    /// so if that changes, egui will request another frame to get the layout right.
+    /// Only use this once per [`Ui`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut query = String::new();
+    /// ui.horizontal(|ui| {
+    ///     ui.label("Search:");
+    ///     ui.add_filling(egui::TextEdit::singleline(&mut query));
+    ///     if ui.button("Go").clicked() { /* … */ }
+    /// });
+    /// # });
+    /// ```
+    pub fn add_filling(&mut self, widget: impl Widget) -> Response {
+        if self.placer.is_grid() {
+            return self.add_sized(self.available_size_before_wrap(), widget);
+        }
+
+        let (id, space_after, size) = self.filling_size();
+        let response = self.add_sized(size, widget);
+        self.filling = Some(Filling {
+            id,
+            space_after,
+            rect: response.rect,
+        });
+        response
+    }
+
+    /// Add empty space that takes up all the space not needed by the other widgets in this [`Ui`],
+    /// pushing the widgets after it to the far end of the layout.
+    ///
+    /// This works like [`Self::add_filling`], so only use it once per [`Ui`].
+    /// It does nothing in a [`Grid`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal(|ui| {
+    ///     if ui.button("Open").clicked() { /* … */ }
+    ///     if ui.button("Save").clicked() { /* … */ }
+    ///     ui.add_spacer();
+    ///     if ui.button("Settings").clicked() { /* … */ }
+    /// });
+    /// # });
+    /// ```
+    pub fn add_spacer(&mut self) -> Response {
+        if self.placer.is_grid() {
+            return self.allocate_response(Vec2::ZERO, Sense::hover());
+        }
+
+        let (id, space_after, size) = self.filling_size();
+        let size = if self.layout().main_dir().is_horizontal() {
+            vec2(size.x, 0.0)
+        } else {
+            vec2(0.0, size.y)
+        };
+        let response = self.allocate_response(size, Sense::hover());
+        self.filling = Some(Filling {
+            id,
+            space_after,
+            rect: response.rect,
+        });
+        response
+    }
+
+    /// Spread out the widgets added after this over all the space of this [`Ui`] along the main direction,
+    /// e.g. to spread out the buttons of a toolbar evenly.
+    ///
+    /// The widgets are measured each frame and spread out accordingly the next frame,
+    /// so if they change size, egui will request another frame to get the layout right.
+    /// The widgets are never closer than [`crate::style::Spacing::item_spacing`].
+    ///
+    /// Call this once per [`Ui`], before adding the widgets.
+    /// It does nothing in a [`Grid`] or in a wrapping layout.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal(|ui| {
+    ///     ui.justify_content(egui::JustifyContent::SpaceBetween);
+    ///     if ui.button("Back").clicked() { /* … */ }
+    ///     if ui.button("Home").clicked() { /* … */ }
+    ///     if ui.button("Forward").clicked() { /* … */ }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`Self::add_spacer`].
+    pub fn justify_content(&mut self, justify: JustifyContent) {
+        if self.placer.is_grid() || self.layout().main_wrap() {
+            return;
+        }
+
+        let id = self.auto_id_with("justify_content");
+        let measured: (f32, usize) = self.data().get_temp(id).unwrap_or_default();
+        let (size, num_items) = measured;
+
+        let horizontal = self.layout().main_dir().is_horizontal();
+        let available = self.available_size_before_wrap();
+        let (available, item_spacing) = if horizontal {
+            (available.x, self.spacing().item_spacing.x)
+        } else {
+            (available.y, self.spacing().item_spacing.y)
+        };
+        let free_space = (available - size).at_least(0.0);
+
+        let (lead, spacing) = match justify {
+            JustifyContent::SpaceBetween => {
+                let spacing = if num_items > 1 {
+                    free_space / (num_items - 1) as f32
+                } else {
+                    0.0
+                };
+                (0.0, spacing)
+            }
+            JustifyContent::SpaceAround => {
+                let spacing = if num_items > 0 {
+                    free_space / num_items as f32
+                } else {
+                    0.0
+                };
+                (0.5 * spacing, spacing)
+            }
+        };
+        let spacing = spacing.at_least(item_spacing);
+
+        if lead > 0.0 {
+            self.add_space(lead);
+        }
+        self.placer.set_extra_item_spacing(spacing - item_spacing);
+
+        let cursor = self.cursor();
+        let start = match self.layout().main_dir() {
+            Direction::LeftToRight => cursor.left(),
+            Direction::RightToLeft => cursor.right(),
+            Direction::TopDown => cursor.top(),
+            Direction::BottomUp => cursor.bottom(),
+        };
+
+        self.justified = Some(Justified {
+            id,
+            measured,
+            first_item: self.placer.num_items(),
+            start,
+            spacing,
+        });
+    }
+
+    /// The space available to [`Self::add_filling`], given the space needed
+    /// by the widgets after it last frame.
+    fn filling_size(&self) -> (Id, f32, Vec2) {
+        let mut size = self.available_size_before_wrap();
+        let id = self.auto_id_with("filling");
+        let space_after: f32 = self.data().get_temp(id).unwrap_or_default();
+        if self.layout().main_dir().is_horizontal() {
+            size.x = (size.x - space_after).at_least(0.0);
+        } else {
+            size.y = (size.y - space_after).at_least(0.0);
+        }
+        (id, space_after, size)
+    }
+
     /// Add a [`Widget`] to this [`Ui`] at a specific location (manual layout).
     ///
     /// See also [`Self::add`] and [`Self::add_sized`].
@@ -1729,6 +2064,27 @@ impl Ui {
         CollapsingHeader::new(heading).show(self, add_contents)
     }
 
+    /// A titled section, with a separator below the title and even spacing around it.
+    ///
+    /// Use this to give e.g. a settings panel a consistent look.
+    /// See [`Section`] for a section that can be collapsed.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut paused = false;
+    /// ui.section("Playback", |ui| {
+    ///     ui.checkbox(&mut paused, "Paused");
+    /// });
+    /// # });
+    /// ```
+    pub fn section<R>(
+        &mut self,
+        title: impl Into<WidgetText>,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        crate::containers::section::show_section(self, title.into(), add_contents)
+    }
+
     /// Create a child ui which is indented to the right.
     ///
     /// The `id_source` here be anything at all.
@@ -1754,13 +2110,16 @@ impl Ui {
         );
 
         let indent = self.spacing().indent;
+        let right_to_left = self.style().right_to_left;
         let mut child_rect = self.placer.available_rect_before_wrap();
-        child_rect.min.x += indent;
+        if right_to_left {
+            child_rect.max.x -= indent;
+        } else {
+            child_rect.min.x += indent;
+        }
 
-        let mut child_ui = Self {
-            id: self.id.with(id_source),
-            ..self.child_ui(child_rect, *self.layout())
-        };
+        let mut child_ui = self.child_ui(child_rect, *self.layout());
+        child_ui.id = self.id.with(id_source);
         let ret = add_contents(&mut child_ui);
 
         let end_with_horizontal_line = self.spacing().indent_ends_with_horizontal_line;
@@ -1769,18 +2128,26 @@ impl Ui {
             child_ui.add_space(4.0);
         }
 
-        // draw a faint line on the left to mark the indented section
+        // draw a faint line on the left (or right, for right-to-left) to mark the indented section
         let stroke = self.visuals().widgets.noninteractive.bg_stroke;
-        let left_top = child_rect.min - 0.5 * indent * Vec2::X;
-        let left_top = self.painter().round_pos_to_pixels(left_top);
-        let left_bottom = pos2(left_top.x, child_ui.min_rect().bottom() - 2.0);
-        let left_bottom = self.painter().round_pos_to_pixels(left_bottom);
-        self.painter.line_segment([left_top, left_bottom], stroke);
+        let line_top = if right_to_left {
+            child_rect.right_top() + 0.5 * indent * Vec2::X
+        } else {
+            child_rect.min - 0.5 * indent * Vec2::X
+        };
+        let line_top = self.painter().round_pos_to_pixels(line_top);
+        let line_bottom = pos2(line_top.x, child_ui.min_rect().bottom() - 2.0);
+        let line_bottom = self.painter().round_pos_to_pixels(line_bottom);
+        self.painter.line_segment([line_top, line_bottom], stroke);
         if end_with_horizontal_line {
             let fudge = 2.0; // looks nicer with button rounding in collapsing headers
-            let right_bottom = pos2(child_ui.min_rect().right() - fudge, left_bottom.y);
+            let end_x = if right_to_left {
+                child_ui.min_rect().left() + fudge
+            } else {
+                child_ui.min_rect().right() - fudge
+            };
             self.painter
-                .line_segment([left_bottom, right_bottom], stroke);
+                .line_segment([line_bottom, pos2(end_x, line_bottom.y)], stroke);
         }
 
         let response = self.allocate_rect(child_ui.min_rect(), Sense::hover());
@@ -2013,6 +2380,34 @@ impl Ui {
         self.placer.is_grid()
     }
 
+    /// Let the next cell in a [`Grid`] span several columns and/or rows.
+    ///
+    /// The cells covered by a cell spanning several rows are skipped in the rows below it.
+    /// Does nothing outside of a grid.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("form").num_columns(2).show(ui, |ui| {
+    ///     ui.grid_span(2, 1);
+    ///     ui.heading("Settings");
+    ///     ui.end_row();
+    ///
+    ///     ui.label("Name");
+    ///     ui.label("Bob");
+    ///     ui.end_row();
+    ///
+    ///     ui.grid_span(2, 1);
+    ///     ui.separator();
+    ///     ui.end_row();
+    /// });
+    /// # });
+    /// ```
+    pub fn grid_span(&mut self, columns: usize, rows: usize) {
+        if let Some(grid) = self.placer.grid_mut() {
+            grid.set_span(columns, rows);
+        }
+    }
+
     /// Move to the next row in a grid layout or wrapping layout.
     /// Otherwise does nothing.
     pub fn end_row(&mut self) {
@@ -2027,6 +2422,12 @@ impl Ui {
 
     /// Temporarily split split an Ui into several columns.
     ///
+    /// The available width is split into `num_columns` columns of equal width,
+    /// separated by [`crate::style::Spacing::item_spacing`]. If the contents of a column are wider
+    /// than that, all columns will be made wider next frame.
+    ///
+    /// With `num_columns == 0` the closure is called with an empty slice.
+    ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
     /// ui.columns(2, |columns| {
@@ -2049,10 +2450,15 @@ impl Ui {
         num_columns: usize,
         add_contents: Box<dyn FnOnce(&mut [Self]) -> R + 'c>,
     ) -> R {
+        if num_columns == 0 {
+            return add_contents(&mut []);
+        }
+
         // TODO(emilk): ensure there is space
         let spacing = self.spacing().item_spacing.x;
         let total_spacing = spacing * (num_columns as f32 - 1.0);
-        let column_width = (self.available_width() - total_spacing) / (num_columns as f32);
+        let column_width =
+            ((self.available_width() - total_spacing) / (num_columns as f32)).at_least(0.0);
         let top_left = self.cursor().min;
 
         let mut columns: Vec<Self> = (0..num_columns)
@@ -0,0 +1,371 @@
+//! Run egui without a backend and interact with it from tests.
+
+use crate::{
+    AccessNode, ColorImage, Context, Event, FullOutput, Id, Key, Modifiers, PointerButton, Pos2,
+    RawInput, Rect, Vec2,
+};
+
+/// How to find a widget in a [`Harness`]: by its label (e.g. the text of a button) or by its [`Id`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum WidgetQuery {
+    Label(String),
+    Id(Id),
+}
+
+impl From<&str> for WidgetQuery {
+    fn from(label: &str) -> Self {
+        Self::Label(label.to_owned())
+    }
+}
+
+impl From<String> for WidgetQuery {
+    fn from(label: String) -> Self {
+        Self::Label(label)
+    }
+}
+
+impl From<Id> for WidgetQuery {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+/// The ui code run by a [`Harness`] each frame.
+type AppFn<'a, State> = Box<dyn FnMut(&Context, &mut State) + 'a>;
+
+/// Runs egui frames without a backend, so that tests can click, drag and type
+/// and then check the state of the app.
+///
+/// Widgets are found in the [`crate::AccessibilityTree`] of the last frame,
+/// which has all widgets that call [`crate::Response::widget_info`] (all the built-in widgets do).
+///
+/// Each frame has a fixed time step, so the results are the same every time.
+///
+/// ```
+/// use egui::util::Harness;
+///
+/// let mut harness = Harness::new_state(
+///     |ctx, checked: &mut bool| {
+///         egui::CentralPanel::default().show(ctx, |ui| {
+///             ui.checkbox(checked, "Dark mode");
+///         });
+///     },
+///     false,
+/// );
+///
+/// harness.click("Dark mode");
+/// assert!(*harness.state());
+/// assert_eq!(harness.get("Dark mode").unwrap().info.selected, Some(true));
+/// ```
+///
+/// The last frame can be painted on the CPU with [`Self::render`],
+/// and compared against a saved image with `Harness::snapshot` (with the `snapshot` feature).
+pub struct Harness<'a, State = ()> {
+    ctx: Context,
+    app: AppFn<'a, State>,
+    state: State,
+
+    /// Input for the next frame.
+    input: RawInput,
+    output: FullOutput,
+    frame_nr: usize,
+    pointer_pos: Pos2,
+
+    /// Keeps the textures up to date, for [`Self::render`].
+    rasterizer: epaint::Rasterizer,
+}
+
+impl<'a> Harness<'a> {
+    /// Create a harness for the given ui code, and run the first frame.
+    pub fn new(mut app: impl FnMut(&Context) + 'a) -> Self {
+        Self::new_state(move |ctx, _: &mut ()| app(ctx), ())
+    }
+}
+
+impl<'a, State> Harness<'a, State> {
+    /// The time between two frames.
+    pub const DT: f32 = 1.0 / 60.0;
+
+    /// Create a harness for the given ui code and app state, and run the first frame.
+    pub fn new_state(app: impl FnMut(&Context, &mut State) + 'a, state: State) -> Self {
+        let ctx = Context::default();
+        ctx.memory().options.accessibility_tree = true;
+
+        let mut harness = Self {
+            ctx,
+            app: Box::new(app),
+            state,
+            input: RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, crate::vec2(800.0, 600.0))),
+                ..Default::default()
+            },
+            output: Default::default(),
+            frame_nr: 0,
+            pointer_pos: Pos2::ZERO,
+            rasterizer: Default::default(),
+        };
+        harness.run();
+        harness
+    }
+
+    /// Change the size of the screen, starting with the next frame. Default: 800x600 points.
+    pub fn set_screen_size(&mut self, size: Vec2) {
+        self.input.screen_rect = Some(Rect::from_min_size(Pos2::ZERO, size));
+    }
+
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    pub fn state_mut(&mut self) -> &mut State {
+        &mut self.state
+    }
+
+    /// The output of the last frame.
+    pub fn output(&self) -> &FullOutput {
+        &self.output
+    }
+
+    /// All widgets shown in the last frame.
+    pub fn widgets(&self) -> &[AccessNode] {
+        match &self.output.platform_output.accessibility_tree {
+            Some(tree) => &tree.nodes,
+            None => &[],
+        }
+    }
+
+    /// Find a widget that was shown in the last frame.
+    pub fn get(&self, query: impl Into<WidgetQuery>) -> Option<&AccessNode> {
+        let query = query.into();
+        self.widgets().iter().find(|widget| match &query {
+            WidgetQuery::Label(label) => widget.info.label.as_deref() == Some(label.as_str()),
+            WidgetQuery::Id(id) => widget.id == *id,
+        })
+    }
+
+    fn rect_of(&self, query: impl Into<WidgetQuery>) -> Rect {
+        let query = query.into();
+        match self.get(query.clone()) {
+            Some(widget) => widget.rect,
+            None => panic!("No widget matching {:?} was shown in the last frame", query),
+        }
+    }
+
+    /// Run one frame, with any events added since the last frame.
+    pub fn run(&mut self) {
+        let mut input = self.input.take();
+        input.time = Some(self.frame_nr as f64 * Self::DT as f64);
+        input.predicted_dt = Self::DT;
+        self.frame_nr += 1;
+
+        for &id in &self.output.textures_delta.free {
+            self.rasterizer.free_texture(id);
+        }
+
+        self.output = self.ctx.run(input, |ctx| (self.app)(ctx, &mut self.state));
+
+        for (id, image_delta) in &self.output.textures_delta.set {
+            self.rasterizer.set_texture(*id, image_delta);
+        }
+    }
+
+    /// Paint the last frame on the CPU, with [`epaint::Rasterizer`].
+    ///
+    /// The background is filled with [`crate::Visuals::window_fill`].
+    pub fn render(&self) -> ColorImage {
+        let pixels_per_point = self.ctx.pixels_per_point();
+        let size = self.ctx.input().screen_rect.size() * pixels_per_point;
+        let size = [size.x.round() as usize, size.y.round() as usize];
+        let clipped_primitives = self.ctx.tessellate(self.output.shapes.clone());
+        let clear_color = self.ctx.style().visuals.window_fill();
+        self.rasterizer
+            .paint(size, pixels_per_point, clear_color, &clipped_primitives)
+    }
+
+    /// Render the last frame and compare it with the image saved as `tests/snapshots/{name}.png`,
+    /// to catch visual regressions.
+    ///
+    /// Run the tests with the environment variable `UPDATE_SNAPSHOTS=1` to save the new images.
+    ///
+    /// Panics if the images differ. See [`Self::try_snapshot`] for the details.
+    #[cfg(feature = "snapshot")]
+    pub fn snapshot(&self, name: &str) {
+        if let Err(err) = self.try_snapshot(name, &Default::default()) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Render the last frame and compare it with the image saved as `{name}.png`
+    /// in [`super::snapshot::SnapshotOptions::output_dir`].
+    ///
+    /// See [`super::snapshot::compare_snapshot`].
+    #[cfg(feature = "snapshot")]
+    pub fn try_snapshot(
+        &self,
+        name: &str,
+        options: &super::snapshot::SnapshotOptions,
+    ) -> Result<(), super::snapshot::SnapshotError> {
+        super::snapshot::compare_snapshot(name, &self.render(), options)
+    }
+
+    /// Run frames until egui no longer asks for a repaint (e.g. when animations are done),
+    /// but at most `max_frames`.
+    pub fn run_until_idle(&mut self, max_frames: usize) {
+        for _ in 0..max_frames {
+            if !self.output.repaint_after.is_zero() {
+                break;
+            }
+            self.run();
+        }
+    }
+
+    /// Add an event to the next frame. Call [`Self::run`] to process it.
+    pub fn push_event(&mut self, event: Event) {
+        if let Event::PointerMoved(pos) = event {
+            self.pointer_pos = pos;
+        }
+        self.input.events.push(event);
+    }
+
+    /// Move the mouse over the widget, and run a frame.
+    pub fn hover(&mut self, query: impl Into<WidgetQuery>) {
+        let pos = self.rect_of(query).center();
+        self.push_event(Event::PointerMoved(pos));
+        self.run();
+    }
+
+    /// Click the center of the widget with the primary mouse button, and run the frames needed for it.
+    pub fn click(&mut self, query: impl Into<WidgetQuery>) {
+        self.hover(query);
+        self.push_pointer_button(true);
+        self.run();
+        self.push_pointer_button(false);
+        self.run();
+    }
+
+    /// Drag the widget by `delta` with the primary mouse button, over a few frames.
+    pub fn drag(&mut self, query: impl Into<WidgetQuery>, delta: Vec2) {
+        self.hover(query);
+        let start = self.pointer_pos;
+        self.push_pointer_button(true);
+        self.run();
+
+        let steps = 5;
+        for i in 1..=steps {
+            let pos = start + delta * (i as f32 / steps as f32);
+            self.push_event(Event::PointerMoved(pos));
+            self.run();
+        }
+
+        self.push_pointer_button(false);
+        self.run();
+    }
+
+    /// Click the widget to focus it, then type the given text.
+    pub fn type_text(&mut self, query: impl Into<WidgetQuery>, text: &str) {
+        self.click(query);
+        self.push_event(Event::Text(text.to_owned()));
+        self.run();
+    }
+
+    /// Press and release a key, and run the frames needed for it.
+    pub fn press_key(&mut self, key: Key, modifiers: Modifiers) {
+        for pressed in [true, false] {
+            self.push_event(Event::Key {
+                key,
+                pressed,
+                modifiers,
+            });
+            self.run();
+        }
+    }
+
+    fn push_pointer_button(&mut self, pressed: bool) {
+        self.push_event(Event::PointerButton {
+            pos: self.pointer_pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Modifiers::NONE,
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_harness_button_and_text_edit() {
+    #[derive(Default)]
+    struct State {
+        clicks: usize,
+        text: String,
+        value: f32,
+    }
+
+    let mut harness = Harness::new_state(
+        |ctx, state: &mut State| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                if ui.button("Click me").clicked() {
+                    state.clicks += 1;
+                }
+                ui.add(crate::TextEdit::singleline(&mut state.text).id(Id::new("name")));
+                ui.add(crate::DragValue::new(&mut state.value).speed(1.0));
+            });
+        },
+        State::default(),
+    );
+
+    harness.click("Click me");
+    harness.click("Click me");
+    assert_eq!(harness.state().clicks, 2);
+
+    harness.type_text(Id::new("name"), "egui");
+    assert_eq!(harness.state().text, "egui");
+
+    let drag_value = harness
+        .widgets()
+        .iter()
+        .find(|widget| widget.info.typ == crate::WidgetType::DragValue)
+        .unwrap()
+        .id;
+    harness.drag(drag_value, crate::vec2(10.0, 0.0));
+    assert!(harness.state().value > 0.0);
+}
+
+#[test]
+fn test_accessibility_label_is_per_frame() {
+    let mut harness = Harness::new_state(
+        |ctx, use_label: &mut bool| {
+            crate::CentralPanel::default().show(ctx, |ui| {
+                let response = ui.button("🗙");
+                if *use_label {
+                    response.accessibility_label("Close");
+                }
+            });
+        },
+        true,
+    );
+
+    harness.click("Close");
+    let clicked_label = harness
+        .output()
+        .platform_output
+        .events
+        .iter()
+        .find_map(|event| {
+            if let crate::output::OutputEvent::Clicked(info) = event {
+                info.label.clone()
+            } else {
+                None
+            }
+        });
+    assert_eq!(clicked_label.as_deref(), Some("Close"));
+
+    *harness.state_mut() = false;
+    harness.run();
+    assert!(harness.get("Close").is_none());
+    assert!(harness.get("🗙").is_some());
+}
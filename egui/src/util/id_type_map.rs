@@ -445,6 +445,25 @@ impl IdTypeMap {
         self.0.remove(&hash);
     }
 
+    /// Stop persisting the value of this type and id.
+    ///
+    /// The value is kept until egui shuts down, but is not saved with the `persistence` feature.
+    /// Call this after each time the value is inserted with [`Self::insert_persisted`].
+    pub fn make_temp<T: 'static>(&mut self, id: Id) {
+        let hash = hash(TypeId::of::<T>(), id);
+        match self.0.get_mut(&hash) {
+            #[cfg(feature = "persistence")]
+            Some(Element::Value { serialize_fn, .. }) => {
+                *serialize_fn = None;
+            }
+            Some(Element::Serialized { .. }) => {
+                // Loaded from a previous session, but never used in this one.
+                self.0.remove(&hash);
+            }
+            _ => {}
+        }
+    }
+
     /// Note all state of the given type.
     pub fn remove_by_type<T: 'static>(&mut self) {
         let key = TypeId::of::<T>();
@@ -718,3 +737,23 @@ fn test_mix_serialize() {
     );
     assert_eq!(map.get_temp::<Serializable>(id), Some(Serializable(555)));
 }
+
+#[cfg(feature = "persistence")]
+#[test]
+fn test_make_temp() {
+    let a = Id::new("a");
+    let b = Id::new("b");
+
+    let mut map: IdTypeMap = Default::default();
+    map.insert_persisted(a, 13.37);
+    map.insert_persisted(b, 42.0);
+    map.make_temp::<f64>(b);
+
+    // Still readable this session:
+    assert_eq!(map.get_persisted::<f64>(b), Some(42.0));
+
+    let serialized = ron::to_string(&map).unwrap();
+    let mut map: IdTypeMap = ron::from_str(&serialized).unwrap();
+    assert_eq!(map.get_persisted::<f64>(a), Some(13.37));
+    assert_eq!(map.get_persisted::<f64>(b), None);
+}
@@ -0,0 +1,139 @@
+//! Record the input of an egui app, and replay it later, e.g. to reproduce a bug or in a test.
+
+use crate::{Context, FullOutput, RawInput};
+
+/// The [`RawInput`] of consecutive frames.
+///
+/// Record the input you give to [`Context::run`] each frame,
+/// and then [`Self::replay`] it into a new [`Context`] to get the same interaction again.
+///
+/// With the `serde` feature you can save the recording to a file,
+/// e.g. to attach it to a bug report or use it in a regression test.
+///
+/// ```
+/// # let run_ui = |ctx: &egui::Context| {};
+/// let ctx = egui::Context::default();
+/// let mut recording = egui::util::InputRecording::default();
+///
+/// // Each frame:
+/// let raw_input = egui::RawInput::default(); // Gathered by your integration.
+/// recording.record(&raw_input);
+/// let full_output = ctx.run(raw_input, run_ui);
+///
+/// // Later:
+/// let outputs = recording.replay(&egui::Context::default(), 1.0 / 60.0, run_ui);
+/// assert_eq!(outputs.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct InputRecording {
+    /// The input of each frame, oldest first.
+    pub frames: Vec<RawInput>,
+}
+
+impl InputRecording {
+    /// Record the input of the next frame.
+    ///
+    /// Call this with the same input you give to [`Context::run`] (or [`Context::begin_frame`]).
+    pub fn record(&mut self, raw_input: &RawInput) {
+        self.frames.push(raw_input.clone());
+    }
+
+    /// Were no frames recorded?
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Number of recorded frames.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// The input of the given frame, with a fixed time step of `dt` seconds.
+    ///
+    /// The recorded `time` and `predicted_dt` are replaced, so that
+    /// animations, double-clicks etc. behave the same way every time the recording is replayed.
+    pub fn frame_input(&self, frame_nr: usize, dt: f32) -> RawInput {
+        let mut raw_input = self.frames[frame_nr].clone();
+        raw_input.time = Some(frame_nr as f64 * dt as f64);
+        raw_input.predicted_dt = dt;
+        raw_input
+    }
+
+    /// Run all recorded frames through the given context, with a fixed time step of `dt` seconds.
+    ///
+    /// For the result to be reproducible, use a new [`Context`] (or one with the same
+    /// [`crate::Memory`] as when the recording started).
+    ///
+    /// Returns the output of each frame.
+    pub fn replay(
+        &self,
+        ctx: &Context,
+        dt: f32,
+        mut run_ui: impl FnMut(&Context),
+    ) -> Vec<FullOutput> {
+        (0..self.frames.len())
+            .map(|frame_nr| ctx.run(self.frame_input(frame_nr, dt), &mut run_ui))
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[test]
+fn test_replay_is_deterministic() {
+    use crate::{pos2, Event, Id, Modifiers, PointerButton, Window};
+
+    let mut recording = InputRecording::default();
+    let mut record_event = |event: Option<Event>| {
+        recording.record(&RawInput {
+            events: event.into_iter().collect(),
+            ..Default::default()
+        });
+    };
+
+    // Drag the window by its title bar:
+    record_event(None);
+    record_event(Some(Event::PointerMoved(pos2(120.0, 110.0))));
+    record_event(Some(Event::PointerButton {
+        pos: pos2(120.0, 110.0),
+        button: PointerButton::Primary,
+        pressed: true,
+        modifiers: Modifiers::NONE,
+    }));
+    for i in 1..=10 {
+        let pos = pos2(120.0 + 5.0 * i as f32, 110.0 + 3.0 * i as f32);
+        record_event(Some(Event::PointerMoved(pos)));
+    }
+    record_event(Some(Event::PointerButton {
+        pos: pos2(170.0, 140.0),
+        button: PointerButton::Primary,
+        pressed: false,
+        modifiers: Modifiers::NONE,
+    }));
+    record_event(None);
+
+    let replay = || {
+        let ctx = Context::default();
+        let outputs = recording.replay(&ctx, 1.0 / 60.0, |ctx| {
+            Window::new("Window")
+                .default_pos(pos2(100.0, 100.0))
+                .show(ctx, |ui| {
+                    ui.label("Drag me");
+                });
+        });
+        let num_shapes: Vec<usize> = outputs.iter().map(|output| output.shapes.len()).collect();
+        let window_pos = ctx
+            .memory()
+            .areas
+            .get(Id::new("Window"))
+            .map(|state| state.pos);
+        (num_shapes, window_pos)
+    };
+
+    let (num_shapes, window_pos) = replay();
+    assert_eq!(num_shapes.len(), recording.len());
+    // The window was dragged as far as the pointer moved:
+    assert_eq!(window_pos, Some(pos2(150.0, 130.0)));
+    assert_eq!(replay(), (num_shapes, window_pos));
+}
@@ -2,11 +2,17 @@
 
 pub mod cache;
 pub(crate) mod fixed_cache;
+pub mod harness;
 mod history;
 pub mod id_type_map;
+pub mod input_recording;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
 pub mod undoer;
 
+pub use harness::Harness;
 pub use history::History;
 pub use id_type_map::IdTypeMap;
+pub use input_recording::InputRecording;
 
 pub use epaint::util::{hash, hash_with};
@@ -0,0 +1,212 @@
+//! Compare the paint output in tests against saved images, to catch visual regressions.
+//!
+//! See [`crate::util::Harness::snapshot`].
+
+use std::path::{Path, PathBuf};
+
+use crate::{Color32, ColorImage};
+
+/// How [`crate::util::Harness::try_snapshot`] compares a new image with the saved one.
+#[derive(Clone, Debug)]
+pub struct SnapshotOptions {
+    /// A pixel has changed if any of its channels differ by more than this.
+    ///
+    /// Default: 2.
+    pub threshold: u8,
+
+    /// The snapshot fails if more than this many pixels have changed.
+    ///
+    /// Default: 0.
+    pub max_changed_pixels: usize,
+
+    /// The directory the snapshots are saved in.
+    ///
+    /// Default: `tests/snapshots` in the directory of the crate being tested.
+    pub output_dir: PathBuf,
+}
+
+impl Default for SnapshotOptions {
+    fn default() -> Self {
+        let crate_dir =
+            std::env::var_os("CARGO_MANIFEST_DIR").map_or_else(PathBuf::new, PathBuf::from);
+        Self {
+            threshold: 2,
+            max_changed_pixels: 0,
+            output_dir: crate_dir.join("tests").join("snapshots"),
+        }
+    }
+}
+
+/// Why a snapshot failed.
+#[derive(Debug)]
+pub enum SnapshotError {
+    /// There is no saved image yet.
+    Missing { path: PathBuf },
+
+    /// The new image has a different size than the saved one.
+    SizeChanged {
+        path: PathBuf,
+        saved: [usize; 2],
+        new: [usize; 2],
+    },
+
+    /// Too many pixels have changed.
+    Changed {
+        path: PathBuf,
+        num_changed_pixels: usize,
+    },
+
+    /// Reading or writing an image failed.
+    Io { path: PathBuf, error: String },
+}
+
+impl std::fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Missing { path } => write!(
+                f,
+                "Missing snapshot {:?}. Run with UPDATE_SNAPSHOTS=1 to save it.",
+                path
+            ),
+            Self::SizeChanged { path, saved, new } => write!(
+                f,
+                "Snapshot {:?} has size {:?}, but the new image has size {:?}. See the .new.png next to it. Run with UPDATE_SNAPSHOTS=1 to accept the change.",
+                path, saved, new
+            ),
+            Self::Changed {
+                path,
+                num_changed_pixels,
+            } => write!(
+                f,
+                "{} pixels differ from snapshot {:?}. See the .new.png and .diff.png next to it. Run with UPDATE_SNAPSHOTS=1 to accept the change.",
+                num_changed_pixels, path
+            ),
+            Self::Io { path, error } => write!(f, "Failed to access {:?}: {}", path, error),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {}
+
+/// Compare `image` with the one saved as `{name}.png` in [`SnapshotOptions::output_dir`].
+///
+/// If the environment variable `UPDATE_SNAPSHOTS` is set, the image is saved instead.
+///
+/// When the images differ, `{name}.new.png` and `{name}.diff.png` are saved next to it,
+/// with the changed pixels in red in the latter.
+pub fn compare_snapshot(
+    name: &str,
+    image: &ColorImage,
+    options: &SnapshotOptions,
+) -> Result<(), SnapshotError> {
+    let path = options.output_dir.join(format!("{}.png", name));
+    let new_path = options.output_dir.join(format!("{}.new.png", name));
+    let diff_path = options.output_dir.join(format!("{}.diff.png", name));
+
+    // Clean up after an earlier failure:
+    std::fs::remove_file(&new_path).ok();
+    std::fs::remove_file(&diff_path).ok();
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        return save_png(&path, image);
+    }
+
+    let saved = match load_png(&path) {
+        Ok(saved) => saved,
+        Err(SnapshotError::Missing { path }) => {
+            save_png(&new_path, image)?;
+            return Err(SnapshotError::Missing { path });
+        }
+        Err(err) => return Err(err),
+    };
+
+    if saved.size != image.size {
+        save_png(&new_path, image)?;
+        return Err(SnapshotError::SizeChanged {
+            path,
+            saved: saved.size,
+            new: image.size,
+        });
+    }
+
+    let mut diff = ColorImage::new(image.size, Color32::TRANSPARENT);
+    let mut num_changed_pixels = 0;
+    for ((saved, new), diff) in saved.pixels.iter().zip(&image.pixels).zip(&mut diff.pixels) {
+        if (0..4).any(|i| saved[i].abs_diff(new[i]) > options.threshold) {
+            num_changed_pixels += 1;
+            *diff = Color32::RED;
+        }
+    }
+
+    if num_changed_pixels > options.max_changed_pixels {
+        save_png(&new_path, image)?;
+        save_png(&diff_path, &diff)?;
+        Err(SnapshotError::Changed {
+            path,
+            num_changed_pixels,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn save_png(path: &Path, image: &ColorImage) -> Result<(), SnapshotError> {
+    let io_error = |error: &dyn std::fmt::Display| SnapshotError::Io {
+        path: path.to_owned(),
+        error: error.to_string(),
+    };
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|err| io_error(&err))?;
+    }
+    let file = std::fs::File::create(path).map_err(|err| io_error(&err))?;
+
+    let mut encoder = png::Encoder::new(
+        std::io::BufWriter::new(file),
+        image.width() as u32,
+        image.height() as u32,
+    );
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let rgba: Vec<u8> = image
+        .pixels
+        .iter()
+        .flat_map(|color| color.to_srgba_unmultiplied())
+        .collect();
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&rgba))
+        .map_err(|err| io_error(&err))
+}
+
+fn load_png(path: &Path) -> Result<ColorImage, SnapshotError> {
+    let io_error = |error: &dyn std::fmt::Display| SnapshotError::Io {
+        path: path.to_owned(),
+        error: error.to_string(),
+    };
+
+    let file = match std::fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Err(SnapshotError::Missing {
+                path: path.to_owned(),
+            });
+        }
+        Err(err) => return Err(io_error(&err)),
+    };
+
+    let mut reader = png::Decoder::new(std::io::BufReader::new(file))
+        .read_info()
+        .map_err(|err| io_error(&err))?;
+    let mut rgba = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut rgba).map_err(|err| io_error(&err))?;
+    if info.color_type != png::ColorType::Rgba || info.bit_depth != png::BitDepth::Eight {
+        return Err(io_error(&"expected an 8-bit RGBA image"));
+    }
+
+    let size = [info.width as usize, info.height as usize];
+    Ok(ColorImage::from_rgba_unmultiplied(
+        size,
+        &rgba[..info.buffer_size()],
+    ))
+}
@@ -0,0 +1,119 @@
+//! Viewports let one [`crate::Context`] drive several native windows.
+//!
+//! See [`crate::Context::show_viewport`].
+
+use crate::{Id, Pos2, Vec2};
+
+/// Identifies a viewport, i.e. one native window driven by a [`crate::Context`].
+///
+/// The main window is [`ViewportId::ROOT`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ViewportId(pub Id);
+
+impl ViewportId {
+    /// The viewport of the main window, which is the one the backend runs [`crate::Context::run`] for.
+    pub const ROOT: Self = Self(Id::null());
+
+    /// Generate a [`ViewportId`] by hashing some source (e.g. a string or integer).
+    pub fn from_hash_of(source: impl std::hash::Hash) -> Self {
+        Self(Id::new(source))
+    }
+}
+
+impl Default for ViewportId {
+    fn default() -> Self {
+        Self::ROOT
+    }
+}
+
+/// How the window of a viewport should look. Used with [`crate::Context::show_viewport`].
+///
+/// Sizes and positions are in points, like everything else in egui.
+///
+/// ```
+/// let builder = egui::ViewportBuilder::new("Tools").with_inner_size(egui::vec2(300.0, 200.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ViewportBuilder {
+    /// The title of the window.
+    pub title: String,
+
+    /// The size of the inside of the window when it is first opened.
+    pub inner_size: Option<Vec2>,
+
+    /// Where on the screen to put the top left corner of the window when it is first opened.
+    pub position: Option<Pos2>,
+
+    /// Can the user resize the window?
+    ///
+    /// Default: `true`.
+    pub resizable: bool,
+}
+
+impl Default for ViewportBuilder {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            inner_size: None,
+            position: None,
+            resizable: true,
+        }
+    }
+}
+
+impl ViewportBuilder {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// The size of the inside of the window when it is first opened.
+    pub fn with_inner_size(mut self, inner_size: Vec2) -> Self {
+        self.inner_size = Some(inner_size);
+        self
+    }
+
+    /// Where on the screen to put the top left corner of the window when it is first opened.
+    pub fn with_position(mut self, position: Pos2) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Can the user resize the window? Default: `true`.
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+/// What [`crate::Context::show_viewport`] returns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewportResponse<R> {
+    /// What the contents returned,
+    /// or `None` if they were not shown (e.g. because the viewport is embedded in a collapsed window).
+    pub inner: Option<R>,
+
+    /// The user asked to close the viewport, e.g. with the close button of its window.
+    ///
+    /// Stop calling [`crate::Context::show_viewport`] to close it.
+    pub close_requested: bool,
+}
+
+// ----------------------------------------------------------------------------
+
+/// The part of the [`crate::Context`] that belongs to one viewport.
+///
+/// The state of the current viewport lives in the [`crate::Context`] itself,
+/// and is swapped with one of these when running the contents of another viewport.
+#[derive(Default)]
+pub(crate) struct ViewportState {
+    pub input: crate::InputState,
+    pub frame_state: crate::frame_state::FrameState,
+    pub graphics: crate::layers::GraphicLayers,
+    pub output: crate::PlatformOutput,
+    pub memory: crate::memory::ViewportMemory,
+}
@@ -21,10 +21,12 @@ use crate::*;
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Button {
     text: WidgetText,
+    shortcut_text: WidgetText,
     wrap: Option<bool>,
     /// None means default for interact
     fill: Option<Color32>,
     stroke: Option<Stroke>,
+    text_color: Option<Color32>,
     sense: Sense,
     small: bool,
     frame: Option<bool>,
@@ -36,9 +38,11 @@ impl Button {
     pub fn new(text: impl Into<WidgetText>) -> Self {
         Self {
             text: text.into(),
+            shortcut_text: Default::default(),
             wrap: None,
             fill: None,
             stroke: None,
+            text_color: None,
             sense: Sense::click(),
             small: false,
             frame: None,
@@ -56,8 +60,10 @@ impl Button {
     ) -> Self {
         Self {
             text: text.into(),
+            shortcut_text: Default::default(),
             fill: None,
             stroke: None,
+            text_color: None,
             sense: Sense::click(),
             small: false,
             frame: None,
@@ -96,6 +102,19 @@ impl Button {
         self
     }
 
+    /// Override the text color, unless the text has a color of its own (see [`RichText::color`]).
+    /// Note that this will override any on-hover effects.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.add(egui::Button::new("Delete").fill(egui::Color32::DARK_RED).text_color(egui::Color32::WHITE));
+    /// # });
+    /// ```
+    pub fn text_color(mut self, text_color: impl Into<Color32>) -> Self {
+        self.text_color = Some(text_color.into());
+        self
+    }
+
     /// Make this a small button, suitable for embedding into text.
     pub fn small(mut self) -> Self {
         self.text = self.text.text_style(TextStyle::Body);
@@ -116,6 +135,14 @@ impl Button {
         self
     }
 
+    /// Show some text on the right side of the button, in weak color.
+    ///
+    /// Designed for menu buttons, for setting a keyboard shortcut text (e.g. `Ctrl+S`).
+    pub fn shortcut_text(mut self, shortcut_text: impl Into<WidgetText>) -> Self {
+        self.shortcut_text = shortcut_text.into();
+        self
+    }
+
     pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
@@ -126,9 +153,11 @@ impl Widget for Button {
     fn ui(self, ui: &mut Ui) -> Response {
         let Button {
             text,
+            shortcut_text,
             wrap,
             fill,
             stroke,
+            text_color,
             sense,
             small,
             frame,
@@ -144,10 +173,22 @@ impl Widget for Button {
         }
         let total_extra = button_padding + button_padding;
 
-        let wrap_width = ui.available_width() - total_extra.x;
+        let shortcut_text = (!shortcut_text.is_empty())
+            .then(|| shortcut_text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button));
+        let shortcut_width = shortcut_text.as_ref().map_or(0.0, |shortcut_text| {
+            ui.spacing().item_spacing.x + shortcut_text.size().x
+        });
+
+        let wrap_width = ui.available_width() - total_extra.x - shortcut_width;
         let text = text.into_galley(ui, wrap, wrap_width, TextStyle::Button);
 
         let mut desired_size = text.size() + 2.0 * button_padding;
+        desired_size.x += shortcut_width;
+        if let Some(shortcut_text) = &shortcut_text {
+            desired_size.y = desired_size
+                .y
+                .max(shortcut_text.size().y + 2.0 * button_padding.y);
+        }
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
@@ -186,7 +227,20 @@ impl Widget for Button {
                 );
             }
 
-            text.paint_with_visuals(ui.painter(), text_pos, visuals);
+            let text_color = text_color.unwrap_or_else(|| visuals.text_color());
+            text.paint_with_fallback_color(ui.painter(), text_pos, text_color);
+
+            if let Some(shortcut_text) = shortcut_text {
+                let shortcut_text_pos = pos2(
+                    rect.max.x - button_padding.x - shortcut_text.size().x,
+                    rect.center().y - 0.5 * shortcut_text.size().y,
+                );
+                shortcut_text.paint_with_fallback_color(
+                    ui.painter(),
+                    shortcut_text_pos,
+                    ui.visuals().weak_text_color(),
+                );
+            }
         }
 
         if let Some(image) = image {
@@ -422,6 +476,7 @@ pub struct ImageButton {
     sense: Sense,
     frame: bool,
     selected: bool,
+    hover_tint: Option<Color32>,
 }
 
 impl ImageButton {
@@ -431,6 +486,7 @@ impl ImageButton {
             sense: Sense::click(),
             frame: true,
             selected: false,
+            hover_tint: None,
         }
     }
 
@@ -446,6 +502,15 @@ impl ImageButton {
         self
     }
 
+    /// Multiply image color with this when the button is hovered,
+    /// e.g. to highlight buttons without a frame in a toolbar.
+    ///
+    /// Default: same as [`Self::tint`].
+    pub fn hover_tint(mut self, hover_tint: impl Into<Color32>) -> Self {
+        self.hover_tint = Some(hover_tint.into());
+        self
+    }
+
     /// If `true`, mark this button as "selected".
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -473,6 +538,7 @@ impl Widget for ImageButton {
             sense,
             frame,
             selected,
+            hover_tint,
         } = self;
 
         let padding = if frame {
@@ -485,6 +551,11 @@ impl Widget for ImageButton {
         let (rect, response) = ui.allocate_exact_size(padded_size, sense);
         response.widget_info(|| WidgetInfo::new(WidgetType::ImageButton));
 
+        let image = match hover_tint {
+            Some(hover_tint) if response.hovered() => image.tint(hover_tint),
+            _ => image,
+        };
+
         if ui.is_rect_visible(rect) {
             let (expansion, rounding, fill, stroke) = if selected {
                 let selection = ui.visuals().selection;
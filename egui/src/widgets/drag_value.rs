@@ -189,6 +189,10 @@ impl<'a> Widget for DragValue<'a> {
         let is_slow_speed = shift && ui.memory().is_being_dragged(ui.next_auto_id());
 
         let old_value = get(&mut get_set_value);
+        if old_value.is_nan() {
+            ui.ctx()
+                .warn(ui.next_auto_id(), "A DragValue was given a NaN value.");
+        }
         let value = clamp_to_range(old_value, clamp_range.clone());
         if old_value != value {
             set(&mut get_set_value, value);
@@ -264,6 +268,7 @@ impl<'a> Widget for DragValue<'a> {
                 ui.memory().drag_value.edit_string = None; // Filled in next frame
             } else if response.dragged() {
                 ui.output().cursor_icon = CursorIcon::ResizeHorizontal;
+                response.request_pointer_capture();
 
                 let mdelta = response.drag_delta();
                 let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
@@ -42,6 +42,8 @@ pub struct Image {
     tint: Color32,
     sense: Sense,
     rotation: Option<(Rot2, Vec2)>,
+    uv_rotation: Rot2,
+    flip: [bool; 2],
 }
 
 impl Image {
@@ -54,6 +56,8 @@ impl Image {
             tint: Color32::WHITE,
             sense: Sense::hover(),
             rotation: None,
+            uv_rotation: Rot2::IDENTITY,
+            flip: [false, false],
         }
     }
 
@@ -93,6 +97,23 @@ impl Image {
         self.rotation = Some((Rot2::from_angle(angle), origin));
         self
     }
+
+    /// Rotate the texture by some angle inside of the (unrotated) image rectangle.
+    ///
+    /// Positive angle is clockwise. The rotation is around the center of the [`Self::uv`] range.
+    ///
+    /// This is useful for showing e.g. camera frames with a different orientation
+    /// without rotating the pixels on the CPU.
+    pub fn rotate_uv(mut self, angle: f32) -> Self {
+        self.uv_rotation = Rot2::from_angle(angle);
+        self
+    }
+
+    /// Mirror the texture horizontally and/or vertically inside of the image rectangle.
+    pub fn flip(mut self, horizontal: bool, vertical: bool) -> Self {
+        self.flip = [horizontal, vertical];
+        self
+    }
 }
 
 impl Image {
@@ -100,17 +121,41 @@ impl Image {
         self.size
     }
 
+    /// The texture coordinates of the left-top, right-top, left-bottom and right-bottom corners,
+    /// after applying [`Self::flip`] and [`Self::rotate_uv`].
+    fn corner_uvs(&self) -> [Pos2; 4] {
+        let mut uv = self.uv;
+        if self.flip[0] {
+            std::mem::swap(&mut uv.min.x, &mut uv.max.x);
+        }
+        if self.flip[1] {
+            std::mem::swap(&mut uv.min.y, &mut uv.max.y);
+        }
+
+        let center = uv.center();
+        let rot = self.uv_rotation.inverse();
+        [
+            uv.left_top(),
+            uv.right_top(),
+            uv.left_bottom(),
+            uv.right_bottom(),
+        ]
+        .map(|corner| center + rot * (corner - center))
+    }
+
     pub fn paint_at(&self, ui: &mut Ui, rect: Rect) {
         if ui.is_rect_visible(rect) {
             use epaint::*;
             let Self {
                 texture_id,
-                uv,
+                uv: _,
                 size,
                 bg_fill,
                 tint,
                 sense: _,
                 rotation,
+                uv_rotation: _,
+                flip: _,
             } = self;
 
             if *bg_fill != Default::default() {
@@ -122,7 +167,7 @@ impl Image {
             {
                 // TODO(emilk): builder pattern for Mesh
                 let mut mesh = Mesh::with_texture(*texture_id);
-                mesh.add_rect_with_uv(rect, *uv, *tint);
+                mesh.add_rect_with_uvs(rect, self.corner_uvs(), *tint);
                 if let Some((rot, origin)) = rotation {
                     mesh.rotate(*rot, rect.min + *origin * *size);
                 }
@@ -264,6 +264,27 @@ impl PlotPoints {
         ys.iter().enumerate().map(|(i, &y)| [i as f64, y]).collect()
     }
 
+    /// From separate series of x- and y-values, e.g. timestamps and measurements.
+    ///
+    /// Both series should have the same length. If not, the extra values are ignored.
+    ///
+    /// ```
+    /// # use egui::plot::PlotPoints;
+    /// let times = [0.0, 0.5, 1.0];
+    /// let temperatures = [20.0, 20.5, 21.5];
+    /// let points = PlotPoints::from_xs_ys(&times, &temperatures);
+    /// assert_eq!(points.points().len(), 3);
+    /// ```
+    pub fn from_xs_ys(xs: &[f64], ys: &[f64]) -> Self {
+        crate::egui_assert!(
+            xs.len() == ys.len(),
+            "Got {} x-values but {} y-values",
+            xs.len(),
+            ys.len()
+        );
+        xs.iter().zip(ys).map(|(&x, &y)| [x, y]).collect()
+    }
+
     /// Returns true if there are no data points available and there is no function to generate any.
     pub(crate) fn is_empty(&self) -> bool {
         match self {
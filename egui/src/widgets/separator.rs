@@ -14,6 +14,7 @@ use crate::*;
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Separator {
     spacing: f32,
+    grow: f32,
     is_horizontal_line: Option<bool>,
 }
 
@@ -21,6 +22,7 @@ impl Default for Separator {
     fn default() -> Self {
         Self {
             spacing: 6.0,
+            grow: 0.0,
             is_horizontal_line: None,
         }
     }
@@ -33,6 +35,28 @@ impl Separator {
         self
     }
 
+    /// Extend each end of the separator line by this much.
+    ///
+    /// The default is to take up the available width (for a horizontal line)
+    /// or height (for a vertical line).
+    ///
+    /// See also [`Self::shrink`].
+    pub fn grow(mut self, extra: f32) -> Self {
+        self.grow += extra;
+        self
+    }
+
+    /// Contract each end of the separator line by this much.
+    ///
+    /// This is useful for e.g. a vertical separator in a toolbar
+    /// that shouldn't touch the top and bottom of it.
+    ///
+    /// See also [`Self::grow`].
+    pub fn shrink(mut self, shrink: f32) -> Self {
+        self.grow -= shrink;
+        self
+    }
+
     /// Explicitly ask for a horizontal line.
     /// By default you will get a horizontal line in vertical layouts,
     /// and a vertical line in horizontal layouts.
@@ -54,6 +78,7 @@ impl Widget for Separator {
     fn ui(self, ui: &mut Ui) -> Response {
         let Separator {
             spacing,
+            grow,
             is_horizontal_line,
         } = self;
 
@@ -73,9 +98,17 @@ impl Widget for Separator {
         if ui.is_rect_visible(response.rect) {
             let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
             if is_horizontal_line {
-                ui.painter().hline(rect.x_range(), rect.center().y, stroke);
+                ui.painter().hline(
+                    (rect.left() - grow)..=(rect.right() + grow),
+                    rect.center().y,
+                    stroke,
+                );
             } else {
-                ui.painter().vline(rect.center().x, rect.y_range(), stroke);
+                ui.painter().vline(
+                    rect.center().x,
+                    (rect.top() - grow)..=(rect.bottom() + grow),
+                    stroke,
+                );
             }
         }
 
@@ -82,6 +82,8 @@ pub struct Slider<'a> {
     min_decimals: usize,
     max_decimals: Option<usize>,
     custom_formatter: Option<NumFormatter<'a>>,
+    /// Set from [`crate::Style::right_to_left`] of the [`Ui`] we are added to: a horizontal slider fills from the right.
+    right_to_left: bool,
 }
 
 impl<'a> Slider<'a> {
@@ -126,6 +128,7 @@ impl<'a> Slider<'a> {
             min_decimals: 0,
             max_decimals: None,
             custom_formatter: None,
+            right_to_left: false,
         }
     }
 
@@ -440,6 +443,9 @@ impl<'a> Slider<'a> {
     fn position_range(&self, rect: &Rect) -> RangeInclusive<f32> {
         let handle_radius = self.handle_radius(rect);
         match self.orientation {
+            SliderOrientation::Horizontal if self.right_to_left => {
+                (rect.right() - handle_radius)..=(rect.left() + handle_radius)
+            }
             SliderOrientation::Horizontal => {
                 (rect.left() + handle_radius)..=(rect.right() - handle_radius)
             }
@@ -524,6 +530,7 @@ impl<'a> Slider<'a> {
     }
 
     fn add_contents(&mut self, ui: &mut Ui) -> Response {
+        self.right_to_left = ui.style().right_to_left;
         let thickness = ui
             .text_style_height(&TextStyle::Body)
             .at_least(ui.spacing().interact_size.y);
@@ -559,6 +566,12 @@ impl<'a> Slider<'a> {
 impl<'a> Widget for Slider<'a> {
     fn ui(mut self, ui: &mut Ui) -> Response {
         let old_value = self.get_value();
+        if old_value.is_nan() {
+            ui.ctx().warn(
+                ui.next_auto_id(),
+                format!("The Slider {:?} was given a NaN value.", self.text),
+            );
+        }
 
         let inner_response = match self.orientation {
             SliderOrientation::Horizontal => ui.horizontal(|ui| self.add_contents(ui)),
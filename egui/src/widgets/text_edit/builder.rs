@@ -470,6 +470,14 @@ impl<'t> TextEdit<'t> {
                     );
                 }
 
+                let is_touch_down_on_text =
+                    ui.input().any_touches() && response.is_pointer_button_down_on();
+                let is_touch_long_press =
+                    is_touch_down_on_text && ui.input().pointer.is_long_press();
+                if is_touch_down_on_text && ui.input().pointer.could_any_button_be_click() {
+                    ui.ctx().request_repaint(); // so we notice when it becomes a long-press
+                }
+
                 if response.double_clicked() {
                     // Select word:
                     let center = cursor_at_pointer;
@@ -486,6 +494,14 @@ impl<'t> TextEdit<'t> {
                         primary: galley.from_ccursor(ccursor_range.primary),
                         secondary: galley.from_ccursor(ccursor_range.secondary),
                     }));
+                } else if is_touch_long_press {
+                    // Select word, like on double-click:
+                    ui.memory().request_focus(id);
+                    let ccursor_range = select_word_at(text.as_str(), cursor_at_pointer.ccursor);
+                    state.set_cursor_range(Some(CursorRange {
+                        primary: galley.from_ccursor(ccursor_range.primary),
+                        secondary: galley.from_ccursor(ccursor_range.secondary),
+                    }));
                 } else if allow_drag_to_select {
                     if response.hovered() && ui.input().pointer.any_pressed() {
                         ui.memory().request_focus(id);
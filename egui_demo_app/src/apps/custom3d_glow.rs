@@ -11,13 +11,12 @@ pub struct Custom3d {
 }
 
 impl Custom3d {
-    pub fn new<'a>(cc: &'a eframe::CreationContext<'a>) -> Self {
-        Self {
-            rotating_triangle: Arc::new(Mutex::new(RotatingTriangle::new(
-                cc.gl.as_ref().expect("GL Enabled"),
-            ))),
+    /// Returns `None` if we are not painting with `glow`.
+    pub fn new<'a>(cc: &'a eframe::CreationContext<'a>) -> Option<Self> {
+        Some(Self {
+            rotating_triangle: Arc::new(Mutex::new(RotatingTriangle::new(cc.gl.as_ref()?))),
             angle: 0.0,
-        }
+        })
     }
 }
 
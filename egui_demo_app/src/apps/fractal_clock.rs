@@ -58,28 +58,32 @@ impl FractalClock {
     }
 
     fn options_ui(&mut self, ui: &mut Ui, seconds_since_midnight: Option<f64>) {
-        if seconds_since_midnight.is_some() {
-            ui.label(format!(
-                "Local time: {:02}:{:02}:{:02}.{:03}",
-                (self.time % (24.0 * 60.0 * 60.0) / 3600.0).floor(),
-                (self.time % (60.0 * 60.0) / 60.0).floor(),
-                (self.time % 60.0).floor(),
-                (self.time % 1.0 * 100.0).floor()
-            ));
-        } else {
-            ui.label("The fractal_clock clock is not showing the correct time");
-        };
-        ui.label(format!("Painted line count: {}", self.line_count));
-
-        ui.checkbox(&mut self.paused, "Paused");
-        ui.add(Slider::new(&mut self.zoom, 0.0..=1.0).text("zoom"));
-        ui.add(Slider::new(&mut self.start_line_width, 0.0..=5.0).text("Start line width"));
-        ui.add(Slider::new(&mut self.depth, 0..=14).text("depth"));
-        ui.add(Slider::new(&mut self.length_factor, 0.0..=1.0).text("length factor"));
-        ui.add(Slider::new(&mut self.luminance_factor, 0.0..=1.0).text("luminance factor"));
-        ui.add(Slider::new(&mut self.width_factor, 0.0..=1.0).text("width factor"));
-
-        egui::reset_button(ui, self);
+        ui.section("Status", |ui| {
+            if seconds_since_midnight.is_some() {
+                ui.label(format!(
+                    "Local time: {:02}:{:02}:{:02}.{:03}",
+                    (self.time % (24.0 * 60.0 * 60.0) / 3600.0).floor(),
+                    (self.time % (60.0 * 60.0) / 60.0).floor(),
+                    (self.time % 60.0).floor(),
+                    (self.time % 1.0 * 100.0).floor()
+                ));
+            } else {
+                ui.label("The fractal_clock clock is not showing the correct time");
+            };
+            ui.label(format!("Painted line count: {}", self.line_count));
+        });
+
+        ui.section("Settings", |ui| {
+            ui.checkbox(&mut self.paused, "Paused");
+            ui.add(Slider::new(&mut self.zoom, 0.0..=1.0).text("zoom"));
+            ui.add(Slider::new(&mut self.start_line_width, 0.0..=5.0).text("Start line width"));
+            ui.add(Slider::new(&mut self.depth, 0..=14).text("depth"));
+            ui.add(Slider::new(&mut self.length_factor, 0.0..=1.0).text("length factor"));
+            ui.add(Slider::new(&mut self.luminance_factor, 0.0..=1.0).text("luminance factor"));
+            ui.add(Slider::new(&mut self.width_factor, 0.0..=1.0).text("width factor"));
+
+            egui::reset_button(ui, self);
+        });
 
         ui.hyperlink_to(
             "Inspired by a screensaver by Rob Mayoff",
@@ -148,8 +148,11 @@ impl BackendPanel {
     fn integration_ui(&mut self, ui: &mut egui::Ui, frame: &mut eframe::Frame) {
         ui.horizontal(|ui| {
             ui.spacing_mut().item_spacing.x = 0.0;
-            ui.label("egui running inside ");
-            ui.hyperlink_to("eframe", "https://github.com/emilk/egui/tree/master/eframe");
+            ui.label(format!("egui {} running inside ", egui::VERSION));
+            ui.hyperlink_to(
+                format!("eframe {}", eframe::VERSION),
+                "https://github.com/emilk/egui/tree/master/eframe",
+            );
             ui.label(".");
         });
 
@@ -71,11 +71,21 @@ pub fn start_separate(canvas_id: &str) -> Result<WebHandle, wasm_bindgen::JsValu
 
 /// This is the entry-point for all the web-assembly.
 /// This is called once from the HTML.
-/// It loads the app, installs some callbacks, then returns.
+/// It loads the app, installs some callbacks, then returns a promise of the [`WebHandle`].
 /// You can add more callbacks like this if you want to call in to your code.
+///
+/// Paints with WebGPU if the `wgpu` feature is enabled and the browser supports it.
 #[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
-pub fn start(canvas_id: &str) -> Result<WebHandle, wasm_bindgen::JsValue> {
+pub async fn start(canvas_id: String) -> Result<WebHandle, wasm_bindgen::JsValue> {
     init_wasm_hooks();
-    start_separate(canvas_id)
+
+    let web_options = eframe::WebOptions::default();
+    eframe::start_web_async(
+        &canvas_id,
+        web_options,
+        Box::new(|cc| Box::new(WrapApp::new(cc))),
+    )
+    .await
+    .map(|handle| WebHandle { handle })
 }
@@ -93,7 +93,8 @@ pub struct State {
 /// Wraps many demo/test apps into one.
 pub struct WrapApp {
     state: State,
-    custom3d: crate::apps::Custom3d,
+    /// `None` if the backend we paint with can't run it.
+    custom3d: Option<crate::apps::Custom3d>,
     dropped_files: Vec<egui::DroppedFile>,
 }
 
@@ -141,11 +142,13 @@ impl WrapApp {
             ),
         ];
 
-        vec.push((
-            "🔺 3D painting",
-            "custom3d",
-            &mut self.custom3d as &mut dyn eframe::App,
-        ));
+        if let Some(custom3d) = &mut self.custom3d {
+            vec.push((
+                "🔺 3D painting",
+                "custom3d",
+                custom3d as &mut dyn eframe::App,
+            ));
+        }
 
         vec.push((
             "🎨 Color test",
@@ -212,7 +215,9 @@ impl eframe::App for WrapApp {
 
     #[cfg(feature = "glow")]
     fn on_exit(&mut self, gl: Option<&glow::Context>) {
-        self.custom3d.on_exit(gl);
+        if let Some(custom3d) = &mut self.custom3d {
+            custom3d.on_exit(gl);
+        }
     }
 }
 
@@ -277,9 +282,8 @@ impl WrapApp {
                 .clicked()
             {
                 selected_anchor = anchor.to_owned();
-                if frame.is_web() {
-                    ui.output().open_url(format!("#{}", anchor));
-                }
+                #[cfg(target_arch = "wasm32")]
+                frame.set_location_hash(anchor);
             }
         }
         self.state.selected_anchor = selected_anchor;
@@ -289,9 +293,8 @@ impl WrapApp {
                 // TODO(emilk): fix the overlap on small screens
                 if clock_button(ui, crate::seconds_since_midnight()).clicked() {
                     self.state.selected_anchor = "clock".to_owned();
-                    if frame.is_web() {
-                        ui.output().open_url("#clock");
-                    }
+                    #[cfg(target_arch = "wasm32")]
+                    frame.set_location_hash("clock");
                 }
             }
 
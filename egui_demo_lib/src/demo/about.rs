@@ -41,6 +41,157 @@ impl super::View for About {
         ui.add_space(12.0); // ui.separator();
         ui.heading("Links");
         links(ui);
+
+        ui.add_space(12.0); // ui.separator();
+        ui.collapsing("Build info", |ui| {
+            about_panel(
+                ui,
+                &AppInfo::new("egui_demo_lib", env!("CARGO_PKG_VERSION"))
+                    .with_homepage("https://github.com/emilk/egui")
+                    .with_library("egui", egui::VERSION, "https://github.com/emilk/egui")
+                    .with_library(
+                        "epaint",
+                        egui::epaint::VERSION,
+                        "https://github.com/emilk/egui/tree/master/epaint",
+                    )
+                    .with_library(
+                        "emath",
+                        egui::emath::VERSION,
+                        "https://github.com/emilk/egui/tree/master/emath",
+                    ),
+            );
+        });
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Information about an app, shown by [`about_panel`].
+///
+/// ```
+/// let info = egui_demo_lib::AppInfo::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+///     .with_git_hash(option_env!("GIT_HASH"))
+///     .with_build_date(option_env!("BUILD_DATE"))
+///     .with_library("egui", egui::VERSION, "https://github.com/emilk/egui");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AppInfo {
+    pub name: String,
+    pub version: String,
+
+    /// E.g. set from an environment variable by your build script.
+    pub git_hash: Option<String>,
+
+    /// E.g. set from an environment variable by your build script.
+    pub build_date: Option<String>,
+
+    pub homepage: Option<String>,
+
+    /// Libraries to give credit to, as `(name, version, url)`.
+    pub libraries: Vec<(String, String, String)>,
+}
+
+impl AppInfo {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Typically called with `option_env!("GIT_HASH")` or similar.
+    pub fn with_git_hash(mut self, git_hash: Option<&str>) -> Self {
+        self.git_hash = git_hash.map(ToOwned::to_owned);
+        self
+    }
+
+    /// Typically called with `option_env!("BUILD_DATE")` or similar.
+    pub fn with_build_date(mut self, build_date: Option<&str>) -> Self {
+        self.build_date = build_date.map(ToOwned::to_owned);
+        self
+    }
+
+    pub fn with_homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Typically called with the `VERSION` constant of the library, e.g. [`egui::VERSION`].
+    pub fn with_library(
+        mut self,
+        name: impl Into<String>,
+        version: impl Into<String>,
+        url: impl Into<String>,
+    ) -> Self {
+        self.libraries
+            .push((name.into(), version.into(), url.into()));
+        self
+    }
+}
+
+/// Show the name, version, build info and credits of an app.
+pub fn about_panel(ui: &mut egui::Ui, info: &AppInfo) {
+    let AppInfo {
+        name,
+        version,
+        git_hash,
+        build_date,
+        homepage,
+        libraries,
+    } = info;
+
+    ui.horizontal(|ui| {
+        ui.strong(name);
+        ui.label(version);
+    });
+
+    egui::Grid::new("about_panel_build_info")
+        .num_columns(2)
+        .show(ui, |ui| {
+            if let Some(git_hash) = git_hash {
+                ui.label("Git hash:");
+                ui.monospace(git_hash);
+                ui.end_row();
+            }
+            if let Some(build_date) = build_date {
+                ui.label("Build date:");
+                ui.label(build_date);
+                ui.end_row();
+            }
+            ui.label("Target:");
+            ui.label(target_description());
+            ui.end_row();
+            if let Some(homepage) = homepage {
+                ui.label("Homepage:");
+                ui.hyperlink(homepage);
+                ui.end_row();
+            }
+        });
+
+    if !libraries.is_empty() {
+        ui.add_space(4.0);
+        ui.label("Built with:");
+        ui.indent("about_panel_libraries", |ui| {
+            for (name, version, url) in libraries {
+                ui.horizontal(|ui| {
+                    ui.hyperlink_to(name, url);
+                    ui.label(version);
+                });
+            }
+        });
+    }
+}
+
+fn target_description() -> String {
+    if cfg!(target_arch = "wasm32") {
+        "web (wasm32)".to_owned()
+    } else {
+        format!(
+            "native ({} {})",
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        )
     }
 }
 
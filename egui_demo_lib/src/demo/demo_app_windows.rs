@@ -25,10 +25,13 @@ impl Default for Demos {
             Box::new(super::code_example::CodeExample::default()),
             Box::new(super::context_menu::ContextMenus::default()),
             Box::new(super::dancing_strings::DancingStrings::default()),
+            Box::new(super::docking::Docking::default()),
             Box::new(super::drag_and_drop::DragAndDropDemo::default()),
             Box::new(super::font_book::FontBook::default()),
+            Box::new(super::form_demo::FormDemo::default()),
             Box::new(super::MiscDemoWindow::default()),
             Box::new(super::multi_touch::MultiTouch::default()),
+            Box::new(super::node_graph::NodeGraph::default()),
             Box::new(super::painting::Painting::default()),
             Box::new(super::plot_demo::PlotDemo::default()),
             Box::new(super::scrolling::Scrolling::default()),
@@ -301,11 +304,35 @@ impl DemoWindows {
 // ----------------------------------------------------------------------------
 
 fn file_menu_button(ui: &mut Ui) {
+    let organize_shortcut = egui::KeyboardShortcut::new(
+        egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+        egui::Key::O,
+    );
+    ui.ctx()
+        .register_shortcut("File", organize_shortcut, "Organize windows");
+
+    // NOTE: we check the keyboard shortcut outside of the menu, so that it works even when the menu is closed:
+    if ui.input_mut().consume_shortcut(&organize_shortcut) {
+        ui.ctx().memory().reset_areas();
+    }
+
     ui.menu_button("File", |ui| {
-        if ui.button("Organize windows").clicked() {
+        let shortcut_text = ui.ctx().format_shortcut(&organize_shortcut);
+        if ui
+            .add(egui::Button::new("Organize windows").shortcut_text(shortcut_text))
+            .clicked()
+        {
             ui.ctx().memory().reset_areas();
             ui.close_menu();
         }
+        if ui
+            .button("Reset layout")
+            .on_hover_text("Forget window positions, sizes, scroll etc, but keep other state")
+            .clicked()
+        {
+            ui.ctx().memory().reset_layout();
+            ui.close_menu();
+        }
         if ui
             .button("Reset egui memory")
             .on_hover_text("Forget scroll, positions, sizes etc")
@@ -0,0 +1,59 @@
+use egui::*;
+
+#[derive(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct Docking {
+    notes: String,
+    brightness: f32,
+}
+
+impl super::Demo for Docking {
+    fn name(&self) -> &'static str {
+        "🗖 Docking"
+    }
+
+    fn show(&mut self, ctx: &Context, open: &mut bool) {
+        use super::View as _;
+        Window::new(self.name())
+            .open(open)
+            .default_size(vec2(480.0, 320.0))
+            .vscroll(false)
+            .show(ctx, |ui| self.ui(ui));
+    }
+}
+
+impl super::View for Docking {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.vertical_centered(|ui| {
+            ui.add(crate::egui_github_link_file!());
+        });
+        ui.label(
+            "Drag the tabs around to split and group them, or out of the window to undock them.",
+        );
+
+        let tree = DockNode::horizontal(
+            DockNode::tabs(["Settings"]),
+            DockNode::vertical(
+                DockNode::tabs(["Preview", "Notes"]),
+                DockNode::tabs(["Log"]),
+            ),
+        );
+        DockArea::new("docking_demo", tree).show(ui, |ui, tab| match tab {
+            "Settings" => {
+                ui.add(Slider::new(&mut self.brightness, 0.0..=1.0).text("Brightness"));
+            }
+            "Preview" => {
+                let (rect, _) = ui.allocate_exact_size(ui.available_size(), Sense::hover());
+                ui.painter()
+                    .rect_filled(rect, 0.0, Rgba::from_gray(self.brightness));
+            }
+            "Notes" => {
+                ui.add_sized(ui.available_size(), TextEdit::multiline(&mut self.notes));
+            }
+            _ => {
+                ui.label(format!("Nothing to see in {} yet.", tab));
+            }
+        });
+    }
+}
@@ -0,0 +1,82 @@
+use egui::Color32;
+use egui_extras::{Field, FieldValue, Form};
+
+/// Shows off a settings form built with [`egui_extras::Form`].
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FormDemo {
+    user_name: String,
+    email: String,
+    volume: f32,
+    max_connections: u32,
+    autosave: bool,
+    theme: usize,
+    accent: Color32,
+}
+
+impl Default for FormDemo {
+    fn default() -> Self {
+        Self {
+            user_name: "Ferris".to_owned(),
+            email: "ferris@example.com".to_owned(),
+            volume: 0.8,
+            max_connections: 8,
+            autosave: true,
+            theme: 0,
+            accent: Color32::from_rgb(0, 155, 255),
+        }
+    }
+}
+
+impl super::Demo for FormDemo {
+    fn name(&self) -> &'static str {
+        "📋 Form"
+    }
+
+    fn show(&mut self, ctx: &egui::Context, open: &mut bool) {
+        egui::Window::new(self.name())
+            .open(open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                use super::View as _;
+                self.ui(ui);
+            });
+    }
+}
+
+impl super::View for FormDemo {
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let response = Form::new("settings_form")
+            .field(Field::text("User name", &mut self.user_name).required())
+            .field(
+                Field::text("Email", &mut self.email).validate(|value| match value {
+                    FieldValue::Text(email) if !email.contains('@') => {
+                        Err("Not an email address".to_owned())
+                    }
+                    _ => Ok(()),
+                }),
+            )
+            .field(Field::number("Volume", &mut self.volume).range(0.0..=1.0))
+            .field(Field::number("Max connections", &mut self.max_connections).range(1.0..=64.0))
+            .field(Field::bool("Autosave", &mut self.autosave))
+            .field(Field::choice(
+                "Theme",
+                &mut self.theme,
+                ["System", "Dark", "Light"],
+            ))
+            .field(Field::color("Accent color", &mut self.accent))
+            .show(ui);
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            let can_save = response.is_valid() && response.is_dirty();
+            if ui
+                .add_enabled(can_save, egui::Button::new("Save"))
+                .clicked()
+            {
+                response.mark_clean();
+            }
+            ui.add(crate::egui_github_link_file!());
+        });
+    }
+}
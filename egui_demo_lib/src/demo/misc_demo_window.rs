@@ -16,6 +16,7 @@ pub struct MiscDemoWindow {
     colors: ColorWidgets,
     custom_collapsing_header: CustomCollapsingHeader,
     tree: Tree,
+    tabs: TabsDemo,
     box_painting: BoxPainting,
 
     dummy_bool: bool,
@@ -35,6 +36,7 @@ impl Default for MiscDemoWindow {
             colors: Default::default(),
             custom_collapsing_header: Default::default(),
             tree: Tree::demo(),
+            tabs: Default::default(),
             box_painting: Default::default(),
 
             dummy_bool: false,
@@ -92,6 +94,10 @@ impl View for MiscDemoWindow {
             .default_open(false)
             .show(ui, |ui| self.tree.ui(ui));
 
+        CollapsingHeader::new("Tabs")
+            .default_open(false)
+            .show(ui, |ui| self.tabs.ui(ui));
+
         CollapsingHeader::new("Checkboxes")
             .default_open(false)
             .show(ui, |ui| {
@@ -409,6 +415,47 @@ impl CustomCollapsingHeader {
 
 // ----------------------------------------------------------------------------
 
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct TabsDemo {
+    tabs: Vec<String>,
+    selected: usize,
+    num_opened: usize,
+}
+
+impl Default for TabsDemo {
+    fn default() -> Self {
+        Self {
+            tabs: (1..=3).map(|i| format!("Tab {}", i)).collect(),
+            selected: 0,
+            num_opened: 3,
+        }
+    }
+}
+
+impl TabsDemo {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Drag a tab to move it, or click its × to close it.");
+        if ui.button("Open tab").clicked() {
+            self.num_opened += 1;
+            self.tabs.push(format!("Tab {}", self.num_opened));
+            self.selected = self.tabs.len() - 1;
+        }
+
+        egui::Tabs::new("demo_tabs").closable(true).show(
+            ui,
+            &mut self.tabs,
+            &mut self.selected,
+            |tab| tab.as_str().into(),
+            |ui, tab| {
+                ui.label(format!("The contents of {}.", tab));
+                ui.label(LOREM_IPSUM);
+            },
+        );
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Clone, Copy, PartialEq)]
 enum Action {
     Keep,
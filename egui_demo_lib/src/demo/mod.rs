@@ -10,11 +10,14 @@ pub mod code_example;
 pub mod context_menu;
 pub mod dancing_strings;
 pub mod demo_app_windows;
+pub mod docking;
 pub mod drag_and_drop;
 pub mod font_book;
+pub mod form_demo;
 pub mod layout_test;
 pub mod misc_demo_window;
 pub mod multi_touch;
+pub mod node_graph;
 pub mod paint_bezier;
 pub mod painting;
 pub mod password;
@@ -0,0 +1,117 @@
+use egui::epaint::CubicBezierShape;
+use egui::*;
+
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+struct Node {
+    title: String,
+
+    /// Top left corner, in canvas coordinates.
+    pos: Pos2,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct NodeGraph {
+    nodes: Vec<Node>,
+
+    /// Connections from the output of one node to the input of another.
+    edges: Vec<(usize, usize)>,
+}
+
+impl Default for NodeGraph {
+    fn default() -> Self {
+        let node = |title: &str, x, y| Node {
+            title: title.to_owned(),
+            pos: pos2(x, y),
+        };
+        Self {
+            nodes: vec![
+                node("Camera", 20.0, 40.0),
+                node("Blur", 220.0, 20.0),
+                node("Edges", 220.0, 140.0),
+                node("Screen", 420.0, 80.0),
+            ],
+            edges: vec![(0, 1), (0, 2), (1, 3), (2, 3)],
+        }
+    }
+}
+
+const NODE_SIZE: Vec2 = Vec2::new(120.0, 50.0);
+
+impl super::Demo for NodeGraph {
+    fn name(&self) -> &'static str {
+        "🔀 Node Graph"
+    }
+
+    fn show(&mut self, ctx: &Context, open: &mut bool) {
+        use super::View as _;
+        Window::new(self.name())
+            .open(open)
+            .default_size(vec2(560.0, 320.0))
+            .vscroll(false)
+            .show(ctx, |ui| self.ui(ui));
+    }
+}
+
+impl super::View for NodeGraph {
+    fn ui(&mut self, ui: &mut Ui) {
+        ui.vertical_centered(|ui| {
+            ui.add(crate::egui_github_link_file!());
+        });
+        ui.label("Drag the nodes to move them. Drag the background to pan, and ctrl-scroll or pinch to zoom.");
+
+        Frame::canvas(ui.style()).show(ui, |ui| {
+            Canvas::new("node_graph").show(ui, |ui, _transform| {
+                let painter = ui.painter();
+                let visuals = ui.visuals().clone();
+
+                let output_pos =
+                    |node: &Node| pos2(node.pos.x + NODE_SIZE.x, node.pos.y + 0.5 * NODE_SIZE.y);
+                let input_pos = |node: &Node| pos2(node.pos.x, node.pos.y + 0.5 * NODE_SIZE.y);
+
+                // Move the nodes before painting anything, so the edges follow without delay:
+                let responses: Vec<Response> = self
+                    .nodes
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(i, node)| {
+                        let rect = Rect::from_min_size(node.pos, NODE_SIZE);
+                        let response = ui.interact(rect, ui.id().with(i), Sense::drag());
+                        node.pos += response.drag_delta();
+                        response
+                    })
+                    .collect();
+
+                for &(from, to) in &self.edges {
+                    let start = output_pos(&self.nodes[from]);
+                    let end = input_pos(&self.nodes[to]);
+                    let bend = vec2(0.5 * (end.x - start.x).abs().max(50.0), 0.0);
+                    painter.add(CubicBezierShape::from_points_stroke(
+                        [start, start + bend, end - bend, end],
+                        false,
+                        Color32::TRANSPARENT,
+                        Stroke::new(2.0, visuals.widgets.inactive.fg_stroke.color),
+                    ));
+                }
+
+                for (node, response) in self.nodes.iter().zip(&responses) {
+                    let rect = Rect::from_min_size(node.pos, NODE_SIZE);
+                    let widget_visuals = visuals.widgets.style(response);
+                    painter.rect(
+                        rect,
+                        4.0,
+                        widget_visuals.bg_fill,
+                        Stroke::new(1.0, widget_visuals.bg_stroke.color),
+                    );
+                    painter.text(
+                        rect.center(),
+                        Align2::CENTER_CENTER,
+                        &node.title,
+                        FontId::proportional(14.0),
+                        widget_visuals.text_color(),
+                    );
+                }
+            });
+        });
+    }
+}
@@ -82,7 +82,7 @@ fn huge_content_lines(ui: &mut egui::Ui) {
 
     let text_style = TextStyle::Body;
     let row_height = ui.text_style_height(&text_style);
-    let num_rows = 10_000;
+    let num_rows = 1_000_000;
     ScrollArea::vertical().auto_shrink([false; 2]).show_rows(
         ui,
         row_height,
@@ -48,30 +48,33 @@ impl super::View for StripDemo {
                     ui.label("width: 100%\nheight: 50px");
                 });
                 strip.strip(|builder| {
-                    builder.sizes(Size::remainder(), 2).horizontal(|mut strip| {
-                        strip.cell(|ui| {
-                            ui.painter().rect_filled(
-                                ui.available_rect_before_wrap(),
-                                0.0,
-                                faded_color(Color32::RED),
-                            );
-                            ui.label("width: 50%\nheight: remaining");
-                        });
-                        strip.strip(|builder| {
-                            builder.sizes(Size::remainder(), 3).vertical(|mut strip| {
-                                strip.empty();
-                                strip.cell(|ui| {
-                                    ui.painter().rect_filled(
-                                        ui.available_rect_before_wrap(),
-                                        0.0,
-                                        faded_color(Color32::YELLOW),
-                                    );
-                                    ui.label("width: 50%\nheight: 1/3 of the red region");
+                    builder
+                        .size(Size::weighted(2.0))
+                        .size(Size::remainder())
+                        .horizontal(|mut strip| {
+                            strip.cell(|ui| {
+                                ui.painter().rect_filled(
+                                    ui.available_rect_before_wrap(),
+                                    0.0,
+                                    faded_color(Color32::RED),
+                                );
+                                ui.label("width: 2/3\nheight: remaining");
+                            });
+                            strip.strip(|builder| {
+                                builder.sizes(Size::remainder(), 3).vertical(|mut strip| {
+                                    strip.empty();
+                                    strip.cell(|ui| {
+                                        ui.painter().rect_filled(
+                                            ui.available_rect_before_wrap(),
+                                            0.0,
+                                            faded_color(Color32::YELLOW),
+                                        );
+                                        ui.label("width: 1/3\nheight: 1/3 of the red region");
+                                    });
+                                    strip.empty();
                                 });
-                                strip.empty();
                             });
                         });
-                    });
                 });
                 strip.strip(|builder| {
                     builder
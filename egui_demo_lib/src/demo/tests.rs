@@ -64,6 +64,23 @@ impl super::View for IdTest {
             ui.label("Contents of second foldable ui");
         });
 
+        ui.label(
+            "\
+            If you don't want to come up with unique names, use `id_from_caller` \
+            to also derive the Id:s from where in the code the widgets are created:",
+        );
+
+        egui::CollapsingHeader::new("Collapsing header")
+            .id_from_caller()
+            .show(ui, |ui| {
+                ui.label("Contents of third foldable ui");
+            });
+        egui::CollapsingHeader::new("Collapsing header")
+            .id_from_caller()
+            .show(ui, |ui| {
+                ui.label("Contents of fourth foldable ui");
+            });
+
         ui.label("\
             Any widget that can be interacted with also need a unique Id. \
             For most widgets the Id is generated by a running counter. \
@@ -294,6 +311,25 @@ impl super::View for TableTest {
             ui.end_row();
         });
 
+        ui.separator();
+        egui::Grid::new("spanning grid")
+            .striped(true)
+            .show(ui, |ui| {
+                ui.grid_span(3, 1);
+                ui.label("Spanning three columns");
+                ui.end_row();
+
+                ui.grid_span(1, 2);
+                ui.label("Spanning two rows");
+                ui.label("Second row, second column");
+                ui.label("Second row, third column");
+                ui.end_row();
+
+                ui.grid_span(2, 1);
+                ui.separator();
+                ui.end_row();
+            });
+
         ui.vertical_centered(|ui| {
             egui::reset_button(ui, self);
             ui.add(crate::egui_github_link_file!());
@@ -377,12 +413,14 @@ impl super::View for InputTest {
 
 pub struct WindowResizeTest {
     text: String,
+    name: String,
 }
 
 impl Default for WindowResizeTest {
     fn default() -> Self {
         Self {
             text: crate::LOREM_IPSUM_LONG.to_owned(),
+            name: "Ferris".to_owned(),
         }
     }
 }
@@ -455,7 +493,12 @@ impl super::Demo for WindowResizeTest {
             .default_height(300.0)
             .show(ctx, |ui| {
                 ui.label("Shows how you can fill an area with a widget.");
-                ui.add_sized(ui.available_size(), TextEdit::multiline(&mut self.text));
+                ui.add_filling(TextEdit::multiline(&mut self.text));
+                ui.horizontal(|ui| {
+                    ui.label("Name:");
+                    ui.add_filling(TextEdit::singleline(&mut self.name));
+                    ui.label(format!("{} characters", self.text.chars().count()));
+                });
             });
 
         Window::new("↔ freely resized")
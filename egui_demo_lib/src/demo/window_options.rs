@@ -134,6 +134,12 @@ impl super::View for WindowOptions {
             if ui.button("Disable for 2 seconds").clicked() {
                 self.disabled_time = ui.input().time;
             }
+            if ui
+                .add_enabled(self.title_bar, egui::Button::new("Collapse"))
+                .clicked()
+            {
+                egui::Window::set_collapsed(ui.ctx(), egui::Id::new("demo_window_options"), true);
+            }
             egui::reset_button(ui, self);
             ui.add(crate::egui_github_link_file!());
         });
@@ -17,7 +17,10 @@ pub mod easy_mark;
 pub mod syntax_highlighting;
 
 pub use color_test::ColorTest;
-pub use demo::DemoWindows;
+pub use demo::{
+    about::{about_panel, AppInfo},
+    DemoWindows,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -97,6 +100,17 @@ fn test_egui_zero_window_size() {
     }
 }
 
+#[test]
+fn test_widget_gallery_snapshot() {
+    use demo::Demo as _;
+
+    let mut widget_gallery = demo::widget_gallery::WidgetGallery::default();
+    let mut harness = egui::util::Harness::new(|ctx| widget_gallery.show(ctx, &mut true));
+    harness.set_screen_size(egui::vec2(400.0, 600.0));
+    harness.run_until_idle(10);
+    harness.snapshot("widget_gallery");
+}
+
 // ----------------------------------------------------------------------------
 
 /// Detect narrow screens. This is used to show a simpler UI on mobile devices,
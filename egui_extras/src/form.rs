@@ -0,0 +1,389 @@
+use std::ops::RangeInclusive;
+
+use egui::{Color32, ComboBox, DragValue, Grid, Id, Response, RichText, TextEdit, Ui};
+
+/// The current value of a [`Field`], given to its validator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldValue<'v> {
+    Text(&'v str),
+    Number(f64),
+    Bool(bool),
+    /// Index of the selected option.
+    Choice(usize),
+    Color(Color32),
+}
+
+/// A copy of a field value, for knowing which fields have changed.
+#[derive(Clone, Debug, PartialEq)]
+enum Snapshot {
+    Text(String),
+    Number(f64),
+    Bool(bool),
+    Choice(usize),
+    Color(Color32),
+}
+
+enum FieldKind<'a> {
+    Text(&'a mut String),
+    Number {
+        get_set_value: Box<dyn 'a + FnMut(Option<f64>) -> f64>,
+        integral: bool,
+        range: Option<RangeInclusive<f64>>,
+    },
+    Bool(&'a mut bool),
+    Choice {
+        selected: &'a mut usize,
+        options: Vec<String>,
+    },
+    Color(&'a mut Color32),
+}
+
+impl<'a> FieldKind<'a> {
+    fn snapshot(&mut self) -> Snapshot {
+        match self {
+            Self::Text(text) => Snapshot::Text(text.to_string()),
+            Self::Number { get_set_value, .. } => Snapshot::Number(get_set_value(None)),
+            Self::Bool(value) => Snapshot::Bool(**value),
+            Self::Choice { selected, .. } => Snapshot::Choice(**selected),
+            Self::Color(color) => Snapshot::Color(**color),
+        }
+    }
+
+    fn ui(&mut self, ui: &mut Ui, id: Id) -> Response {
+        match self {
+            Self::Text(text) => ui.add(TextEdit::singleline(*text)),
+            Self::Number {
+                get_set_value,
+                integral,
+                range,
+            } => {
+                let mut drag_value = DragValue::from_get_set(get_set_value);
+                if *integral {
+                    drag_value = drag_value.fixed_decimals(0);
+                }
+                if let Some(range) = range {
+                    drag_value = drag_value.clamp_range(range.clone());
+                }
+                ui.add(drag_value)
+            }
+            Self::Bool(value) => ui.checkbox(value, ""),
+            Self::Choice { selected, options } => {
+                let mut response = ComboBox::from_id_source(id)
+                    .selected_text(options.get(**selected).map_or("", String::as_str))
+                    .show_ui(ui, |ui| {
+                        let mut changed = false;
+                        for (index, option) in options.iter().enumerate() {
+                            changed |= ui.selectable_value(*selected, index, option).changed();
+                        }
+                        changed
+                    });
+                if response.inner == Some(true) {
+                    response.response.mark_changed();
+                }
+                response.response
+            }
+            Self::Color(color) => ui.color_edit_button_srgba(color),
+        }
+    }
+}
+
+type Validator<'a> = Box<dyn 'a + Fn(FieldValue<'_>) -> Result<(), String>>;
+
+/// One row of a [`Form`]: a label, an editor for a value, and optional validation.
+///
+/// ```
+/// use egui_extras::{Field, FieldValue};
+/// let mut name = String::new();
+/// let field = Field::text("Name", &mut name).validate(|value| match value {
+///     FieldValue::Text(text) if text.contains(' ') => Err("Must be a single word".to_owned()),
+///     _ => Ok(()),
+/// });
+/// ```
+pub struct Field<'a> {
+    label: String,
+    kind: FieldKind<'a>,
+    required: bool,
+    validator: Option<Validator<'a>>,
+}
+
+impl<'a> Field<'a> {
+    fn new(label: impl Into<String>, kind: FieldKind<'a>) -> Self {
+        Self {
+            label: label.into(),
+            kind,
+            required: false,
+            validator: None,
+        }
+    }
+
+    /// A single line of text.
+    pub fn text(label: impl Into<String>, text: &'a mut String) -> Self {
+        Self::new(label, FieldKind::Text(text))
+    }
+
+    /// Any number, edited with a [`DragValue`].
+    pub fn number<Num: egui::emath::Numeric>(label: impl Into<String>, value: &'a mut Num) -> Self {
+        let get_set_value = move |v: Option<f64>| {
+            if let Some(v) = v {
+                *value = Num::from_f64(v);
+            }
+            value.to_f64()
+        };
+        Self::new(
+            label,
+            FieldKind::Number {
+                get_set_value: Box::new(get_set_value),
+                integral: Num::INTEGRAL,
+                range: None,
+            },
+        )
+    }
+
+    /// A checkbox.
+    pub fn bool(label: impl Into<String>, value: &'a mut bool) -> Self {
+        Self::new(label, FieldKind::Bool(value))
+    }
+
+    /// One of several options, shown in a [`ComboBox`].
+    /// `selected` is the index of the selected option.
+    pub fn choice(
+        label: impl Into<String>,
+        selected: &'a mut usize,
+        options: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        Self::new(
+            label,
+            FieldKind::Choice {
+                selected,
+                options: options.into_iter().map(Into::into).collect(),
+            },
+        )
+    }
+
+    /// A color, edited with a color picker.
+    pub fn color(label: impl Into<String>, color: &'a mut Color32) -> Self {
+        Self::new(label, FieldKind::Color(color))
+    }
+
+    /// Clamp a number field to this range. Has no effect on other fields.
+    pub fn range(mut self, clamp_range: RangeInclusive<f64>) -> Self {
+        if let FieldKind::Number { range, .. } = &mut self.kind {
+            *range = Some(clamp_range);
+        }
+        self
+    }
+
+    /// A text field that must not be empty.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    /// Check the value, returning an error message to show below the field if it is invalid.
+    pub fn validate(
+        mut self,
+        validator: impl 'a + Fn(FieldValue<'_>) -> Result<(), String>,
+    ) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    fn error(&self, snapshot: &Snapshot) -> Option<String> {
+        let value = match snapshot {
+            Snapshot::Text(text) => FieldValue::Text(text),
+            Snapshot::Number(value) => FieldValue::Number(*value),
+            Snapshot::Bool(value) => FieldValue::Bool(*value),
+            Snapshot::Choice(selected) => FieldValue::Choice(*selected),
+            Snapshot::Color(color) => FieldValue::Color(*color),
+        };
+        if self.required && value == FieldValue::Text("") {
+            return Some("Required".to_owned());
+        }
+        (self.validator.as_ref()?)(value).err()
+    }
+}
+
+/// A settings form: a two-column grid of labels and editors, built from a list of [`Field`]s.
+///
+/// Invalid fields get their error message shown below them, and changed fields are marked with `*`.
+/// A summary of errors and changes is shown at the bottom, and also returned in the [`FormResponse`].
+///
+/// ### Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_extras::{Field, Form};
+/// let mut name = "Ferris".to_owned();
+/// let mut age = 7_u32;
+/// let mut vegetarian = false;
+///
+/// let response = Form::new("person")
+///     .field(Field::text("Name", &mut name).required())
+///     .field(Field::number("Age", &mut age).range(0.0..=150.0))
+///     .field(Field::bool("Vegetarian", &mut vegetarian))
+///     .show(ui);
+///
+/// if response.is_valid() && response.is_dirty() && ui.button("Save").clicked() {
+///     // save…
+///     response.mark_clean();
+/// }
+/// # });
+/// ```
+pub struct Form<'a> {
+    id_source: Id,
+    fields: Vec<Field<'a>>,
+    show_summary: bool,
+}
+
+impl<'a> Form<'a> {
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            fields: vec![],
+            show_summary: true,
+        }
+    }
+
+    /// Add a row to the form.
+    pub fn field(mut self, field: Field<'a>) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// Add several rows to the form.
+    pub fn fields(mut self, fields: impl IntoIterator<Item = Field<'a>>) -> Self {
+        self.fields.extend(fields);
+        self
+    }
+
+    /// Show the number of errors and unsaved changes below the form. Default: `true`.
+    pub fn show_summary(mut self, show_summary: bool) -> Self {
+        self.show_summary = show_summary;
+        self
+    }
+
+    pub fn show(self, ui: &mut Ui) -> FormResponse {
+        let Self {
+            id_source,
+            mut fields,
+            show_summary,
+        } = self;
+
+        let id = ui.make_persistent_id(id_source);
+
+        // The values when the form was first shown, or last marked clean:
+        let clean: Option<Vec<Snapshot>> = ui.data().get_temp(id);
+        let clean = clean
+            .filter(|clean| clean.len() == fields.len())
+            .unwrap_or_else(|| {
+                let clean: Vec<Snapshot> = fields.iter_mut().map(|f| f.kind.snapshot()).collect();
+                ui.data().insert_temp(id, clean.clone());
+                clean
+            });
+
+        let mut errors = vec![];
+        let mut dirty = vec![];
+        let mut current = Vec::with_capacity(fields.len());
+        let mut changed = false;
+
+        let mut response = ui
+            .vertical(|ui| {
+                Grid::new(id.with("grid")).num_columns(2).show(ui, |ui| {
+                    for (index, (field, clean)) in fields.iter_mut().zip(&clean).enumerate() {
+                        let is_dirty = field.kind.snapshot() != *clean;
+                        if is_dirty {
+                            ui.label(format!("{} *", field.label))
+                                .on_hover_text("Changed");
+                        } else {
+                            ui.label(&field.label);
+                        }
+
+                        changed |= field.kind.ui(ui, id.with(index)).changed();
+                        ui.end_row();
+
+                        let snapshot = field.kind.snapshot();
+                        if let Some(error) = field.error(&snapshot) {
+                            ui.label("");
+                            ui.label(RichText::new(&error).color(ui.visuals().error_fg_color));
+                            ui.end_row();
+                            errors.push((field.label.clone(), error));
+                        }
+                        if snapshot != *clean {
+                            dirty.push(field.label.clone());
+                        }
+                        current.push(snapshot);
+                    }
+                });
+
+                if show_summary {
+                    ui.horizontal(|ui| {
+                        if !errors.is_empty() {
+                            ui.label(
+                                RichText::new(format!("{} error(s)", errors.len()))
+                                    .color(ui.visuals().error_fg_color),
+                            );
+                        }
+                        if dirty.is_empty() {
+                            ui.weak("No unsaved changes");
+                        } else {
+                            ui.label(format!("{} unsaved change(s)", dirty.len()));
+                        }
+                    });
+                }
+            })
+            .response;
+
+        if changed {
+            // Make sure the change markers are updated:
+            ui.ctx().request_repaint();
+            response.mark_changed();
+        }
+
+        FormResponse {
+            response,
+            errors,
+            dirty,
+            id,
+            current,
+        }
+    }
+}
+
+/// What happened to a [`Form`] this frame.
+pub struct FormResponse {
+    /// [`Response::changed`] is set if any value was edited this frame.
+    pub response: Response,
+
+    /// The label and error message of each invalid field.
+    pub errors: Vec<(String, String)>,
+
+    /// The labels of the fields that changed since the form was first shown (or since [`Self::mark_clean`]).
+    pub dirty: Vec<String>,
+
+    id: Id,
+    current: Vec<Snapshot>,
+}
+
+impl FormResponse {
+    /// No field has a validation error.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Some field changed since the form was first shown (or since [`Self::mark_clean`]).
+    pub fn is_dirty(&self) -> bool {
+        !self.dirty.is_empty()
+    }
+
+    /// Was any value edited this frame?
+    pub fn changed(&self) -> bool {
+        self.response.changed()
+    }
+
+    /// Consider the current values unchanged, e.g. after saving them.
+    pub fn mark_clean(&self) {
+        self.response
+            .ctx
+            .data()
+            .insert_temp(self.id, self.current.clone());
+    }
+}
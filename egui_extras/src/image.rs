@@ -1,5 +1,5 @@
 use egui::mutex::Mutex;
-use egui::TextureFilter;
+use egui::{TextureFilter, TextureOptions};
 
 /// An image to be shown in egui.
 ///
@@ -13,7 +13,7 @@ pub struct RetainedImage {
     image: Mutex<egui::ColorImage>,
     /// Lazily loaded when we have an egui context.
     texture: Mutex<Option<egui::TextureHandle>>,
-    filter: TextureFilter,
+    options: TextureOptions,
 }
 
 impl RetainedImage {
@@ -23,7 +23,7 @@ impl RetainedImage {
             size: image.size,
             image: Mutex::new(image),
             texture: Default::default(),
-            filter: Default::default(),
+            options: Default::default(),
         }
     }
 
@@ -86,7 +86,7 @@ impl RetainedImage {
     ///     .with_texture_filter(TextureFilter::Nearest);
     /// ```
     pub fn with_texture_filter(mut self, filter: TextureFilter) -> Self {
-        self.filter = filter;
+        self.options.filter = filter;
 
         // If the texture has already been uploaded, this will force it to be re-uploaded with the
         // updated filter.
@@ -95,6 +95,16 @@ impl RetainedImage {
         self
     }
 
+    /// Set the texture filter and wrap mode to use for the image.
+    ///
+    /// **Note:** If the texture has already been uploaded to the GPU, this will require
+    /// re-uploading the texture with the updated options.
+    pub fn with_texture_options(mut self, options: TextureOptions) -> Self {
+        self.options = options;
+        *self.texture.lock() = None;
+        self
+    }
+
     /// The size of the image data (number of pixels wide/high).
     pub fn size(&self) -> [usize; 2] {
         self.size
@@ -128,7 +138,7 @@ impl RetainedImage {
             .get_or_insert_with(|| {
                 let image: &mut ColorImage = &mut self.image.lock();
                 let image = std::mem::take(image);
-                ctx.load_texture(&self.debug_name, image, self.filter)
+                ctx.load_texture(&self.debug_name, image, self.options)
             })
             .id()
     }
@@ -162,6 +172,130 @@ impl RetainedImage {
 
 // ----------------------------------------------------------------------------
 
+/// An SVG image to be shown in egui, e.g. a toolbar icon.
+///
+/// Unlike [`RetainedImage::from_svg_bytes`], the SVG is rasterized at the size it is shown at,
+/// taking [`egui::Context::pixels_per_point`] into account, so it stays crisp on high-dpi screens
+/// and when zooming. The textures are cached per size.
+///
+/// Load once, and save somewhere in your app state.
+///
+/// Requires the "svg" feature.
+#[cfg(feature = "svg")]
+pub struct RetainedSvg {
+    debug_name: String,
+    svg_bytes: Vec<u8>,
+    /// The size given in the SVG, in points.
+    size: egui::Vec2,
+    /// One texture per size in pixels.
+    textures: Mutex<std::collections::HashMap<[u32; 2], egui::TextureHandle>>,
+    options: TextureOptions,
+    /// Loading the system fonts is slow, so we only do it once.
+    svg_options: usvg::Options,
+}
+
+#[cfg(feature = "svg")]
+impl RetainedSvg {
+    /// Don't keep more textures than this around, e.g. when the size is animated.
+    const MAX_CACHED_SIZES: usize = 8;
+
+    /// Pass in the bytes of an SVG that you've loaded.
+    ///
+    /// # Errors
+    /// On invalid image
+    pub fn from_svg_bytes(debug_name: impl Into<String>, svg_bytes: &[u8]) -> Result<Self, String> {
+        let svg_options = svg_options();
+        let size = parse_svg(svg_bytes, &svg_options)?.svg_node().size;
+        Ok(Self {
+            debug_name: debug_name.into(),
+            svg_bytes: svg_bytes.to_vec(),
+            size: egui::vec2(size.width() as f32, size.height() as f32),
+            textures: Default::default(),
+            options: Default::default(),
+            svg_options,
+        })
+    }
+
+    /// Pass in the str of an SVG that you've loaded.
+    ///
+    /// # Errors
+    /// On invalid image
+    pub fn from_svg_str(debug_name: impl Into<String>, svg_str: &str) -> Result<Self, String> {
+        Self::from_svg_bytes(debug_name, svg_str.as_bytes())
+    }
+
+    /// Set the texture filter and wrap mode to use for the image.
+    pub fn with_texture_options(mut self, options: TextureOptions) -> Self {
+        self.options = options;
+        self.textures.lock().clear();
+        self
+    }
+
+    /// The size given in the SVG, in points.
+    pub fn size_vec2(&self) -> egui::Vec2 {
+        self.size
+    }
+
+    /// The debug name of the image, e.g. the file name.
+    pub fn debug_name(&self) -> &str {
+        &self.debug_name
+    }
+
+    /// The texture for showing the image at the given size (in points).
+    ///
+    /// The SVG is rasterized the first time it is shown at a certain size (in pixels).
+    pub fn texture_id(&self, ctx: &egui::Context, size: egui::Vec2) -> egui::TextureId {
+        let size_in_pixels = size * ctx.pixels_per_point();
+        let size_in_pixels = [
+            size_in_pixels.x.round().max(1.0) as u32,
+            size_in_pixels.y.round().max(1.0) as u32,
+        ];
+
+        let mut textures = self.textures.lock();
+        if !textures.contains_key(&size_in_pixels) && textures.len() >= Self::MAX_CACHED_SIZES {
+            textures.clear();
+        }
+        textures
+            .entry(size_in_pixels)
+            .or_insert_with(|| {
+                let [w, h] = size_in_pixels;
+                let fit_to = usvg::FitTo::Size(w, h);
+                let image = rasterize_svg(&self.svg_bytes, fit_to, &self.svg_options)
+                    .unwrap_or_else(|err| {
+                        crate::log_err!("Failed to rasterize {:?}: {}", self.debug_name, err);
+                        ColorImage::new([1, 1], egui::Color32::TRANSPARENT)
+                    });
+                ctx.load_texture(&self.debug_name, image, self.options)
+            })
+            .id()
+    }
+
+    /// Show the image with the given maximum size, keeping the aspect ratio.
+    pub fn show_max_size(&self, ui: &mut egui::Ui, max_size: egui::Vec2) -> egui::Response {
+        let mut desired_size = self.size_vec2();
+        desired_size *= (max_size.x / desired_size.x).min(1.0);
+        desired_size *= (max_size.y / desired_size.y).min(1.0);
+        self.show_size(ui, desired_size)
+    }
+
+    /// Show the image with the size given in the SVG.
+    pub fn show(&self, ui: &mut egui::Ui) -> egui::Response {
+        self.show_size(ui, self.size_vec2())
+    }
+
+    /// Show the image with the given scale factor (1.0 = the size given in the SVG).
+    pub fn show_scaled(&self, ui: &mut egui::Ui, scale: f32) -> egui::Response {
+        self.show_size(ui, self.size_vec2() * scale)
+    }
+
+    /// Show the image with the given size.
+    pub fn show_size(&self, ui: &mut egui::Ui, desired_size: egui::Vec2) -> egui::Response {
+        ui.image(self.texture_id(ui.ctx(), desired_size), desired_size)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 use egui::ColorImage;
 
 /// Load a (non-svg) image.
@@ -191,24 +325,42 @@ pub fn load_image_bytes(image_bytes: &[u8]) -> Result<egui::ColorImage, String>
 /// On invalid image
 #[cfg(feature = "svg")]
 pub fn load_svg_bytes(svg_bytes: &[u8]) -> Result<egui::ColorImage, String> {
-    let mut opt = usvg::Options::default();
-    opt.fontdb.load_system_fonts();
+    load_svg_bytes_with_size(svg_bytes, usvg::FitTo::Original)
+}
 
-    let rtree = usvg::Tree::from_data(svg_bytes, &opt.to_ref()).map_err(|err| err.to_string())?;
+/// Load an SVG and rasterize it into an egui image of the given size.
+///
+/// Requires the "svg" feature.
+///
+/// # Errors
+/// On invalid image
+#[cfg(feature = "svg")]
+pub fn load_svg_bytes_with_size(
+    svg_bytes: &[u8],
+    fit_to: usvg::FitTo,
+) -> Result<egui::ColorImage, String> {
+    rasterize_svg(svg_bytes, fit_to, &svg_options())
+}
+
+#[cfg(feature = "svg")]
+fn rasterize_svg(
+    svg_bytes: &[u8],
+    fit_to: usvg::FitTo,
+    svg_options: &usvg::Options,
+) -> Result<egui::ColorImage, String> {
+    let rtree = parse_svg(svg_bytes, svg_options)?;
 
     let pixmap_size = rtree.svg_node().size.to_screen_size();
+    let pixmap_size = fit_to
+        .fit_to(pixmap_size)
+        .ok_or_else(|| "Failed to fit SVG to the requested size".to_owned())?;
     let [w, h] = [pixmap_size.width(), pixmap_size.height()];
 
     let mut pixmap = tiny_skia::Pixmap::new(w, h)
         .ok_or_else(|| format!("Failed to create SVG Pixmap of size {}x{}", w, h))?;
 
-    resvg::render(
-        &rtree,
-        usvg::FitTo::Original,
-        Default::default(),
-        pixmap.as_mut(),
-    )
-    .ok_or_else(|| "Failed to render SVG".to_owned())?;
+    resvg::render(&rtree, fit_to, Default::default(), pixmap.as_mut())
+        .ok_or_else(|| "Failed to render SVG".to_owned())?;
 
     let image = egui::ColorImage::from_rgba_unmultiplied(
         [pixmap.width() as _, pixmap.height() as _],
@@ -217,3 +369,15 @@ pub fn load_svg_bytes(svg_bytes: &[u8]) -> Result<egui::ColorImage, String> {
 
     Ok(image)
 }
+
+#[cfg(feature = "svg")]
+fn svg_options() -> usvg::Options {
+    let mut opt = usvg::Options::default();
+    opt.fontdb.load_system_fonts();
+    opt
+}
+
+#[cfg(feature = "svg")]
+fn parse_svg(svg_bytes: &[u8], svg_options: &usvg::Options) -> Result<usvg::Tree, String> {
+    usvg::Tree::from_data(svg_bytes, &svg_options.to_ref()).map_err(|err| err.to_string())
+}
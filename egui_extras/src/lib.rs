@@ -12,6 +12,7 @@
 #[cfg(feature = "chrono")]
 mod datepicker;
 
+mod form;
 pub mod image;
 mod layout;
 mod sizing;
@@ -21,7 +22,10 @@ mod table;
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
 
+pub use crate::form::{Field, FieldValue, Form, FormResponse};
 pub use crate::image::RetainedImage;
+#[cfg(feature = "svg")]
+pub use crate::image::RetainedSvg;
 pub(crate) use crate::layout::StripLayout;
 pub use crate::sizing::Size;
 pub use crate::strip::*;
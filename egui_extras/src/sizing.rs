@@ -7,8 +7,8 @@ pub enum Size {
     /// Relative size relative to all available space.
     Relative { fraction: f32, range: (f32, f32) },
 
-    /// Multiple remainders each get the same space.
-    Remainder { range: (f32, f32) },
+    /// Multiple remainders share the remaining space in proportion to their weights.
+    Remainder { weight: f32, range: (f32, f32) },
 }
 
 impl Size {
@@ -39,7 +39,17 @@ impl Size {
 
     /// Multiple remainders each get the same space.
     pub fn remainder() -> Self {
+        Self::weighted(1.0)
+    }
+
+    /// Share the remaining space with the other remainders in proportion to `weight`,
+    /// like `flex-grow` in CSS.
+    ///
+    /// A remainder with weight `2.0` gets twice as much space as a [`Self::remainder`].
+    pub fn weighted(weight: f32) -> Self {
+        egui::egui_assert!(0.0 <= weight);
         Self::Remainder {
+            weight,
             range: (0.0, f32::INFINITY),
         }
     }
@@ -93,7 +103,7 @@ impl Sizing {
             return vec![];
         }
 
-        let mut remainders = 0;
+        let mut remainder_weight = 0.0;
         let sum_non_remainder = self
             .sizes
             .iter()
@@ -106,29 +116,35 @@ impl Sizing {
                     assert!(0.0 <= fraction && fraction <= 1.0);
                     (length * fraction).clamp(min, max)
                 }
-                Size::Remainder { .. } => {
-                    remainders += 1;
+                Size::Remainder { weight, .. } => {
+                    remainder_weight += weight;
                     0.0
                 }
             })
             .sum::<f32>()
             + spacing * (self.sizes.len() - 1) as f32;
 
-        let avg_remainder_length = if remainders == 0 {
+        // How much space each unit of weight gets:
+        let length_per_weight = if remainder_weight <= 0.0 {
             0.0
         } else {
             let mut remainder_length = length - sum_non_remainder;
-            let avg_remainder_length = 0.0f32.max(remainder_length / remainders as f32).floor();
+            let length_per_weight = 0.0f32.max(remainder_length / remainder_weight).floor();
+            let mut weight_left = remainder_weight;
             self.sizes.iter().for_each(|&size| {
-                if let Size::Remainder { range: (min, _max) } = size {
-                    if avg_remainder_length < min {
+                if let Size::Remainder {
+                    weight,
+                    range: (min, _max),
+                } = size
+                {
+                    if weight * length_per_weight < min {
                         remainder_length -= min;
-                        remainders -= 1;
+                        weight_left -= weight;
                     }
                 }
             });
-            if remainders > 0 {
-                0.0f32.max(remainder_length / remainders as f32)
+            if weight_left > 0.0 {
+                0.0f32.max(remainder_length / weight_left)
             } else {
                 0.0
             }
@@ -142,7 +158,10 @@ impl Sizing {
                     fraction,
                     range: (min, max),
                 } => (length * fraction).clamp(min, max),
-                Size::Remainder { range: (min, max) } => avg_remainder_length.clamp(min, max),
+                Size::Remainder {
+                    weight,
+                    range: (min, max),
+                } => (weight * length_per_weight).clamp(min, max),
             })
             .collect()
     }
@@ -169,6 +188,13 @@ fn test_sizing() {
     assert_eq!(sizing.to_lengths(40.0, 10.0), vec![20.0, 10.0]);
     assert_eq!(sizing.to_lengths(110.0, 10.0), vec![50.0, 50.0]);
 
+    let sizing: Sizing = vec![Size::weighted(3.0), Size::remainder(), Size::exact(10.0)].into();
+    assert_eq!(sizing.to_lengths(50.0, 0.0), vec![30.0, 10.0, 10.0]);
+    assert_eq!(sizing.to_lengths(60.0, 5.0), vec![30.0, 10.0, 10.0]);
+
+    let sizing: Sizing = vec![Size::weighted(3.0), Size::remainder().at_least(20.0)].into();
+    assert_eq!(sizing.to_lengths(40.0, 0.0), vec![20.0, 20.0]);
+
     let sizing: Sizing = vec![Size::relative(0.5).at_least(10.0), Size::exact(10.0)].into();
     assert_eq!(sizing.to_lengths(50.0, 0.0), vec![25.0, 10.0]);
     assert_eq!(sizing.to_lengths(30.0, 0.0), vec![15.0, 10.0]);
@@ -324,7 +324,10 @@ impl<'a> Table<'a> {
                 // If the last column is Size::Remainder, then let it fill the remainder!
                 let last_column = i + 1 == sizing.sizes.len();
                 if last_column {
-                    if let Size::Remainder { range: (min, max) } = sizing.sizes[i] {
+                    if let Size::Remainder {
+                        range: (min, max), ..
+                    } = sizing.sizes[i]
+                    {
                         let eps = 0.1; // just to avoid some rounding errors.
                         *width = (available_width - eps).clamp(min, max);
                         break;
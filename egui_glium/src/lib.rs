@@ -26,6 +26,7 @@ pub struct EguiGlium {
     pub painter: crate::Painter,
 
     shapes: Vec<egui::epaint::ClippedShape>,
+    shape_layers: Vec<(egui::LayerId, usize)>,
     textures_delta: egui::TexturesDelta,
 }
 
@@ -43,6 +44,7 @@ impl EguiGlium {
             egui_winit,
             painter,
             shapes: Default::default(),
+            shape_layers: Default::default(),
             textures_delta: Default::default(),
         }
     }
@@ -73,6 +75,8 @@ impl EguiGlium {
             repaint_after,
             textures_delta,
             shapes,
+            shape_layers,
+            viewports: _, // embedded as `egui::Window`s
         } = self.egui_ctx.run(raw_input, run_ui);
 
         self.egui_winit.handle_platform_output(
@@ -82,6 +86,7 @@ impl EguiGlium {
         );
 
         self.shapes = shapes;
+        self.shape_layers = shape_layers;
         self.textures_delta.append(textures_delta);
 
         repaint_after
@@ -90,8 +95,9 @@ impl EguiGlium {
     /// Paint the results of the last call to [`Self::run`].
     pub fn paint<T: glium::Surface>(&mut self, display: &glium::Display, target: &mut T) {
         let shapes = std::mem::take(&mut self.shapes);
+        let shape_layers = std::mem::take(&mut self.shape_layers);
         let textures_delta = std::mem::take(&mut self.textures_delta);
-        let clipped_primitives = self.egui_ctx.tessellate(shapes);
+        let clipped_primitives = self.egui_ctx.tessellate_layers(shapes, &shape_layers);
         self.painter.paint_and_update_textures(
             display,
             target,
@@ -12,7 +12,7 @@ use {
         program,
         texture::{self, srgb_texture2d::SrgbTexture2d},
         uniform,
-        uniforms::{MagnifySamplerFilter, SamplerWrapFunction},
+        uniforms::{MagnifySamplerFilter, MinifySamplerFilter, SamplerWrapFunction},
     },
     std::rc::Rc,
 };
@@ -23,6 +23,9 @@ pub struct Painter {
 
     textures: AHashMap<egui::TextureId, Rc<SrgbTexture2d>>,
 
+    /// How to sample each texture. Missing entries use [`egui::TextureOptions::default`].
+    texture_options: AHashMap<egui::TextureId, egui::TextureOptions>,
+
     /// [`egui::TextureId::User`] index
     next_native_tex_id: u64,
 }
@@ -57,6 +60,7 @@ impl Painter {
             max_texture_side,
             program,
             textures: Default::default(),
+            texture_options: Default::default(),
             next_native_tex_id: 0,
         }
     }
@@ -146,13 +150,39 @@ impl Painter {
         let height_in_points = height_in_pixels as f32 / pixels_per_point;
 
         if let Some(texture) = self.get_texture(mesh.texture_id) {
-            // The texture coordinates for text are so that both nearest and linear should work with the egui font texture.
-            // For user textures linear sampling is more likely to be the right choice.
-            let filter = MagnifySamplerFilter::Linear;
+            let options = self
+                .texture_options
+                .get(&mesh.texture_id)
+                .copied()
+                .unwrap_or_default();
+            let magnify_filter = match options.filter {
+                egui::TextureFilter::Nearest => MagnifySamplerFilter::Nearest,
+                egui::TextureFilter::Linear => MagnifySamplerFilter::Linear,
+            };
+            let minify_filter = {
+                use egui::TextureFilter::{Linear, Nearest};
+                match (options.filter, options.mipmap_mode) {
+                    (Nearest, None) => MinifySamplerFilter::Nearest,
+                    (Linear, None) => MinifySamplerFilter::Linear,
+                    (Nearest, Some(Nearest)) => MinifySamplerFilter::NearestMipmapNearest,
+                    (Nearest, Some(Linear)) => MinifySamplerFilter::NearestMipmapLinear,
+                    (Linear, Some(Nearest)) => MinifySamplerFilter::LinearMipmapNearest,
+                    (Linear, Some(Linear)) => MinifySamplerFilter::LinearMipmapLinear,
+                }
+            };
+            let wrap_function = match options.wrap_mode {
+                egui::TextureWrapMode::ClampToEdge => SamplerWrapFunction::Clamp,
+                egui::TextureWrapMode::Repeat => SamplerWrapFunction::Repeat,
+                egui::TextureWrapMode::MirroredRepeat => SamplerWrapFunction::Mirror,
+            };
 
             let uniforms = uniform! {
                 u_screen_size: [width_in_points, height_in_points],
-                u_sampler: texture.sampled().magnify_filter(filter).wrap_function(SamplerWrapFunction::Clamp),
+                u_sampler: texture
+                    .sampled()
+                    .magnify_filter(magnify_filter)
+                    .minify_filter(minify_filter)
+                    .wrap_function(wrap_function),
             };
 
             // egui outputs colors with premultiplied alpha:
@@ -250,7 +280,11 @@ impl Painter {
             format: glium::texture::ClientFormat::U8U8U8U8,
         };
         let format = texture::SrgbFormat::U8U8U8U8;
-        let mipmaps = texture::MipmapsOption::NoMipmap;
+        let mipmaps = if delta.options.mipmap_mode.is_some() {
+            texture::MipmapsOption::AutoGeneratedMipmaps
+        } else {
+            texture::MipmapsOption::NoMipmap
+        };
 
         if let Some(pos) = delta.pos {
             // update a sub-region
@@ -268,10 +302,13 @@ impl Painter {
                 SrgbTexture2d::with_format(facade, glium_image, format, mipmaps).unwrap();
             self.textures.insert(tex_id, gl_texture.into());
         }
+
+        self.texture_options.insert(tex_id, delta.options);
     }
 
     pub fn free_texture(&mut self, tex_id: egui::TextureId) {
         self.textures.remove(&tex_id);
+        self.texture_options.remove(&tex_id);
     }
 
     fn get_texture(&self, texture_id: egui::TextureId) -> Option<&SrgbTexture2d> {
@@ -279,9 +316,20 @@ impl Painter {
     }
 
     pub fn register_native_texture(&mut self, native: Rc<SrgbTexture2d>) -> egui::TextureId {
+        self.register_native_texture_with_options(native, Default::default())
+    }
+
+    /// Like [`Self::register_native_texture`], but with the given filter and wrap mode
+    /// instead of the default [`egui::TextureOptions::LINEAR`].
+    pub fn register_native_texture_with_options(
+        &mut self,
+        native: Rc<SrgbTexture2d>,
+        options: egui::TextureOptions,
+    ) -> egui::TextureId {
         let id = egui::TextureId::User(self.next_native_tex_id);
         self.next_native_tex_id += 1;
         self.textures.insert(id, native);
+        self.texture_options.insert(id, options);
         id
     }
 
@@ -21,6 +21,7 @@ const VERT_SRC: &str = include_str!("shader/vertex.glsl");
 const FRAG_SRC: &str = include_str!("shader/fragment.glsl");
 
 pub type TextureFilter = egui::TextureFilter;
+pub type TextureWrapMode = egui::TextureWrapMode;
 
 trait TextureFilterExt {
     fn glow_code(&self) -> u32;
@@ -35,6 +36,30 @@ impl TextureFilterExt for TextureFilter {
     }
 }
 
+/// The `TEXTURE_MIN_FILTER` to use when the texture has mipmaps.
+fn mipmap_min_filter_glow_code(filter: TextureFilter, mipmap_mode: TextureFilter) -> u32 {
+    match (filter, mipmap_mode) {
+        (TextureFilter::Nearest, TextureFilter::Nearest) => glow::NEAREST_MIPMAP_NEAREST,
+        (TextureFilter::Nearest, TextureFilter::Linear) => glow::NEAREST_MIPMAP_LINEAR,
+        (TextureFilter::Linear, TextureFilter::Nearest) => glow::LINEAR_MIPMAP_NEAREST,
+        (TextureFilter::Linear, TextureFilter::Linear) => glow::LINEAR_MIPMAP_LINEAR,
+    }
+}
+
+trait TextureWrapModeExt {
+    fn glow_code(&self) -> u32;
+}
+
+impl TextureWrapModeExt for TextureWrapMode {
+    fn glow_code(&self) -> u32 {
+        match self {
+            TextureWrapMode::ClampToEdge => glow::CLAMP_TO_EDGE,
+            TextureWrapMode::Repeat => glow::REPEAT,
+            TextureWrapMode::MirroredRepeat => glow::MIRRORED_REPEAT,
+        }
+    }
+}
+
 /// An OpenGL painter using [`glow`].
 ///
 /// This is responsible for painting egui and managing egui textures.
@@ -42,6 +67,11 @@ impl TextureFilterExt for TextureFilter {
 ///
 /// This struct must be destroyed with [`Painter::destroy`] before dropping, to ensure OpenGL
 /// objects have been properly deleted and are not leaked.
+///
+/// The painter only needs a [`glow::Context`], so you can use it to paint egui on top of
+/// an existing OpenGL engine that owns the context and the window.
+/// Note that painting changes the OpenGL state (blending, scissor test, depth test, culling,
+/// the bound program, buffers and textures), so restore whatever your engine relies on afterwards.
 pub struct Painter {
     gl: Arc<glow::Context>,
 
@@ -100,8 +130,10 @@ impl Painter {
     /// # Errors
     /// will return `Err` below cases
     /// * failed to compile shader
-    /// * failed to create postprocess on webgl with `sRGB` support
     /// * failed to create buffer
+    ///
+    /// If the post processing needed for `sRGB` on WebGL can't be set up
+    /// (e.g. because of a missing extension), it falls back to a shader without `sRGB` support.
     pub fn new(
         gl: Arc<glow::Context>,
         pp_fb_extent: Option<[i32; 2]>,
@@ -125,15 +157,14 @@ impl Painter {
                 if let Some(size) = pp_fb_extent {
                     tracing::debug!("WebGL with sRGB enabled. Turning on post processing for linear framebuffer blending.");
                     // install post process to correct sRGB color:
-                    (
-                        Some(PostProcess::new(
-                            gl.clone(),
-                            shader_prefix,
-                            is_webgl_1,
-                            size,
-                        )?),
-                        "#define SRGB_SUPPORTED",
-                    )
+                    match PostProcess::new(gl.clone(), shader_prefix, is_webgl_1, size) {
+                        Ok(post_process) => (Some(post_process), "#define SRGB_SUPPORTED"),
+                        Err(err) => {
+                            // Colors will be slightly off, but that is better than nothing:
+                            tracing::warn!("Failed to set up sRGB post processing, falling back to the sRGB-less shader: {}", err);
+                            (None, "")
+                        }
+                    }
                 } else {
                     tracing::debug!("WebGL or OpenGL ES detected but PostProcess disabled because dimension is None");
                     (None, "")
@@ -512,7 +543,7 @@ impl Painter {
 
                 let data: &[u8] = bytemuck::cast_slice(image.pixels.as_ref());
 
-                self.upload_texture_srgb(delta.pos, image.size, delta.filter, data);
+                self.upload_texture_srgb(delta.pos, image.size, delta.options, data);
             }
             egui::ImageData::Font(image) => {
                 assert_eq!(
@@ -531,7 +562,7 @@ impl Painter {
                     .flat_map(|a| a.to_array())
                     .collect();
 
-                self.upload_texture_srgb(delta.pos, image.size, delta.filter, &data);
+                self.upload_texture_srgb(delta.pos, image.size, delta.options, &data);
             }
         };
     }
@@ -540,7 +571,7 @@ impl Painter {
         &mut self,
         pos: Option<[usize; 2]>,
         [w, h]: [usize; 2],
-        texture_filter: TextureFilter,
+        options: egui::TextureOptions,
         data: &[u8],
     ) {
         assert_eq!(data.len(), w * h * 4);
@@ -558,27 +589,34 @@ impl Painter {
             self.max_texture_side
         );
 
+        // WebGL1 can only generate mipmaps for power-of-two textures, so we skip them there.
+        let mipmap_mode = options.mipmap_mode.filter(|_| !self.is_webgl_1);
+        let min_filter = match mipmap_mode {
+            Some(mipmap_mode) => mipmap_min_filter_glow_code(options.filter, mipmap_mode),
+            None => options.filter.glow_code(),
+        };
+
         unsafe {
             self.gl.tex_parameter_i32(
                 glow::TEXTURE_2D,
                 glow::TEXTURE_MAG_FILTER,
-                texture_filter.glow_code() as i32,
+                options.filter.glow_code() as i32,
             );
             self.gl.tex_parameter_i32(
                 glow::TEXTURE_2D,
                 glow::TEXTURE_MIN_FILTER,
-                texture_filter.glow_code() as i32,
+                min_filter as i32,
             );
 
             self.gl.tex_parameter_i32(
                 glow::TEXTURE_2D,
                 glow::TEXTURE_WRAP_S,
-                glow::CLAMP_TO_EDGE as i32,
+                options.wrap_mode.glow_code() as i32,
             );
             self.gl.tex_parameter_i32(
                 glow::TEXTURE_2D,
                 glow::TEXTURE_WRAP_T,
-                glow::CLAMP_TO_EDGE as i32,
+                options.wrap_mode.glow_code() as i32,
             );
             check_for_gl_error!(&self.gl, "tex_parameter");
 
@@ -624,6 +662,11 @@ impl Painter {
                 );
                 check_for_gl_error!(&self.gl, "tex_image_2d");
             }
+
+            if mipmap_mode.is_some() {
+                self.gl.generate_mipmap(glow::TEXTURE_2D);
+                check_for_gl_error!(&self.gl, "generate_mipmap");
+            }
         }
     }
 
@@ -654,6 +697,46 @@ impl Painter {
         }
     }
 
+    /// Read back the pixels of the current framebuffer, e.g. to take a screenshot.
+    ///
+    /// Call this after painting, but before swapping buffers.
+    /// See [`egui::Context::layer_shapes`] for how to capture a single window.
+    pub fn read_screen_rgba(&self, [width, height]: [u32; 2]) -> egui::ColorImage {
+        self.assert_not_destroyed();
+
+        if width == 0 || height == 0 {
+            return Default::default();
+        }
+
+        let mut pixels = vec![0_u8; (width * height * 4) as usize];
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                width as _,
+                height as _,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+        }
+
+        // OpenGL has the origin in the bottom left, egui in the top left.
+        // egui paints with premultiplied alpha, so that is what is in the framebuffer:
+        let row_len = width as usize * 4;
+        let pixels = pixels
+            .chunks_exact(row_len)
+            .rev()
+            .flat_map(|row| row.chunks_exact(4))
+            .map(|p| egui::Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+
+        egui::ColorImage {
+            size: [width as usize, height as usize],
+            pixels,
+        }
+    }
+
     unsafe fn destroy_gl(&self) {
         self.gl.delete_program(self.program);
         for tex in self.textures.values() {
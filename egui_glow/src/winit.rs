@@ -8,6 +8,7 @@ pub struct EguiGlow {
     pub painter: crate::Painter,
 
     shapes: Vec<egui::epaint::ClippedShape>,
+    shape_layers: Vec<(egui::LayerId, usize)>,
     textures_delta: egui::TexturesDelta,
 }
 
@@ -27,6 +28,7 @@ impl EguiGlow {
             egui_winit: egui_winit::State::new(event_loop),
             painter,
             shapes: Default::default(),
+            shape_layers: Default::default(),
             textures_delta: Default::default(),
         }
     }
@@ -55,12 +57,14 @@ impl EguiGlow {
             repaint_after,
             textures_delta,
             shapes,
+            shape_layers,
         } = self.egui_ctx.run(raw_input, run_ui);
 
         self.egui_winit
             .handle_platform_output(window, &self.egui_ctx, platform_output);
 
         self.shapes = shapes;
+        self.shape_layers = shape_layers;
         self.textures_delta.append(textures_delta);
         repaint_after
     }
@@ -68,13 +72,14 @@ impl EguiGlow {
     /// Paint the results of the last call to [`Self::run`].
     pub fn paint(&mut self, window: &winit::window::Window) {
         let shapes = std::mem::take(&mut self.shapes);
+        let shape_layers = std::mem::take(&mut self.shape_layers);
         let mut textures_delta = std::mem::take(&mut self.textures_delta);
 
         for (id, image_delta) in textures_delta.set {
             self.painter.set_texture(id, &image_delta);
         }
 
-        let clipped_primitives = self.egui_ctx.tessellate(shapes);
+        let clipped_primitives = self.egui_ctx.tessellate_layers(shapes, &shape_layers);
         let dimensions: [u32; 2] = window.inner_size().into();
         self.painter.paint_primitives(
             dimensions,
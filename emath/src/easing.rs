@@ -0,0 +1,112 @@
+//! Easing functions for animations.
+//!
+//! Each function maps `t` in `[0, 1]` (how far the animation has come in time)
+//! to how far the animated value has come, usually also in `[0, 1]`.
+//! The exception is [`back_out`], which overshoots `1` before settling.
+//! `f(0) == 0` and `f(1) == 1` for all of them.
+//!
+//! See <https://easings.net/> for what they look like.
+
+use std::f32::consts::PI;
+
+/// No easing: constant speed.
+#[inline]
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+/// Starts slow, ends fast.
+#[inline]
+pub fn quadratic_in(t: f32) -> f32 {
+    t * t
+}
+
+/// Starts fast, ends slow.
+#[inline]
+pub fn quadratic_out(t: f32) -> f32 {
+    -(t * (t - 2.)) // = 1 - (1 - t)^2
+}
+
+/// Slow at the start and end, fast in the middle.
+#[inline]
+pub fn quadratic_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        2. * t * t
+    } else {
+        (-2. * t * t) + (4. * t) - 1.
+    }
+}
+
+/// Starts slow, ends fast.
+#[inline]
+pub fn cubic_in(t: f32) -> f32 {
+    t * t * t
+}
+
+/// Starts fast, ends slow.
+#[inline]
+pub fn cubic_out(t: f32) -> f32 {
+    let f = t - 1.;
+    f * f * f + 1.
+}
+
+/// Slow at the start and end, fast in the middle.
+#[inline]
+pub fn cubic_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        4. * t * t * t
+    } else {
+        let f = (2. * t) - 2.;
+        0.5 * f * f * f + 1.
+    }
+}
+
+/// Starts slow, ends fast, following a quarter sine wave.
+#[inline]
+pub fn sin_in(t: f32) -> f32 {
+    1. - ((t * PI / 2.).cos())
+}
+
+/// Starts fast, ends slow, following a quarter sine wave.
+#[inline]
+pub fn sin_out(t: f32) -> f32 {
+    (t * PI / 2.).sin()
+}
+
+/// Slow at the start and end, fast in the middle, following half a sine wave.
+#[inline]
+pub fn sin_in_out(t: f32) -> f32 {
+    0.5 * (1. - (t * PI).cos())
+}
+
+/// Overshoots the target a little before settling, like a spring.
+///
+/// Peaks at about `1.1`, so the result is outside `[0, 1]` for part of the animation.
+#[inline]
+pub fn back_out(t: f32) -> f32 {
+    let c1 = 1.70158;
+    let c3 = c1 + 1.;
+    let f = t - 1.;
+    1. + c3 * f * f * f + c1 * f * f
+}
+
+#[test]
+fn test_easing_end_points() {
+    let functions: [fn(f32) -> f32; 11] = [
+        linear,
+        quadratic_in,
+        quadratic_out,
+        quadratic_in_out,
+        cubic_in,
+        cubic_out,
+        cubic_in_out,
+        sin_in,
+        sin_out,
+        sin_in_out,
+        back_out,
+    ];
+    for f in functions {
+        assert!(f(0.0).abs() < 1e-6);
+        assert!((f(1.0) - 1.0).abs() < 1e-6);
+    }
+}
@@ -27,6 +27,7 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 // ----------------------------------------------------------------------------
 
 pub mod align;
+pub mod easing;
 mod numeric;
 mod pos2;
 mod rect;
@@ -45,6 +46,9 @@ pub use {
     vec2::*,
 };
 
+/// The version of emath, e.g. for showing in an about panel.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 // ----------------------------------------------------------------------------
 
 /// Helper trait to implement [`lerp`] and [`remap`].
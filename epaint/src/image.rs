@@ -1,4 +1,4 @@
-use crate::{textures::TextureFilter, Color32};
+use crate::{textures::TextureOptions, Color32};
 
 /// An image stored in RAM.
 ///
@@ -118,6 +118,40 @@ impl ColorImage {
         img
     }
 
+    /// Clone a sub-region as a new image, e.g. for cropping a screenshot to a single window.
+    ///
+    /// `[x, y]` is the top left corner and `[w, h]` the size of the region, in pixels.
+    ///
+    /// The parts of the region outside of the image are cut off
+    /// (e.g. for a window that is partially off-screen), so the result can be smaller than `[w, h]`.
+    ///
+    /// ```
+    /// # use epaint::{ColorImage, Color32};
+    /// let image = ColorImage::new([64, 32], Color32::RED);
+    /// let cropped = image.region([10, 10], [30, 22]);
+    /// assert_eq!(cropped.size, [30, 22]);
+    ///
+    /// let cropped = image.region([50, 20], [30, 22]);
+    /// assert_eq!(cropped.size, [14, 12]);
+    /// ```
+    pub fn region(&self, [x, y]: [usize; 2], [w, h]: [usize; 2]) -> Self {
+        let x = x.min(self.width());
+        let y = y.min(self.height());
+        let w = w.min(self.width() - x);
+        let h = h.min(self.height() - y);
+
+        let mut pixels = Vec::with_capacity(w * h);
+        for y in y..y + h {
+            let offset = y * self.width() + x;
+            pixels.extend_from_slice(&self.pixels[offset..(offset + w)]);
+        }
+        assert_eq!(pixels.len(), w * h);
+        Self {
+            size: [w, h],
+            pixels,
+        }
+    }
+
     #[inline]
     pub fn width(&self) -> usize {
         self.size[0]
@@ -274,7 +308,8 @@ pub struct ImageDelta {
     /// If [`Self::pos`] is `Some`, this describes a patch of the whole image starting at [`Self::pos`].
     pub image: ImageData,
 
-    pub filter: TextureFilter,
+    /// How the texture should be sampled.
+    pub options: TextureOptions,
 
     /// If `None`, set the whole texture to [`Self::image`].
     ///
@@ -284,19 +319,19 @@ pub struct ImageDelta {
 
 impl ImageDelta {
     /// Update the whole texture.
-    pub fn full(image: impl Into<ImageData>, filter: TextureFilter) -> Self {
+    pub fn full(image: impl Into<ImageData>, options: TextureOptions) -> Self {
         Self {
             image: image.into(),
-            filter,
+            options,
             pos: None,
         }
     }
 
     /// Update a sub-region of an existing texture.
-    pub fn partial(pos: [usize; 2], image: impl Into<ImageData>, filter: TextureFilter) -> Self {
+    pub fn partial(pos: [usize; 2], image: impl Into<ImageData>, options: TextureOptions) -> Self {
         Self {
             image: image.into(),
-            filter,
+            options,
             pos: Some(pos),
         }
     }
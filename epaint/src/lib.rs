@@ -17,6 +17,8 @@ pub mod color;
 pub mod image;
 mod mesh;
 pub mod mutex;
+mod path_builder;
+mod rasterizer;
 mod shadow;
 mod shape;
 pub mod shape_transform;
@@ -34,6 +36,8 @@ pub use {
     color::{Color32, Rgba},
     image::{ColorImage, FontImage, ImageData, ImageDelta},
     mesh::{Mesh, Mesh16, Vertex},
+    path_builder::PathBuilder,
+    rasterizer::Rasterizer,
     shadow::Shadow,
     shape::{
         CircleShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape, Rounding, Shape,
@@ -41,9 +45,9 @@ pub use {
     },
     stats::PaintStats,
     stroke::Stroke,
-    tessellator::{tessellate_shapes, TessellationOptions, Tessellator},
+    tessellator::{tessellate_shapes, tessellate_shapes_ref, TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
-    texture_atlas::TextureAtlas,
+    texture_atlas::{PreparedDisc, TextureAtlas},
     texture_handle::TextureHandle,
     textures::TextureManager,
 };
@@ -53,6 +57,9 @@ pub use emath::{pos2, vec2, Pos2, Rect, Vec2};
 pub use ahash;
 pub use emath;
 
+/// The version of epaint, e.g. for showing in an about panel.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
 #[cfg(feature = "color-hex")]
 pub use color_hex;
 
@@ -149,30 +149,104 @@ impl Mesh {
 
     /// Rectangle with a texture and color.
     pub fn add_rect_with_uv(&mut self, rect: Rect, uv: Rect, color: Color32) {
+        self.add_rect_with_uvs(
+            rect,
+            [
+                uv.left_top(),
+                uv.right_top(),
+                uv.left_bottom(),
+                uv.right_bottom(),
+            ],
+            color,
+        );
+    }
+
+    /// A "9-slice" textured rectangle, for drawing skinned frames and buttons from a single image.
+    ///
+    /// The `uv` region of the texture is cut into a 3x3 grid by `uv_border` (in texture coordinates).
+    /// The corners keep the size `border` (in points), the edges are stretched along one axis,
+    /// and the center is stretched to fill the rest of `rect`.
+    pub fn add_nine_slice(
+        &mut self,
+        rect: Rect,
+        uv: Rect,
+        border: Vec2,
+        uv_border: Vec2,
+        color: Color32,
+    ) {
+        let border = border.min(rect.size() * 0.5).max(Vec2::ZERO);
+        let uv_border = uv_border.min(uv.size() * 0.5).max(Vec2::ZERO);
+
+        let xs = [
+            rect.min.x,
+            rect.min.x + border.x,
+            rect.max.x - border.x,
+            rect.max.x,
+        ];
+        let ys = [
+            rect.min.y,
+            rect.min.y + border.y,
+            rect.max.y - border.y,
+            rect.max.y,
+        ];
+        let us = [
+            uv.min.x,
+            uv.min.x + uv_border.x,
+            uv.max.x - uv_border.x,
+            uv.max.x,
+        ];
+        let vs = [
+            uv.min.y,
+            uv.min.y + uv_border.y,
+            uv.max.y - uv_border.y,
+            uv.max.y,
+        ];
+
+        for row in 0..3 {
+            for col in 0..3 {
+                let slice =
+                    Rect::from_min_max(pos2(xs[col], ys[row]), pos2(xs[col + 1], ys[row + 1]));
+                if slice.width() > 0.0 && slice.height() > 0.0 {
+                    let slice_uv =
+                        Rect::from_min_max(pos2(us[col], vs[row]), pos2(us[col + 1], vs[row + 1]));
+                    self.add_rect_with_uv(slice, slice_uv, color);
+                }
+            }
+        }
+    }
+
+    /// Rectangle with an explicit texture coordinate for each corner.
+    ///
+    /// The corners are in the order: left-top, right-top, left-bottom, right-bottom.
+    ///
+    /// This can be used to show a texture rotated or flipped inside of an axis-aligned rectangle.
+    pub fn add_rect_with_uvs(&mut self, rect: Rect, uvs: [Pos2; 4], color: Color32) {
         #![allow(clippy::identity_op)]
 
+        let [uv_left_top, uv_right_top, uv_left_bottom, uv_right_bottom] = uvs;
+
         let idx = self.vertices.len() as u32;
         self.add_triangle(idx + 0, idx + 1, idx + 2);
         self.add_triangle(idx + 2, idx + 1, idx + 3);
 
         self.vertices.push(Vertex {
             pos: rect.left_top(),
-            uv: uv.left_top(),
+            uv: uv_left_top,
             color,
         });
         self.vertices.push(Vertex {
             pos: rect.right_top(),
-            uv: uv.right_top(),
+            uv: uv_right_top,
             color,
         });
         self.vertices.push(Vertex {
             pos: rect.left_bottom(),
-            uv: uv.left_bottom(),
+            uv: uv_left_bottom,
             color,
         });
         self.vertices.push(Vertex {
             pos: rect.right_bottom(),
-            uv: uv.right_bottom(),
+            uv: uv_right_bottom,
             color,
         });
     }
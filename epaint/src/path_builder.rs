@@ -0,0 +1,194 @@
+use crate::{Color32, CubicBezierShape, PathShape, QuadraticBezierShape, Shape, Stroke};
+use emath::*;
+
+/// Builds a general path out of line segments and Bézier curves,
+/// SVG-style, with `move_to`, `line_to`, `quadratic_to`, `cubic_to` and `close`.
+///
+/// The curves are flattened into line segments when the path is turned into a [`Shape`].
+///
+/// ```
+/// use epaint::{pos2, Color32, PathBuilder, Stroke};
+/// let mut path = PathBuilder::default();
+/// path.move_to(pos2(0.0, 0.0));
+/// path.line_to(pos2(100.0, 0.0));
+/// path.cubic_to(pos2(150.0, 0.0), pos2(150.0, 100.0), pos2(100.0, 100.0));
+/// path.close();
+/// let shape = path.stroke(Stroke::new(1.0, Color32::WHITE));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PathBuilder {
+    /// Each sub-path, and whether or not it is closed.
+    subpaths: Vec<(Vec<Pos2>, bool)>,
+
+    /// Max distance between the flattened curves and the true curves.
+    /// `None` means a sensible default.
+    tolerance: Option<f32>,
+}
+
+impl PathBuilder {
+    /// The default max distance between the flattened curves and the true curves, in points.
+    pub const DEFAULT_TOLERANCE: f32 = 0.1;
+
+    /// Max distance between the flattened curves and the true curves, in points.
+    ///
+    /// Default: [`Self::DEFAULT_TOLERANCE`].
+    #[inline]
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Is there nothing in this path?
+    pub fn is_empty(&self) -> bool {
+        self.subpaths.iter().all(|(points, _)| points.is_empty())
+    }
+
+    /// Start a new sub-path at the given point.
+    pub fn move_to(&mut self, pos: Pos2) {
+        self.subpaths.push((vec![pos], false));
+    }
+
+    /// Add a straight line from the current point.
+    ///
+    /// Starts a new sub-path if there is no current point.
+    pub fn line_to(&mut self, pos: Pos2) {
+        self.current_points().push(pos);
+    }
+
+    /// Add a quadratic Bézier curve from the current point to `end`.
+    pub fn quadratic_to(&mut self, control: Pos2, end: Pos2) {
+        let start = self.current_point().unwrap_or(control);
+        let curve = QuadraticBezierShape::from_points_stroke(
+            [start, control, end],
+            false,
+            Color32::TRANSPARENT,
+            Stroke::none(),
+        );
+        let mut points = Vec::new();
+        curve.for_each_flattened_with_t(self.flattening_tolerance(), &mut |pos, _t| {
+            points.push(pos);
+        });
+        self.extend_current(start, points);
+    }
+
+    /// Add a cubic Bézier curve from the current point to `end`.
+    pub fn cubic_to(&mut self, control_1: Pos2, control_2: Pos2, end: Pos2) {
+        let start = self.current_point().unwrap_or(control_1);
+        let curve = CubicBezierShape::from_points_stroke(
+            [start, control_1, control_2, end],
+            false,
+            Color32::TRANSPARENT,
+            Stroke::none(),
+        );
+        let mut points = Vec::new();
+        curve.for_each_flattened_with_t(self.flattening_tolerance(), &mut |pos, _t| {
+            points.push(pos);
+        });
+        self.extend_current(start, points);
+    }
+
+    /// Connect the current point back to the start of the current sub-path.
+    ///
+    /// The next segment will start a new sub-path.
+    pub fn close(&mut self) {
+        if let Some((points, closed)) = self.subpaths.last_mut() {
+            if !points.is_empty() {
+                *closed = true;
+            }
+        }
+    }
+
+    /// Turn the path into a shape with only an outline.
+    pub fn stroke(self, stroke: impl Into<Stroke>) -> Shape {
+        self.into_shape(Color32::TRANSPARENT, stroke)
+    }
+
+    /// Turn the path into a shape.
+    ///
+    /// Only the closed sub-paths are filled,
+    /// and just like with [`PathShape`] the filling is only correct for convex sub-paths.
+    pub fn into_shape(self, fill: Color32, stroke: impl Into<Stroke>) -> Shape {
+        let stroke = stroke.into();
+        Shape::Vec(
+            self.subpaths
+                .into_iter()
+                .filter(|(points, _)| points.len() >= 2)
+                .map(|(points, closed)| {
+                    Shape::Path(PathShape {
+                        points,
+                        closed,
+                        fill: if closed { fill } else { Color32::TRANSPARENT },
+                        stroke,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn flattening_tolerance(&self) -> f32 {
+        self.tolerance.unwrap_or(Self::DEFAULT_TOLERANCE)
+    }
+
+    fn current_point(&self) -> Option<Pos2> {
+        match self.subpaths.last() {
+            Some((points, false)) => points.last().copied(),
+            _ => None,
+        }
+    }
+
+    /// The points of the current (open) sub-path, starting a new one if needed.
+    fn current_points(&mut self) -> &mut Vec<Pos2> {
+        if !matches!(self.subpaths.last(), Some((_, false))) {
+            self.subpaths.push((vec![], false));
+        }
+        &mut self.subpaths.last_mut().unwrap().0
+    }
+
+    fn extend_current(&mut self, start: Pos2, points: Vec<Pos2>) {
+        let current = self.current_points();
+        if current.is_empty() {
+            current.push(start);
+        }
+        current.extend(points);
+    }
+}
+
+#[test]
+fn test_path_builder() {
+    let mut path = PathBuilder::default();
+    assert!(path.is_empty());
+    path.move_to(pos2(0.0, 0.0));
+    path.line_to(pos2(10.0, 0.0));
+    path.quadratic_to(pos2(20.0, 0.0), pos2(20.0, 10.0));
+    path.close();
+    path.move_to(pos2(50.0, 50.0));
+    path.cubic_to(pos2(60.0, 50.0), pos2(60.0, 60.0), pos2(50.0, 60.0));
+
+    let shapes = match path.into_shape(Color32::RED, Stroke::none()) {
+        Shape::Vec(shapes) => shapes,
+        _ => panic!("Expected a Shape::Vec"),
+    };
+    assert_eq!(shapes.len(), 2);
+
+    match &shapes[0] {
+        Shape::Path(path) => {
+            assert!(path.closed);
+            assert_eq!(path.fill, Color32::RED);
+            assert_eq!(path.points[0], pos2(0.0, 0.0));
+            assert_eq!(path.points[1], pos2(10.0, 0.0));
+            assert!(path.points.len() > 3);
+            assert!(path.points.last().unwrap().distance(pos2(20.0, 10.0)) < 1e-3);
+        }
+        _ => panic!("Expected a Shape::Path"),
+    }
+
+    match &shapes[1] {
+        Shape::Path(path) => {
+            assert!(!path.closed);
+            assert_eq!(path.fill, Color32::TRANSPARENT);
+            assert_eq!(path.points[0], pos2(50.0, 50.0));
+            assert!(path.points.last().unwrap().distance(pos2(50.0, 60.0)) < 1e-3);
+        }
+        _ => panic!("Expected a Shape::Path"),
+    }
+}
@@ -0,0 +1,310 @@
+//! Paint the output of the [`crate::Tessellator`] on the CPU, e.g. for tests and screenshots without a GPU.
+
+use crate::{
+    textures::{TextureFilter, TextureOptions, TextureWrapMode},
+    ClippedPrimitive, Color32, ColorImage, ImageData, ImageDelta, Mesh, Primitive, TextureId,
+};
+use emath::{pos2, Pos2, Rect};
+
+/// Paints [`ClippedPrimitive`]s into a [`ColorImage`] on the CPU.
+///
+/// Just like a GPU backend it needs to be told about the textures,
+/// with [`Self::set_texture`] and [`Self::free_texture`].
+///
+/// It blends in gamma space, so the result is close to (but not exactly the same as)
+/// what the GPU backends paint. It is also slow, and skips [`crate::PaintCallback`]s.
+///
+/// ```
+/// # use epaint::*;
+/// let mut rasterizer = epaint::Rasterizer::default();
+/// let fonts_delta = epaint::ImageDelta::full(
+///     ColorImage::new([1, 1], Color32::WHITE),
+///     epaint::textures::TextureOptions::LINEAR,
+/// );
+/// rasterizer.set_texture(TextureId::default(), &fonts_delta);
+///
+/// let mut mesh = Mesh::default();
+/// mesh.add_colored_rect(Rect::from_min_max(pos2(2.0, 2.0), pos2(6.0, 6.0)), Color32::RED);
+/// let primitives = [ClippedPrimitive {
+///     clip_rect: Rect::EVERYTHING,
+///     primitive: Primitive::Mesh(mesh),
+/// }];
+///
+/// let image = rasterizer.paint([8, 8], 1.0, Color32::BLACK, &primitives);
+/// assert_eq!(image[(1, 1)], Color32::BLACK);
+/// assert_eq!(image[(3, 3)], Color32::RED);
+/// ```
+#[derive(Default)]
+pub struct Rasterizer {
+    textures: ahash::AHashMap<TextureId, (ColorImage, TextureOptions)>,
+}
+
+impl Rasterizer {
+    /// Create or update a texture, like a backend does with [`crate::TexturesDelta::set`].
+    pub fn set_texture(&mut self, tex_id: TextureId, delta: &ImageDelta) {
+        let image = match &delta.image {
+            ImageData::Color(image) => image.clone(),
+            ImageData::Font(image) => ColorImage {
+                size: image.size,
+                pixels: image.srgba_pixels(1.0).collect(),
+            },
+        };
+
+        if let Some([x, y]) = delta.pos {
+            if let Some((texture, options)) = self.textures.get_mut(&tex_id) {
+                *options = delta.options;
+                let [w, h] = image.size;
+                if texture.width() < x + w || texture.height() < y + h {
+                    return; // a patch outside of the texture
+                }
+                for row in 0..h {
+                    let start = (y + row) * texture.width() + x;
+                    texture.pixels[start..start + w]
+                        .copy_from_slice(&image.pixels[row * w..(row + 1) * w]);
+                }
+            }
+        } else {
+            self.textures.insert(tex_id, (image, delta.options));
+        }
+    }
+
+    /// Forget a texture, like a backend does with [`crate::TexturesDelta::free`].
+    pub fn free_texture(&mut self, tex_id: TextureId) {
+        self.textures.remove(&tex_id);
+    }
+
+    /// Paint the primitives into a new image of `size_px` pixels, which is first filled with `clear_color`.
+    pub fn paint(
+        &self,
+        size_px: [usize; 2],
+        pixels_per_point: f32,
+        clear_color: Color32,
+        clipped_primitives: &[ClippedPrimitive],
+    ) -> ColorImage {
+        let mut target = ColorImage::new(size_px, clear_color);
+        let screen_rect =
+            Rect::from_min_max(Pos2::ZERO, pos2(size_px[0] as f32, size_px[1] as f32));
+
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in clipped_primitives
+        {
+            if let Primitive::Mesh(mesh) = primitive {
+                // Round the clip rectangle to whole pixels, like a scissor rectangle:
+                let clip_rect = Rect::from_min_max(
+                    (pixels_per_point * clip_rect.min.to_vec2())
+                        .to_pos2()
+                        .round(),
+                    (pixels_per_point * clip_rect.max.to_vec2())
+                        .to_pos2()
+                        .round(),
+                )
+                .intersect(screen_rect);
+                if clip_rect.is_positive() {
+                    self.paint_mesh(&mut target, clip_rect, pixels_per_point, mesh);
+                }
+            }
+        }
+
+        target
+    }
+
+    fn paint_mesh(
+        &self,
+        target: &mut ColorImage,
+        clip_rect: Rect,
+        pixels_per_point: f32,
+        mesh: &Mesh,
+    ) {
+        let (texture, options) = match self.textures.get(&mesh.texture_id) {
+            Some((texture, options)) => (texture, *options),
+            None => return, // like a backend, we can't paint without the texture
+        };
+
+        for indices in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| {
+                let vertex = &mesh.vertices[indices[i] as usize];
+                Corner {
+                    pos: (pixels_per_point * vertex.pos.to_vec2()).to_pos2(),
+                    uv: vertex.uv,
+                    color: vertex.color,
+                }
+            });
+            paint_triangle(target, clip_rect, texture, options, [a, b, c]);
+        }
+    }
+}
+
+/// A triangle corner, in pixels.
+struct Corner {
+    pos: Pos2,
+    uv: Pos2,
+    color: Color32,
+}
+
+fn paint_triangle(
+    target: &mut ColorImage,
+    clip_rect: Rect,
+    texture: &ColorImage,
+    options: TextureOptions,
+    [a, b, c]: [Corner; 3],
+) {
+    // Make the winding the same for all triangles, so that the fill rule below works:
+    let (b, c) = if edge(a.pos, b.pos, c.pos) < 0.0 {
+        (c, b)
+    } else {
+        (b, c)
+    };
+    let area = edge(a.pos, b.pos, c.pos);
+    if area <= 0.0 {
+        return; // degenerate
+    }
+
+    let bounds = Rect::from_min_max(
+        a.pos.min(b.pos).min(c.pos).floor(),
+        a.pos.max(b.pos).max(c.pos).ceil(),
+    )
+    .intersect(clip_rect);
+    if !bounds.is_positive() {
+        return;
+    }
+
+    for y in bounds.min.y as usize..bounds.max.y as usize {
+        for x in bounds.min.x as usize..bounds.max.x as usize {
+            let p = pos2(x as f32 + 0.5, y as f32 + 0.5);
+
+            // Barycentric weights:
+            let wa = edge(b.pos, c.pos, p);
+            let wb = edge(c.pos, a.pos, p);
+            let wc = edge(a.pos, b.pos, p);
+            if !covers(wa, b.pos, c.pos) || !covers(wb, c.pos, a.pos) || !covers(wc, a.pos, b.pos) {
+                continue;
+            }
+            let [wa, wb, wc] = [wa / area, wb / area, wc / area];
+
+            let uv = pos2(
+                wa * a.uv.x + wb * b.uv.x + wc * c.uv.x,
+                wa * a.uv.y + wb * b.uv.y + wc * c.uv.y,
+            );
+            let texel = sample(texture, options, uv);
+
+            let mut src = [0_u8; 4];
+            for (i, src) in src.iter_mut().enumerate() {
+                let vertex_color =
+                    wa * a.color[i] as f32 + wb * b.color[i] as f32 + wc * c.color[i] as f32;
+                *src = (vertex_color * texel[i] / 255.0).round().clamp(0.0, 255.0) as u8;
+            }
+
+            // Premultiplied alpha blending:
+            let dst = &mut target[(x, y)];
+            let mut blended = [0_u8; 4];
+            for (i, blended) in blended.iter_mut().enumerate() {
+                let value = src[i] as f32 + dst[i] as f32 * (1.0 - src[3] as f32 / 255.0);
+                *blended = value.round().min(255.0) as u8;
+            }
+            *dst = Color32::from_rgba_premultiplied(blended[0], blended[1], blended[2], blended[3]);
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a, b, p`.
+fn edge(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+/// Is a pixel with the given weight for the edge `a -> b` inside the triangle?
+///
+/// Pixels exactly on an edge shared by two triangles belong to only one of them,
+/// so they are not blended twice.
+fn covers(weight: f32, a: Pos2, b: Pos2) -> bool {
+    let d = b - a;
+    weight > 0.0 || (weight == 0.0 && (d.y < 0.0 || (d.y == 0.0 && d.x > 0.0)))
+}
+
+/// Sample the texture at `uv`, returning premultiplied color in `0..=255`.
+fn sample(texture: &ColorImage, options: TextureOptions, uv: Pos2) -> [f32; 4] {
+    let [w, h] = texture.size;
+    if w == 0 || h == 0 {
+        return [0.0; 4];
+    }
+
+    let x = uv.x * w as f32;
+    let y = uv.y * h as f32;
+    match options.filter {
+        TextureFilter::Nearest => {
+            let x = wrap(x.floor() as isize, w, options.wrap_mode);
+            let y = wrap(y.floor() as isize, h, options.wrap_mode);
+            texture[(x, y)].to_array().map(|c| c as f32)
+        }
+        TextureFilter::Linear => {
+            let (x, y) = (x - 0.5, y - 0.5);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (tx, ty) = (x - x0, y - y0);
+            let texel = |dx: isize, dy: isize| {
+                let x = wrap(x0 as isize + dx, w, options.wrap_mode);
+                let y = wrap(y0 as isize + dy, h, options.wrap_mode);
+                texture[(x, y)].to_array().map(|c| c as f32)
+            };
+            let [t00, t10, t01, t11] = [texel(0, 0), texel(1, 0), texel(0, 1), texel(1, 1)];
+            let mut color = [0.0; 4];
+            for (i, color) in color.iter_mut().enumerate() {
+                let top = t00[i] + tx * (t10[i] - t00[i]);
+                let bottom = t01[i] + tx * (t11[i] - t01[i]);
+                *color = top + ty * (bottom - top);
+            }
+            color
+        }
+    }
+}
+
+/// Map a texel coordinate into `0..size`.
+fn wrap(i: isize, size: usize, wrap_mode: TextureWrapMode) -> usize {
+    let size = size as isize;
+    let i = match wrap_mode {
+        TextureWrapMode::ClampToEdge => i.clamp(0, size - 1),
+        TextureWrapMode::Repeat => i.rem_euclid(size),
+        TextureWrapMode::MirroredRepeat => {
+            let i = i.rem_euclid(2 * size);
+            if i < size {
+                i
+            } else {
+                2 * size - 1 - i
+            }
+        }
+    };
+    i as usize
+}
+
+#[test]
+fn test_rasterizer_shared_edges() {
+    // Two halves of a half-transparent rectangle must not be blended twice along the diagonal:
+    let mut rasterizer = Rasterizer::default();
+    rasterizer.set_texture(
+        TextureId::default(),
+        &ImageDelta::full(
+            ColorImage::new([1, 1], Color32::WHITE),
+            TextureOptions::NEAREST,
+        ),
+    );
+
+    let color = Color32::from_rgba_premultiplied(50, 50, 50, 128);
+    let mut mesh = Mesh::default();
+    mesh.add_colored_rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(8.0, 8.0)), color);
+    let primitives = [ClippedPrimitive {
+        clip_rect: Rect::from_min_max(pos2(0.0, 0.0), pos2(6.0, 8.0)),
+        primitive: Primitive::Mesh(mesh),
+    }];
+
+    let image = rasterizer.paint([8, 8], 1.0, Color32::BLACK, &primitives);
+    for y in 0..8 {
+        for x in 0..8 {
+            let expected = if x < 6 {
+                Color32::from_rgb(50, 50, 50)
+            } else {
+                Color32::BLACK
+            };
+            assert_eq!(image[(x, y)], expected, "pixel {} {}", x, y);
+        }
+    }
+}
@@ -319,6 +319,87 @@ impl Shape {
             }
         }
     }
+
+    /// Rotate (and scale, if `rot` is not normalized) the shape about `origin`, in-place.
+    ///
+    /// Combine with [`Self::translate`] for a full 2D transform of a group of shapes (in a [`Shape::Vec`]).
+    ///
+    /// Rectangles are turned into [`PathShape`]s.
+    /// Text is rotated but not scaled,
+    /// and the rect of a [`PaintCallback`] becomes the bounding rect of the transformed rect.
+    pub fn rotate(&mut self, rot: Rot2, origin: Pos2) {
+        let transform = |p: Pos2| origin + rot * (p - origin);
+        let scale = rot.length();
+
+        match self {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    shape.rotate(rot, origin);
+                }
+            }
+            Shape::Circle(circle_shape) => {
+                circle_shape.center = transform(circle_shape.center);
+                circle_shape.radius *= scale;
+                circle_shape.stroke.width *= scale;
+            }
+            Shape::LineSegment { points, stroke } => {
+                for p in points {
+                    *p = transform(*p);
+                }
+                stroke.width *= scale;
+            }
+            Shape::Path(path_shape) => {
+                for p in &mut path_shape.points {
+                    *p = transform(*p);
+                }
+                path_shape.stroke.width *= scale;
+            }
+            Shape::Rect(rect_shape) => {
+                let mut points = vec![];
+                crate::tessellator::path::rounded_rectangle(
+                    &mut points,
+                    rect_shape.rect,
+                    rect_shape.rounding,
+                );
+                let mut path_shape =
+                    PathShape::convex_polygon(points, rect_shape.fill, rect_shape.stroke);
+                for p in &mut path_shape.points {
+                    *p = transform(*p);
+                }
+                path_shape.stroke.width *= scale;
+                *self = Shape::Path(path_shape);
+            }
+            Shape::Text(text_shape) => {
+                text_shape.pos = transform(text_shape.pos);
+                text_shape.angle += rot.angle();
+            }
+            Shape::Mesh(mesh) => {
+                mesh.rotate(rot, origin);
+            }
+            Shape::QuadraticBezier(bezier_shape) => {
+                for p in &mut bezier_shape.points {
+                    *p = transform(*p);
+                }
+                bezier_shape.stroke.width *= scale;
+            }
+            Shape::CubicBezier(cubie_curve) => {
+                for p in &mut cubie_curve.points {
+                    *p = transform(*p);
+                }
+                cubie_curve.stroke.width *= scale;
+            }
+            Shape::Callback(shape) => {
+                let r = shape.rect;
+                shape.rect = Rect::from_points(&[
+                    transform(r.left_top()),
+                    transform(r.right_top()),
+                    transform(r.left_bottom()),
+                    transform(r.right_bottom()),
+                ]);
+            }
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -782,6 +863,10 @@ impl PaintCallbackInfo {
 /// If you want to paint some 3D shapes inside an egui region, you can use this.
 ///
 /// This is advanced usage, and is backend specific.
+/// The callback is called in order with the other shapes, so it is clipped and layered
+/// like any other shape (e.g. when painted inside an `egui::Window`).
+///
+/// See the `custom_3d_glow` and `custom_3d_three-d` examples.
 #[derive(Clone)]
 pub struct PaintCallback {
     /// Where to paint.
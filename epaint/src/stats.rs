@@ -174,6 +174,12 @@ pub struct PaintStats {
     pub clipped_primitives: AllocInfo,
     pub vertices: AllocInfo,
     pub indices: AllocInfo,
+
+    /// With a tessellation cache: the number of layers that had to be tessellated.
+    pub num_layers_tessellated: usize,
+
+    /// With a tessellation cache: the number of layers whose tessellation from last frame was reused.
+    pub num_layers_reused: usize,
 }
 
 impl PaintStats {
@@ -336,38 +336,57 @@ impl Path {
         self.0.push(PathPoint { pos, normal });
     }
 
+    /// Add a circle, with as many vertices as needed to look smooth on a high-dpi display.
+    ///
+    /// See also [`Self::add_circle_with_pixels_per_point`].
     pub fn add_circle(&mut self, center: Pos2, radius: f32) {
-        use precomputed_vertices::*;
+        // The cutoffs were originally tuned for a high-dpi display:
+        self.add_circle_with_pixels_per_point(center, radius, 2.0);
+    }
 
-        // These cutoffs are based on a high-dpi display. TODO(emilk): use pixels_per_point here?
-        // same cutoffs as in add_circle_quadrant
+    /// Add a circle, with enough vertices to look smooth at the given resolution,
+    /// but without wasting vertices on small circles.
+    pub fn add_circle_with_pixels_per_point(
+        &mut self,
+        center: Pos2,
+        radius: f32,
+        pixels_per_point: f32,
+    ) {
+        use precomputed_vertices::*;
 
-        if radius <= 2.0 {
-            self.0.extend(CIRCLE_8.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else if radius <= 5.0 {
-            self.0.extend(CIRCLE_16.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else if radius < 18.0 {
-            self.0.extend(CIRCLE_32.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        } else if radius < 50.0 {
-            self.0.extend(CIRCLE_64.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
+        // same cutoffs as in add_circle_quadrant (on a high-dpi display)
+        let radius_px = radius * pixels_per_point;
+        let normals: &[Vec2] = if radius_px <= 4.0 {
+            &CIRCLE_8
+        } else if radius_px <= 10.0 {
+            &CIRCLE_16
+        } else if radius_px < 36.0 {
+            &CIRCLE_32
+        } else if radius_px < 100.0 {
+            &CIRCLE_64
+        } else if radius_px < 400.0 || !radius_px.is_finite() {
+            &CIRCLE_128
         } else {
-            self.0.extend(CIRCLE_128.iter().map(|&n| PathPoint {
-                pos: center + radius * n,
-                normal: n,
-            }));
-        }
+            // Huge circle: pick the number of vertices so that the
+            // distance between the polygon and the true circle is at most ~0.1 pixels,
+            // but don't go overboard for circles that are mostly off-screen anyway.
+            const MAX_VERTICES: usize = 1024;
+            let n = (std::f32::consts::PI * (radius_px / 0.2).sqrt()).ceil() as usize;
+            let n = n.min(MAX_VERTICES);
+            // The path is closed, so we must not repeat the first point at the end:
+            self.reserve(n);
+            for i in 0..n {
+                let angle = std::f32::consts::TAU * i as f32 / n as f32;
+                let normal = Vec2::angled(angle);
+                self.add_point(center + radius * normal, normal);
+            }
+            return;
+        };
+
+        self.0.extend(normals.iter().map(|&n| PathPoint {
+            pos: center + radius * n,
+            normal: n,
+        }));
     }
 
     pub fn add_line_segment(&mut self, points: [Pos2; 2]) {
@@ -1033,34 +1052,88 @@ impl Tessellator {
         clipped_shape: ClippedShape,
         out_primitives: &mut Vec<ClippedPrimitive>,
     ) {
-        let ClippedShape(new_clip_rect, new_shape) = clipped_shape;
-
-        if !new_clip_rect.is_positive() {
-            return; // skip empty clip rectangles
+        let ClippedShape(clip_rect, shape) = clipped_shape;
+        match shape {
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.tessellate_clipped_shape(ClippedShape(clip_rect, shape), out_primitives);
+                }
+            }
+            Shape::Mesh(_) => {
+                // Move the mesh instead of copying it:
+                if let Some(out_mesh) = self.out_mesh(clip_rect, &shape, out_primitives) {
+                    self.tessellate_shape(shape, out_mesh);
+                }
+            }
+            shape => self.tessellate_shape_clipped(clip_rect, &shape, out_primitives),
         }
+    }
 
-        if let Shape::Vec(shapes) = new_shape {
-            for shape in shapes {
-                self.tessellate_clipped_shape(ClippedShape(new_clip_rect, shape), out_primitives);
+    /// Like [`Self::tessellate_clipped_shape`], but without taking ownership of the shape.
+    pub fn tessellate_clipped_shape_ref(
+        &mut self,
+        clipped_shape: &ClippedShape,
+        out_primitives: &mut Vec<ClippedPrimitive>,
+    ) {
+        let ClippedShape(clip_rect, shape) = clipped_shape;
+        self.tessellate_shape_clipped(*clip_rect, shape, out_primitives);
+    }
+
+    fn tessellate_shape_clipped(
+        &mut self,
+        clip_rect: Rect,
+        shape: &Shape,
+        out_primitives: &mut Vec<ClippedPrimitive>,
+    ) {
+        match shape {
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.tessellate_shape_clipped(clip_rect, shape, out_primitives);
+                }
+            }
+            Shape::Callback(callback) => {
+                if !clip_rect.is_positive()
+                    || self.options.coarse_tessellation_culling && is_outside(shape, clip_rect)
+                {
+                    return;
+                }
+                out_primitives.push(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::Callback(callback.clone()),
+                });
+            }
+            _ => {
+                if let Some(out_mesh) = self.out_mesh(clip_rect, shape, out_primitives) {
+                    self.tessellate_shape_ref(shape, out_mesh);
+                }
             }
-            return;
         }
+    }
 
-        if let Shape::Callback(callback) = new_shape {
-            out_primitives.push(ClippedPrimitive {
-                clip_rect: new_clip_rect,
-                primitive: Primitive::Callback(callback),
-            });
-            return;
+    /// The mesh to append the triangles of `shape` to,
+    /// or `None` if `shape` is clipped away.
+    fn out_mesh<'a>(
+        &mut self,
+        clip_rect: Rect,
+        shape: &Shape,
+        out_primitives: &'a mut Vec<ClippedPrimitive>,
+    ) -> Option<&'a mut Mesh> {
+        if !clip_rect.is_positive() {
+            return None; // skip empty clip rectangles
+        }
+
+        if self.options.coarse_tessellation_culling && is_outside(shape, clip_rect) {
+            // Skip it before we start a new mesh, so that the shapes around it can share a mesh.
+            return None;
         }
 
         let start_new_mesh = match out_primitives.last() {
             None => true,
             Some(output_clipped_primitive) => {
-                output_clipped_primitive.clip_rect != new_clip_rect
+                output_clipped_primitive.clip_rect != clip_rect
                     || match &output_clipped_primitive.primitive {
                         Primitive::Mesh(output_mesh) => {
-                            output_mesh.texture_id != new_shape.texture_id()
+                            output_mesh.texture_id != shape.texture_id()
                         }
                         Primitive::Callback(_) => true,
                     }
@@ -1069,18 +1142,16 @@ impl Tessellator {
 
         if start_new_mesh {
             out_primitives.push(ClippedPrimitive {
-                clip_rect: new_clip_rect,
+                clip_rect,
                 primitive: Primitive::Mesh(Mesh::default()),
             });
         }
 
-        let out = out_primitives.last_mut().unwrap();
+        self.clip_rect = clip_rect;
 
-        if let Primitive::Mesh(out_mesh) = &mut out.primitive {
-            self.clip_rect = new_clip_rect;
-            self.tessellate_shape(new_shape, out_mesh);
-        } else {
-            unreachable!();
+        match &mut out_primitives.last_mut().unwrap().primitive {
+            Primitive::Mesh(out_mesh) => Some(out_mesh),
+            Primitive::Callback(_) => unreachable!(),
         }
     }
 
@@ -1092,15 +1163,11 @@ impl Tessellator {
     /// * `out`: triangles are appended to this.
     pub fn tessellate_shape(&mut self, shape: Shape, out: &mut Mesh) {
         match shape {
-            Shape::Noop => {}
             Shape::Vec(vec) => {
                 for shape in vec {
                     self.tessellate_shape(shape, out);
                 }
             }
-            Shape::Circle(circle) => {
-                self.tessellate_circle(circle, out);
-            }
             Shape::Mesh(mesh) => {
                 if !mesh.is_valid() {
                     crate::epaint_assert!(false, "Invalid Mesh in Shape::Mesh");
@@ -1114,12 +1181,31 @@ impl Tessellator {
                 }
                 out.append(mesh);
             }
-            Shape::LineSegment { points, stroke } => self.tessellate_line(points, stroke, out),
+            shape => self.tessellate_shape_ref(&shape, out),
+        }
+    }
+
+    /// Like [`Self::tessellate_shape`], but without taking ownership of the shape.
+    pub fn tessellate_shape_ref(&mut self, shape: &Shape, out: &mut Mesh) {
+        match shape {
+            Shape::Noop => {}
+            Shape::Vec(vec) => {
+                for shape in vec {
+                    self.tessellate_shape_ref(shape, out);
+                }
+            }
+            Shape::Circle(circle) => {
+                self.tessellate_circle(*circle, out);
+            }
+            Shape::Mesh(mesh) => {
+                self.tessellate_mesh(mesh, out);
+            }
+            Shape::LineSegment { points, stroke } => self.tessellate_line(*points, *stroke, out),
             Shape::Path(path_shape) => {
-                self.tessellate_path(&path_shape, out);
+                self.tessellate_path(path_shape, out);
             }
             Shape::Rect(rect_shape) => {
-                self.tessellate_rect(&rect_shape, out);
+                self.tessellate_rect(rect_shape, out);
             }
             Shape::Text(text_shape) => {
                 if self.options.debug_paint_text_rects {
@@ -1129,12 +1215,12 @@ impl Tessellator {
                         out,
                     );
                 }
-                self.tessellate_text(&text_shape, out);
+                self.tessellate_text(text_shape, out);
             }
             Shape::QuadraticBezier(quadratic_shape) => {
-                self.tessellate_quadratic_bezier(quadratic_shape, out);
+                self.tessellate_quadratic_bezier(*quadratic_shape, out);
             }
-            Shape::CubicBezier(cubic_shape) => self.tessellate_cubic_bezier(cubic_shape, out),
+            Shape::CubicBezier(cubic_shape) => self.tessellate_cubic_bezier(*cubic_shape, out),
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
@@ -1191,7 +1277,11 @@ impl Tessellator {
         }
 
         self.scratchpad_path.clear();
-        self.scratchpad_path.add_circle(center, radius);
+        self.scratchpad_path.add_circle_with_pixels_per_point(
+            center,
+            radius,
+            self.pixels_per_point,
+        );
         self.scratchpad_path.fill(self.feathering, fill, out);
         self.scratchpad_path
             .stroke_closed(self.feathering, stroke, out);
@@ -1504,6 +1594,28 @@ impl Tessellator {
     }
 }
 
+/// Is the shape certainly outside the clip rectangle, so that it can be skipped?
+fn is_outside(shape: &Shape, clip_rect: Rect) -> bool {
+    match shape {
+        // Calculating the bounds of these is as expensive as tessellating them,
+        // and they are culled in `tessellate_shape` anyway:
+        Shape::Noop | Shape::Vec(_) | Shape::Mesh(_) => false,
+        Shape::Text(text_shape) => {
+            if text_shape.angle != 0.0 {
+                false // `visual_bounding_rect` does not take the rotation into account
+            } else {
+                let rect = text_shape
+                    .visual_bounding_rect()
+                    .union(text_shape.galley.rect.translate(text_shape.pos.to_vec2()))
+                    .expand(text_shape.underline.width);
+                !clip_rect.intersects(rect)
+            }
+        }
+        Shape::Callback(callback) => !clip_rect.intersects(callback.rect),
+        _ => !clip_rect.intersects(shape.visual_bounding_rect()),
+    }
+}
+
 /// Turns [`Shape`]:s into sets of triangles.
 ///
 /// The given shapes will tessellated in the same order as they are given.
@@ -1535,8 +1647,37 @@ pub fn tessellate_shapes(
         tessellator.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
     }
 
+    finish_primitives(&mut tessellator, clipped_primitives)
+}
+
+/// Like [`tessellate_shapes`], but without taking ownership of the shapes.
+pub fn tessellate_shapes_ref(
+    pixels_per_point: f32,
+    options: TessellationOptions,
+    font_tex_size: [usize; 2],
+    prepared_discs: Vec<PreparedDisc>,
+    shapes: &[ClippedShape],
+) -> Vec<ClippedPrimitive> {
+    let mut tessellator =
+        Tessellator::new(pixels_per_point, options, font_tex_size, prepared_discs);
+
+    let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
+
+    for clipped_shape in shapes {
+        tessellator.tessellate_clipped_shape_ref(clipped_shape, &mut clipped_primitives);
+    }
+
+    finish_primitives(&mut tessellator, clipped_primitives)
+}
+
+fn finish_primitives(
+    tessellator: &mut Tessellator,
+    mut clipped_primitives: Vec<ClippedPrimitive>,
+) -> Vec<ClippedPrimitive> {
+    let options = tessellator.options;
+
     if options.debug_paint_clip_rects {
-        clipped_primitives = add_clip_rects(&mut tessellator, clipped_primitives);
+        clipped_primitives = add_clip_rects(tessellator, clipped_primitives);
     }
 
     if options.debug_ignore_clip_rects {
@@ -1621,3 +1762,56 @@ fn test_tessellator() {
     );
     assert_eq!(primitives.len(), 2);
 }
+
+#[test]
+fn test_tessellator_culling() {
+    use crate::*;
+
+    let screen = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let scroll_area = Rect::from_min_max(pos2(0.0, 0.0), pos2(50.0, 50.0));
+
+    let visible = Shape::rect_filled(
+        Rect::from_min_size(pos2(10.0, 10.0), vec2(5.0, 5.0)),
+        0.0,
+        Color32::RED,
+    );
+    let hidden = Shape::rect_filled(
+        Rect::from_min_size(pos2(10.0, 80.0), vec2(5.0, 5.0)),
+        0.0,
+        Color32::RED,
+    );
+
+    let clipped_shapes = vec![
+        ClippedShape(screen, visible.clone()),
+        ClippedShape(scroll_area, hidden),
+        ClippedShape(screen, visible),
+    ];
+
+    let primitives = tessellate_shapes(
+        1.0,
+        Default::default(),
+        [1024, 1024],
+        vec![],
+        clipped_shapes,
+    );
+    assert_eq!(
+        primitives.len(),
+        1,
+        "The culled shape should not split the mesh"
+    );
+}
+
+#[test]
+fn test_huge_circles() {
+    use crate::*;
+
+    let mut path = Path::default();
+    path.add_circle_with_pixels_per_point(Pos2::ZERO, 1e9, 1.0);
+    assert_eq!(path.0.len(), 1024);
+
+    for radius in [f32::INFINITY, f32::NAN] {
+        path.clear();
+        path.add_circle_with_pixels_per_point(Pos2::ZERO, radius, 1.0);
+        assert_eq!(path.0.len(), 129);
+    }
+}
@@ -222,6 +222,8 @@ pub struct Font {
     pixels_per_point: f32,
     row_height: f32,
     glyph_info_cache: AHashMap<char, (FontIndex, GlyphInfo)>,
+    /// Characters that none of the fonts have, and which are shown as the replacement glyph.
+    missing_chars: BTreeSet<char>,
 }
 
 impl Font {
@@ -234,6 +236,7 @@ impl Font {
                 pixels_per_point: 1.0,
                 row_height: 0.0,
                 glyph_info_cache: Default::default(),
+                missing_chars: Default::default(),
             };
         }
 
@@ -247,6 +250,7 @@ impl Font {
             pixels_per_point,
             row_height,
             glyph_info_cache: Default::default(),
+            missing_chars: Default::default(),
         };
 
         const PRIMARY_REPLACEMENT_CHAR: char = '◻'; // white medium square
@@ -288,6 +292,13 @@ impl Font {
         })
     }
 
+    /// Characters that were asked for, but that none of the fonts have.
+    ///
+    /// These are shown as a replacement glyph (`◻`).
+    pub fn missing_chars(&self) -> &BTreeSet<char> {
+        &self.missing_chars
+    }
+
     #[inline(always)]
     pub fn round_to_pixel(&self, point: f32) -> f32 {
         (point * self.pixels_per_point).round() / self.pixels_per_point
@@ -318,6 +329,9 @@ impl Font {
         }
 
         let font_index_glyph_info = self.glyph_info_no_cache_or_fallback(c);
+        if font_index_glyph_info.is_none() && !self.fonts.is_empty() && !c.is_control() {
+            self.missing_chars.insert(c);
+        }
         let font_index_glyph_info = font_index_glyph_info.unwrap_or(self.replacement_glyph);
         self.glyph_info_cache.insert(c, font_index_glyph_info);
         font_index_glyph_info
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
 use crate::{
@@ -466,6 +466,19 @@ impl Fonts {
         self.lock().fonts.atlas.lock().fill_ratio()
     }
 
+    /// Characters that were laid out, but that none of the fonts have.
+    ///
+    /// These are shown as a replacement glyph (`◻`).
+    /// Add a font that has them to the [`FontDefinitions`] to fix it.
+    pub fn missing_chars(&self) -> BTreeSet<char> {
+        self.lock()
+            .fonts
+            .sized_family
+            .values()
+            .flat_map(|font| font.missing_chars().iter().copied())
+            .collect()
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// The implementation uses memoization so repeated calls are cheap.
@@ -1,6 +1,6 @@
 use emath::{remap_clamp, Rect};
 
-use crate::{textures::TextureFilter, FontImage, ImageDelta};
+use crate::{textures::TextureOptions, FontImage, ImageDelta};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 struct Rectu {
@@ -172,18 +172,25 @@ impl TextureAtlas {
         }
     }
 
+    /// Mark the whole image as dirty, so that the next [`Self::take_delta`] returns all of it.
+    ///
+    /// Useful if the backend lost its copy of the texture.
+    pub fn invalidate(&mut self) {
+        self.dirty = Rectu::EVERYTHING;
+    }
+
     /// Call to get the change to the image since last call.
     pub fn take_delta(&mut self) -> Option<ImageDelta> {
         let dirty = std::mem::replace(&mut self.dirty, Rectu::NOTHING);
         if dirty == Rectu::NOTHING {
             None
         } else if dirty == Rectu::EVERYTHING {
-            Some(ImageDelta::full(self.image.clone(), TextureFilter::Linear))
+            Some(ImageDelta::full(self.image.clone(), TextureOptions::LINEAR))
         } else {
             let pos = [dirty.min_x, dirty.min_y];
             let size = [dirty.max_x - dirty.min_x, dirty.max_y - dirty.min_y];
             let region = self.image.region(pos, size);
-            Some(ImageDelta::partial(pos, region, TextureFilter::Linear))
+            Some(ImageDelta::partial(pos, region, TextureOptions::LINEAR))
         }
     }
 
@@ -27,7 +27,7 @@ impl TextureManager {
     /// MUST have a white pixel at (0,0) ([`crate::WHITE_UV`]).
     ///
     /// The texture is given a retain-count of `1`, requiring one call to [`Self::free`] to free it.
-    pub fn alloc(&mut self, name: String, image: ImageData, filter: TextureFilter) -> TextureId {
+    pub fn alloc(&mut self, name: String, image: ImageData, options: TextureOptions) -> TextureId {
         let id = TextureId::Managed(self.next_id);
         self.next_id += 1;
 
@@ -36,10 +36,10 @@ impl TextureManager {
             size: image.size(),
             bytes_per_pixel: image.bytes_per_pixel(),
             retain_count: 1,
-            filter,
+            options,
         });
 
-        self.delta.set.push((id, ImageDelta::full(image, filter)));
+        self.delta.set.push((id, ImageDelta::full(image, options)));
         id
     }
 
@@ -132,8 +132,8 @@ pub struct TextureMeta {
     /// Free when this reaches zero.
     pub retain_count: usize,
 
-    /// The texture filtering mode to use when rendering
-    pub filter: TextureFilter,
+    /// How the texture is sampled when rendering.
+    pub options: TextureOptions,
 }
 
 /// How the texture texels are filtered.
@@ -158,6 +158,96 @@ impl Default for TextureFilter {
     }
 }
 
+/// How texture coordinates outside of `[0, 1]` are handled.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum TextureWrapMode {
+    /// Stretch the edge texels.
+    ///
+    /// This is the default.
+    ClampToEdge,
+
+    /// Tile the texture.
+    Repeat,
+
+    /// Tile the texture, mirroring every other repetition.
+    MirroredRepeat,
+}
+
+impl Default for TextureWrapMode {
+    fn default() -> Self {
+        Self::ClampToEdge
+    }
+}
+
+/// How a texture is sampled: filtering, wrapping and mipmapping.
+///
+/// Anything that accepts `impl Into<TextureOptions>` also accepts a plain [`TextureFilter`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextureOptions {
+    /// How to filter the texture when it is magnified or minified.
+    pub filter: TextureFilter,
+
+    /// How to handle texture coordinates outside of `[0, 1]`.
+    pub wrap_mode: TextureWrapMode,
+
+    /// If set, the backend generates mipmaps for the texture,
+    /// and uses this filter to blend between the mipmap levels.
+    ///
+    /// Use this for images that are shown much smaller than their native size
+    /// (e.g. thumbnails) to prevent them from shimmering.
+    ///
+    /// Not supported by `egui-wgpu` and WebGL1, which ignore this.
+    /// `wgpu` can't generate mipmaps, so `egui-wgpu` would need to render them itself.
+    ///
+    /// Default: `None` (no mipmaps).
+    pub mipmap_mode: Option<TextureFilter>,
+}
+
+impl TextureOptions {
+    /// Linear filtering, clamped to edge.
+    pub const LINEAR: Self = Self {
+        filter: TextureFilter::Linear,
+        wrap_mode: TextureWrapMode::ClampToEdge,
+        mipmap_mode: None,
+    };
+
+    /// Nearest-neighbor filtering, clamped to edge.
+    ///
+    /// Good for pixel art and icon atlases.
+    pub const NEAREST: Self = Self {
+        filter: TextureFilter::Nearest,
+        wrap_mode: TextureWrapMode::ClampToEdge,
+        mipmap_mode: None,
+    };
+
+    /// Change the wrap mode.
+    #[inline]
+    pub fn with_wrap_mode(mut self, wrap_mode: TextureWrapMode) -> Self {
+        self.wrap_mode = wrap_mode;
+        self
+    }
+
+    /// Generate mipmaps, using the given filter between mipmap levels.
+    #[inline]
+    pub fn with_mipmap_mode(mut self, mipmap_mode: Option<TextureFilter>) -> Self {
+        self.mipmap_mode = mipmap_mode;
+        self
+    }
+}
+
+impl From<TextureFilter> for TextureOptions {
+    #[inline]
+    fn from(filter: TextureFilter) -> Self {
+        Self {
+            filter,
+            wrap_mode: TextureWrapMode::default(),
+            mipmap_mode: None,
+        }
+    }
+}
+
 impl TextureMeta {
     /// Size in bytes.
     /// width x height x [`Self::bytes_per_pixel`].
@@ -19,13 +19,13 @@ fn main() {
 }
 
 struct MyApp {
-    svg_image: egui_extras::RetainedImage,
+    svg_image: egui_extras::RetainedSvg,
 }
 
 impl Default for MyApp {
     fn default() -> Self {
         Self {
-            svg_image: egui_extras::RetainedImage::from_svg_bytes(
+            svg_image: egui_extras::RetainedSvg::from_svg_bytes(
                 "rustacean-flat-happy.svg",
                 include_bytes!("rustacean-flat-happy.svg"),
             )
@@ -38,7 +38,9 @@ impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("SVG example");
-            ui.label("The SVG is rasterized and displayed as a texture.");
+            ui.label(
+                "The SVG is rasterized at the size it is shown at, and displayed as a texture.",
+            );
 
             ui.separator();
 